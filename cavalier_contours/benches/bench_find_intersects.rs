@@ -0,0 +1,26 @@
+mod bench_utils;
+
+use bench_utils::{SegKind, wavy_pline};
+use cavalier_contours::polyline::PlineSource;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+fn find_intersects(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_intersects");
+    for vertex_count in [1_000, 10_000] {
+        // same center and radius so the polylines cross each other many times, radius scales with
+        // vertex count to keep the same segment lengths
+        let radius = vertex_count as f64 / 10.0;
+        let pline1 = wavy_pline(7, vertex_count, (0.0, 0.0), radius, SegKind::Mixed);
+        let pline2 = wavy_pline(8, vertex_count, (0.0, 0.0), radius, SegKind::Mixed);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(vertex_count),
+            &vertex_count,
+            |b, _| b.iter(|| pline1.find_intersects(black_box(&pline2))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, find_intersects);
+criterion_main!(benches);
@@ -0,0 +1,59 @@
+//! Compares repeated polyline offset and boolean operations with and without reusing an
+//! [OffsetContext].
+mod bench_utils;
+
+use bench_utils::{SegKind, wavy_pline};
+use cavalier_contours::polyline::{
+    BooleanOp, OffsetContext, PlineBooleanOptions, PlineOffsetOptions, PlineSource, PlineSourceMut,
+};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+fn offset_context(c: &mut Criterion) {
+    let closed = wavy_pline(7, 400, (0.0, 0.0), 10.0, SegKind::Mixed);
+    let mut open = closed.clone();
+    open.set_is_closed(false);
+    let other = wavy_pline(8, 400, (3.0, 1.0), 10.0, SegKind::Mixed);
+    let context = OffsetContext::new();
+
+    let mut group = c.benchmark_group("offset_context");
+    for (name, pline) in [("closed", &closed), ("open", &open)] {
+        let options = PlineOffsetOptions::default();
+        group.bench_function(BenchmarkId::new("offset", name), |b| {
+            b.iter(|| pline.parallel_offset_opt(black_box(0.5), &options))
+        });
+
+        let options = PlineOffsetOptions {
+            context: Some(&context),
+            ..Default::default()
+        };
+        group.bench_function(BenchmarkId::new("offset_with_context", name), |b| {
+            b.iter(|| pline.parallel_offset_opt(black_box(0.5), &options))
+        });
+    }
+
+    for op in [
+        BooleanOp::Or,
+        BooleanOp::And,
+        BooleanOp::Not,
+        BooleanOp::Xor,
+    ] {
+        let options = PlineBooleanOptions::default();
+        group.bench_function(BenchmarkId::new("boolean", format!("{op:?}")), |b| {
+            b.iter(|| closed.boolean_opt(black_box(&other), op, &options))
+        });
+
+        let options = PlineBooleanOptions {
+            context: Some(&context),
+            ..Default::default()
+        };
+        group.bench_function(
+            BenchmarkId::new("boolean_with_context", format!("{op:?}")),
+            |b| b.iter(|| closed.boolean_opt(black_box(&other), op, &options)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, offset_context);
+criterion_main!(benches);
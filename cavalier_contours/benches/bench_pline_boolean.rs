@@ -0,0 +1,29 @@
+mod bench_utils;
+
+use bench_utils::{SegKind, wavy_pline};
+use cavalier_contours::polyline::{BooleanOp, PlineSource};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+fn pline_boolean(c: &mut Criterion) {
+    let pline1 = wavy_pline(5, 2_000, (0.0, 0.0), 100.0, SegKind::Mixed);
+    let pline2 = wavy_pline(6, 2_000, (30.0, 10.0), 100.0, SegKind::Mixed);
+
+    let mut group = c.benchmark_group("pline_boolean");
+    for op in [
+        BooleanOp::Or,
+        BooleanOp::And,
+        BooleanOp::Not,
+        BooleanOp::Xor,
+    ] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{op:?}")),
+            &op,
+            |b, &op| b.iter(|| pline1.boolean(black_box(&pline2), op)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, pline_boolean);
+criterion_main!(benches);
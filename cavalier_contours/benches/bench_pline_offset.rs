@@ -0,0 +1,38 @@
+mod bench_utils;
+
+use bench_utils::{SegKind, wavy_pline};
+use cavalier_contours::polyline::PlineSource;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+fn pline_offset(c: &mut Criterion) {
+    let cases = [
+        (
+            "lines",
+            wavy_pline(1, 1_000, (0.0, 0.0), 100.0, SegKind::Lines),
+        ),
+        (
+            "arcs",
+            wavy_pline(2, 1_000, (0.0, 0.0), 100.0, SegKind::Arcs),
+        ),
+        // larger radius to keep the same order of segment length as the other cases
+        (
+            "high_vertex_count",
+            wavy_pline(3, 20_000, (0.0, 0.0), 1000.0, SegKind::Mixed),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("pline_offset");
+    for (name, pline) in cases.iter() {
+        // positive offset is inward (counter clockwise polylines)
+        for offset in [-5.0, 5.0, 20.0] {
+            group.bench_with_input(BenchmarkId::new(*name, offset), &offset, |b, &offset| {
+                b.iter(|| pline.parallel_offset(black_box(offset)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, pline_offset);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+mod bench_utils;
+
+use bench_utils::plate_with_islands;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+fn shape_offset(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shape_offset");
+    group.sample_size(20);
+    for (rows, cols) in [(5, 5), (15, 15)] {
+        let shape = plate_with_islands(4, rows, cols, 100);
+        let loop_count = shape.ccw_plines.len() + shape.cw_plines.len();
+        // small offset keeps loops separate, large offset merges holes with islands
+        for offset in [0.5, 2.5] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("{loop_count}_loops"), offset),
+                &offset,
+                |b, &offset| {
+                    b.iter(|| shape.parallel_offset(black_box(offset), Default::default()))
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, shape_offset);
+criterion_main!(benches);
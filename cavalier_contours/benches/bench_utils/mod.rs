@@ -0,0 +1,152 @@
+//! Generators for large deterministic polylines and shapes used by the benchmarks.
+//!
+//! All generators take a seed so the same input is generated for every run (and on every
+//! platform), allowing benchmark results to be compared across releases.
+use std::f64::consts::TAU;
+
+use cavalier_contours::{
+    core::math::Vector2,
+    polyline::{PlineCreation, PlineSourceMut, Polyline},
+    shape_algorithms::Shape,
+};
+
+/// Small deterministic pseudo random number generator (xorshift64*).
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // state must be non-zero
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Random value in the range [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Random value in the range [min, max).
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+}
+
+/// Type of segments to generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)] // not every benchmark uses every kind
+pub enum SegKind {
+    /// All line segments.
+    Lines,
+    /// All arc segments.
+    Arcs,
+    /// Randomly mixed line and arc segments.
+    Mixed,
+}
+
+/// Closed counter clockwise polyline with `vertex_count` vertexes positioned around `center`.
+///
+/// The distance from `center` to the boundary varies smoothly between roughly 0.7 and 1.3 times
+/// `radius` (sum of sine waves with random frequencies and phases) so offsetting inward creates
+/// pinch points and offsetting outward closes gaps. Arc segments pass through the midpoint of the
+/// curve between their end points so the polyline never self intersects.
+pub fn wavy_pline(
+    seed: u64,
+    vertex_count: usize,
+    center: (f64, f64),
+    radius: f64,
+    kind: SegKind,
+) -> Polyline {
+    let mut rng = Rng::new(seed);
+    let waves: Vec<(f64, f64, f64)> = (0..3)
+        .map(|_| {
+            let frequency = (rng.range(3.0, 12.0)).floor();
+            let amplitude = rng.range(0.03, 0.1);
+            let phase = rng.range(0.0, TAU);
+            (frequency, amplitude, phase)
+        })
+        .collect();
+
+    let point_at = |i: f64| {
+        let theta = TAU * i / vertex_count as f64;
+        let scale = 1.0
+            + waves
+                .iter()
+                .map(|&(f, a, p)| a * (f * theta + p).sin())
+                .sum::<f64>();
+        Vector2::new(
+            center.0 + radius * scale * theta.cos(),
+            center.1 + radius * scale * theta.sin(),
+        )
+    };
+
+    let mut pline = Polyline::with_capacity(vertex_count, true);
+    for i in 0..vertex_count {
+        let start = point_at(i as f64);
+        let is_arc = match kind {
+            SegKind::Lines => false,
+            SegKind::Arcs => true,
+            SegKind::Mixed => rng.next_f64() < 0.5,
+        };
+        let bulge = if is_arc {
+            // bulge = 2 * sagitta / chord length, sagitta is the signed distance of the curve
+            // midpoint from the chord (positive to the right for a counter clockwise arc)
+            let end = point_at((i + 1) as f64);
+            let mid = point_at(i as f64 + 0.5);
+            let chord = end - start;
+            let chord_length = chord.length();
+            let sagitta = (mid - start).perp_dot(chord) / chord_length;
+            2.0 * sagitta / chord_length
+        } else {
+            0.0
+        };
+        pline.add(start.x, start.y, bulge);
+    }
+
+    pline
+}
+
+/// Shape made up of a square plate with a `rows` by `cols` grid of holes, each hole has an island
+/// inside of it. Holes and islands are wavy polylines with `vertex_count` vertexes each.
+///
+/// Total loop count is `2 * rows * cols + 1`.
+#[allow(dead_code)] // only used by the shape benchmarks
+pub fn plate_with_islands(seed: u64, rows: usize, cols: usize, vertex_count: usize) -> Shape<f64> {
+    let cell_size = 20.0;
+    let mut plines = Vec::with_capacity(2 * rows * cols + 1);
+    let (width, height) = (cols as f64 * cell_size, rows as f64 * cell_size);
+    let mut plate = Polyline::with_capacity(4, true);
+    plate.add(0.0, 0.0, 0.0);
+    plate.add(width, 0.0, 0.0);
+    plate.add(width, height, 0.0);
+    plate.add(0.0, height, 0.0);
+    plines.push(plate);
+
+    let mut rng = Rng::new(seed);
+    for row in 0..rows {
+        for col in 0..cols {
+            let center = (
+                (col as f64 + 0.5) * cell_size,
+                (row as f64 + 0.5) * cell_size,
+            );
+            let mut hole = wavy_pline(rng.next_u64(), vertex_count, center, 7.0, SegKind::Mixed);
+            hole.invert_direction_mut();
+            plines.push(hole);
+            plines.push(wavy_pline(
+                rng.next_u64(),
+                vertex_count,
+                center,
+                3.0,
+                SegKind::Mixed,
+            ));
+        }
+    }
+
+    Shape::from_plines(plines)
+}
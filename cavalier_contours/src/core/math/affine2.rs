@@ -0,0 +1,267 @@
+use super::Vector2;
+use crate::core::traits::Real;
+use std::ops;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// 2D affine transform, a point `(x, y)` is transformed to
+/// `(m11 * x + m12 * y + tx, m21 * x + m22 * y + ty)`.
+///
+/// Transforms are combined with [Affine2::then] (or multiplication, `a * b` applies `b` first
+/// then `a`).
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::core::math::*;
+/// // rotate 90 degrees counter clockwise then move right by 2
+/// let t = Affine2::rotation(std::f64::consts::FRAC_PI_2).then(&Affine2::translation(2.0, 0.0));
+/// assert!(t.transform_point(Vector2::new(1.0, 0.0)).fuzzy_eq(Vector2::new(2.0, 1.0)));
+/// assert!(t.is_similarity());
+/// assert!(!Affine2::scaling(1.0, 2.0).is_similarity());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Affine2<T = f64> {
+    pub m11: T,
+    pub m12: T,
+    pub m21: T,
+    pub m22: T,
+    pub tx: T,
+    pub ty: T,
+}
+
+impl<T> Default for Affine2<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T> Affine2<T>
+where
+    T: Real,
+{
+    /// Create a new transform from the matrix components and translation.
+    #[inline]
+    pub fn new(m11: T, m12: T, m21: T, m22: T, tx: T, ty: T) -> Self {
+        Affine2 {
+            m11,
+            m12,
+            m21,
+            m22,
+            tx,
+            ty,
+        }
+    }
+
+    /// Identity transform (leaves points unchanged).
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+
+    /// Translation by `x` and `y`.
+    #[inline]
+    pub fn translation(x: T, y: T) -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::one(), x, y)
+    }
+
+    /// Counter clockwise rotation about the coordinate origin by `angle` in radians.
+    #[inline]
+    pub fn rotation(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::new(c, -s, s, c, T::zero(), T::zero())
+    }
+
+    /// Counter clockwise rotation about the `origin` point given by `angle` in radians.
+    #[inline]
+    pub fn rotation_about(angle: T, origin: Vector2<T>) -> Self {
+        Self::translation(-origin.x, -origin.y)
+            .then(&Self::rotation(angle))
+            .then(&Self::translation(origin.x, origin.y))
+    }
+
+    /// Scaling about the coordinate origin by `sx` along the x axis and `sy` along the y axis.
+    #[inline]
+    pub fn scaling(sx: T, sy: T) -> Self {
+        Self::new(sx, T::zero(), T::zero(), sy, T::zero(), T::zero())
+    }
+
+    /// Uniform scaling about the coordinate origin by `scale_factor`.
+    #[inline]
+    pub fn uniform_scaling(scale_factor: T) -> Self {
+        Self::scaling(scale_factor, scale_factor)
+    }
+
+    /// Reflection across the line passing through `point` in the `direction` given. Returns the
+    /// identity transform if `direction` is a zero vector.
+    pub fn mirror(point: Vector2<T>, direction: Vector2<T>) -> Self {
+        let len_sq = direction.length_squared();
+        if len_sq.fuzzy_eq_zero() {
+            return Self::identity();
+        }
+
+        // reflection matrix is 2 * d * d^T / |d|^2 - I
+        let (dx, dy) = (direction.x, direction.y);
+        let two = T::two();
+        let m11 = (dx * dx - dy * dy) / len_sq;
+        let m12 = two * dx * dy / len_sq;
+        let m22 = (dy * dy - dx * dx) / len_sq;
+        let reflect = Self::new(m11, m12, m12, m22, T::zero(), T::zero());
+
+        Self::translation(-point.x, -point.y)
+            .then(&reflect)
+            .then(&Self::translation(point.x, point.y))
+    }
+
+    /// Returns the transform that applies `self` followed by `other`.
+    #[inline]
+    pub fn then(&self, other: &Self) -> Self {
+        Self::new(
+            other.m11 * self.m11 + other.m12 * self.m21,
+            other.m11 * self.m12 + other.m12 * self.m22,
+            other.m21 * self.m11 + other.m22 * self.m21,
+            other.m21 * self.m12 + other.m22 * self.m22,
+            other.m11 * self.tx + other.m12 * self.ty + other.tx,
+            other.m21 * self.tx + other.m22 * self.ty + other.ty,
+        )
+    }
+
+    /// Determinant of the linear (matrix) part of the transform, negative if the transform
+    /// reflects (inverts orientation).
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    /// Inverse transform, returns `None` if the transform is not invertible (determinant is zero).
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.fuzzy_eq_zero() {
+            return None;
+        }
+
+        let m11 = self.m22 / det;
+        let m12 = -self.m12 / det;
+        let m21 = -self.m21 / det;
+        let m22 = self.m11 / det;
+        let tx = -(m11 * self.tx + m12 * self.ty);
+        let ty = -(m21 * self.tx + m22 * self.ty);
+        Some(Self::new(m11, m12, m21, m22, tx, ty))
+    }
+
+    /// Apply the transform to a point.
+    #[inline]
+    pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T> {
+        Vector2::new(
+            self.m11 * point.x + self.m12 * point.y + self.tx,
+            self.m21 * point.x + self.m22 * point.y + self.ty,
+        )
+    }
+
+    /// Apply the transform to a direction vector (translation is not applied).
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+        Vector2::new(
+            self.m11 * vector.x + self.m12 * vector.y,
+            self.m21 * vector.x + self.m22 * vector.y,
+        )
+    }
+
+    /// Returns true if the transform is a non-degenerate similarity (uniform scale, rotation,
+    /// reflection, and translation only) using relative epsilon `eps`. Circles remain circles
+    /// under similarity transforms.
+    pub fn is_similarity_eps(&self, eps: T) -> bool {
+        let c1 = Vector2::new(self.m11, self.m21);
+        let c2 = Vector2::new(self.m12, self.m22);
+        let len1 = c1.length_squared();
+        if len1.fuzzy_eq_zero() {
+            return false;
+        }
+
+        (len1 - c2.length_squared()).abs() <= eps * len1 && c1.dot(c2).abs() <= eps * len1
+    }
+
+    /// Same as [Affine2::is_similarity_eps] using default epsilon of `T::fuzzy_epsilon()`.
+    #[inline]
+    pub fn is_similarity(&self) -> bool {
+        self.is_similarity_eps(T::fuzzy_epsilon())
+    }
+
+    /// Returns true if all components of the transforms are fuzzy equal using `fuzzy_epsilon`.
+    pub fn fuzzy_eq_eps(&self, other: &Self, fuzzy_epsilon: T) -> bool {
+        self.m11.fuzzy_eq_eps(other.m11, fuzzy_epsilon)
+            && self.m12.fuzzy_eq_eps(other.m12, fuzzy_epsilon)
+            && self.m21.fuzzy_eq_eps(other.m21, fuzzy_epsilon)
+            && self.m22.fuzzy_eq_eps(other.m22, fuzzy_epsilon)
+            && self.tx.fuzzy_eq_eps(other.tx, fuzzy_epsilon)
+            && self.ty.fuzzy_eq_eps(other.ty, fuzzy_epsilon)
+    }
+
+    /// Same as [Affine2::fuzzy_eq_eps] using default epsilon of `T::fuzzy_epsilon()`.
+    #[inline]
+    pub fn fuzzy_eq(&self, other: &Self) -> bool {
+        self.fuzzy_eq_eps(other, T::fuzzy_epsilon())
+    }
+}
+
+impl<T: Real> ops::Mul for Affine2<T> {
+    type Output = Affine2<T>;
+
+    /// Composes transforms, `a * b` applies `b` then `a`.
+    #[inline]
+    fn mul(self, rhs: Affine2<T>) -> Self::Output {
+        rhs.then(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn compose_and_inverse() {
+        let t = Affine2::rotation_about(FRAC_PI_2, Vector2::new(1.0, 1.0))
+            .then(&Affine2::uniform_scaling(2.0))
+            .then(&Affine2::translation(3.0, -1.0));
+        let p = Vector2::new(2.0, 1.0);
+        // (2, 1) rotated about (1, 1) -> (1, 2), scaled -> (2, 4), translated -> (5, 3)
+        assert!(t.transform_point(p).fuzzy_eq(Vector2::new(5.0, 3.0)));
+        let inv = t.inverse().unwrap();
+        assert!(inv.transform_point(Vector2::new(5.0, 3.0)).fuzzy_eq(p));
+        assert!((t * inv).fuzzy_eq(&Affine2::identity()));
+        assert!(Affine2::scaling(0.0, 1.0).inverse().is_none());
+    }
+
+    #[test]
+    fn mirror() {
+        // mirror across line y = x + 1
+        let t = Affine2::mirror(Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0));
+        assert!(
+            t.transform_point(Vector2::new(1.0, 0.0))
+                .fuzzy_eq(Vector2::new(-1.0, 2.0))
+        );
+        assert!(t.determinant() < 0.0);
+        assert!(t.is_similarity());
+        assert!(t.then(&t).fuzzy_eq(&Affine2::identity()));
+
+        let r = Affine2::rotation(PI / 3.0).then(&Affine2::uniform_scaling(0.5));
+        assert!(r.is_similarity());
+        assert!(r.determinant() > 0.0);
+        assert!(!Affine2::new(1.0, 0.5, 0.0, 1.0, 0.0, 0.0).is_similarity());
+        assert!(!Affine2::uniform_scaling(0.0).is_similarity());
+    }
+}
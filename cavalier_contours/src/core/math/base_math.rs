@@ -477,3 +477,12 @@ where
 {
     T::four() * bulge.atan()
 }
+
+/// Convert a `Real` value to `f64` (used when formatting values for output).
+#[inline]
+pub(crate) fn to_f64<T>(value: T) -> f64
+where
+    T: Real,
+{
+    value.to_f64().unwrap()
+}
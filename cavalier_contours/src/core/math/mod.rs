@@ -1,10 +1,12 @@
 //! Core/common math functions for working with angles, 2D space, intersections, etc.
+mod affine2;
 mod base_math;
 mod circle_circle_intersect;
 mod line_circle_intersect;
 mod line_line_intersect;
 mod vector2;
 
+pub use affine2::Affine2;
 pub use base_math::*;
 pub use circle_circle_intersect::{CircleCircleIntr, circle_circle_intr};
 pub use line_circle_intersect::{LineCircleIntr, line_circle_intr};
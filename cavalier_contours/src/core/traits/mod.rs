@@ -7,4 +7,4 @@ mod real;
 pub use control_flow::ControlFlow;
 pub use fuzzy_eq::FuzzyEq;
 pub use fuzzy_ord::FuzzyOrd;
-pub use real::Real;
+pub use real::{MaybeSendSync, Real};
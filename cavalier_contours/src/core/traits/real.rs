@@ -1,6 +1,24 @@
 use super::FuzzyOrd;
 use static_aabb2d_index::IndexableNum;
 
+/// Thread safety bounds required of [Real] types, `Send + Sync` if the `rayon` feature is enabled
+/// (to allow parallel processing) otherwise no bounds. Implemented for all types meeting the
+/// bounds.
+#[cfg(feature = "rayon")]
+pub trait MaybeSendSync: Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T> MaybeSendSync for T where T: Send + Sync {}
+
+/// Thread safety bounds required of [Real] types, `Send + Sync` if the `rayon` feature is enabled
+/// (to allow parallel processing) otherwise no bounds. Implemented for all types meeting the
+/// bounds.
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSendSync {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> MaybeSendSync for T {}
+
 /// Trait representing a real number (e.g. 1.1, -3.5, etc.) that can be fuzzy compared and ordered.
 pub trait Real:
     num_traits::real::Real
@@ -9,6 +27,7 @@ pub trait Real:
     + std::default::Default
     + std::fmt::Debug
     + IndexableNum
+    + MaybeSendSync
     + 'static
 {
     #[inline]
@@ -0,0 +1,77 @@
+//! This module has functions for reading and writing polylines as ASCII
+//! [DXF](https://en.wikipedia.org/wiki/AutoCAD_DXF) data (requires the `dxf` cargo feature).
+//!
+//! A polyline vertex (x, y, bulge) maps directly to a DXF `LWPOLYLINE` vertex so no precision or
+//! arc information is lost going to and from DXF.
+mod reader;
+mod writer;
+
+pub use reader::*;
+pub use writer::*;
+
+use std::fmt;
+
+use crate::core::traits::Real;
+
+/// Error returned when reading DXF data fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DxfError {
+    /// Group code line could not be parsed as an integer, `line` is the 1 based line number.
+    InvalidGroupCode { line: usize },
+    /// Value line could not be parsed for the group code, `line` is the 1 based line number.
+    InvalidValue { line: usize, group_code: i32 },
+    /// Input ended with a group code that has no value line.
+    UnexpectedEndOfInput,
+}
+
+impl fmt::Display for DxfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DxfError::InvalidGroupCode { line } => {
+                write!(f, "invalid DXF group code at line {line}")
+            }
+            DxfError::InvalidValue { line, group_code } => {
+                write!(
+                    f,
+                    "invalid DXF value for group code {group_code} at line {line}"
+                )
+            }
+            DxfError::UnexpectedEndOfInput => {
+                write!(f, "unexpected end of DXF input (group code missing value)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DxfError {}
+
+/// Struct to hold options parameters when reading DXF data.
+#[derive(Debug, Clone)]
+pub struct DxfReadOptions<T> {
+    /// Fuzzy comparison epsilon used for determining if two positions are equal, used to remove
+    /// repeat position vertexes and to chain `LINE` and `ARC` entities together by their end
+    /// points.
+    pub pos_equal_eps: T,
+}
+
+impl<T> DxfReadOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for DxfReadOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
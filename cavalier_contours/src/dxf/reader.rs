@@ -0,0 +1,566 @@
+use static_aabb2d_index::StaticAABB2DIndexBuilder;
+
+use crate::{
+    core::{
+        math::{
+            Vector2, angle, angle_from_bulge, bulge_from_angle, normalize_radians, point_on_circle,
+        },
+        traits::Real,
+    },
+    polyline::{
+        PlineCreation, PlineSource, PlineSourceMut, PlineVertex, Polyline,
+        seg_arc_radius_and_center,
+    },
+};
+
+use super::{DxfError, DxfReadOptions};
+
+/// Read polylines from ASCII DXF data using default options.
+///
+/// See [read_dxf_opt] for more information.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::traits::*;
+/// # use cavalier_contours::dxf::*;
+/// let dxf_str = "0\nSECTION\n2\nENTITIES\n\
+///                0\nLINE\n8\n0\n10\n0.0\n20\n0.0\n11\n10.0\n21\n0.0\n\
+///                0\nARC\n8\n0\n10\n10.0\n20\n5.0\n40\n5.0\n50\n270.0\n51\n90.0\n\
+///                0\nENDSEC\n0\nEOF\n";
+/// let plines: Vec<Polyline> = read_dxf(dxf_str).unwrap();
+/// assert_eq!(plines.len(), 1);
+/// assert!(!plines[0].is_closed());
+/// assert_eq!(plines[0].vertex_count(), 3);
+/// assert!(plines[0][1].bulge.fuzzy_eq(1.0));
+/// ```
+pub fn read_dxf<T>(input: &str) -> Result<Vec<Polyline<T>>, DxfError>
+where
+    T: Real,
+{
+    read_dxf_opt(input, &DxfReadOptions::new())
+}
+
+/// Read polylines from ASCII DXF data.
+///
+/// Only entities in the `ENTITIES` section are read, the following entity types are supported:
+///
+/// * `LWPOLYLINE` and `POLYLINE` (2D and 3D, polygon/polyface meshes are skipped) are each read as
+///   a polyline with closed flag and bulges preserved.
+/// * `CIRCLE` is read as a closed polyline with two half circle arc segments.
+/// * `LINE` and `ARC` entities are chained together by their end points (using
+///   `options.pos_equal_eps`) into polylines, if a chain ends where it started then the resulting
+///   polyline is closed.
+///
+/// All other entity types are ignored. Z coordinates are ignored (entities are projected onto the
+/// XY plane), entities with an extrusion direction of negative Z are mirrored to be in world
+/// coordinates. Repeat position vertexes are removed from all polylines returned and arc segments
+/// which sweep more than a half circle are split in two.
+pub fn read_dxf_opt<T>(
+    input: &str,
+    options: &DxfReadOptions<T>,
+) -> Result<Vec<Polyline<T>>, DxfError>
+where
+    T: Real,
+{
+    let pairs = parse_group_pairs(input)?;
+    let entities = entities_section(&pairs);
+
+    let mut result = Vec::new();
+    let mut chain_segments = Vec::new();
+    let mut i = 0;
+    while i < entities.len() {
+        let entity = &entities[i];
+        i += 1;
+        match entity.name {
+            "LWPOLYLINE" => {
+                result.push(read_lwpolyline(entity)?);
+            }
+            "POLYLINE" => {
+                // vertexes follow as separate entities until SEQEND
+                let vertex_count = entities[i..]
+                    .iter()
+                    .take_while(|e| e.name == "VERTEX")
+                    .count();
+                let vertexes = &entities[i..i + vertex_count];
+                i += vertex_count;
+                if i < entities.len() && entities[i].name == "SEQEND" {
+                    i += 1;
+                }
+                if let Some(pline) = read_polyline(entity, vertexes)? {
+                    result.push(pline);
+                }
+            }
+            "CIRCLE" => {
+                result.push(read_circle(entity)?);
+            }
+            "LINE" => {
+                chain_segments.push(read_line(entity)?);
+            }
+            "ARC" => match read_arc(entity)? {
+                ArcEntity::Segment(seg) => chain_segments.push(seg),
+                ArcEntity::Circle(pline) => result.push(pline),
+            },
+            _ => {}
+        }
+    }
+
+    result.extend(chain_segments_together(
+        &chain_segments,
+        options.pos_equal_eps,
+    ));
+
+    let result = result
+        .into_iter()
+        .filter_map(|pline| {
+            let pline = pline
+                .remove_repeat_pos(options.pos_equal_eps)
+                .unwrap_or(pline);
+            (pline.vertex_count() > 1).then(|| split_large_arcs(pline))
+        })
+        .collect();
+
+    Ok(result)
+}
+
+/// Split arc segments which sweep more than a half circle (bulge magnitude greater than 1) into
+/// two arc segments, polyline arc segments are required to sweep at most a half circle.
+fn split_large_arcs<T>(pline: Polyline<T>) -> Polyline<T>
+where
+    T: Real,
+{
+    if pline.iter_vertexes().all(|v| v.bulge.abs() <= T::one()) {
+        return pline;
+    }
+
+    let mut result = Polyline::with_capacity(pline.vertex_count() + 1, pline.is_closed());
+    for (v1, v2) in pline.iter_segments() {
+        if v1.bulge.abs() <= T::one() {
+            result.add_vertex(v1);
+            continue;
+        }
+
+        let (radius, center) = seg_arc_radius_and_center(v1, v2);
+        let half_sweep = angle_from_bulge(v1.bulge) / T::two();
+        let mid = point_on_circle(radius, center, angle(center, v1.pos()) + half_sweep);
+        let half_bulge = bulge_from_angle(half_sweep);
+        result.add(v1.x, v1.y, half_bulge);
+        result.add(mid.x, mid.y, half_bulge);
+    }
+
+    if !pline.is_closed() {
+        result.add_vertex(pline.last().unwrap());
+    }
+
+    result
+}
+
+/// DXF group code and value pair.
+#[derive(Debug, Clone, Copy)]
+struct GroupPair<'a> {
+    code: i32,
+    value: &'a str,
+    /// 1 based line number of the value (for error reporting).
+    line: usize,
+}
+
+impl GroupPair<'_> {
+    fn parse_f64(&self) -> Result<f64, DxfError> {
+        self.value.parse().map_err(|_| DxfError::InvalidValue {
+            line: self.line,
+            group_code: self.code,
+        })
+    }
+
+    fn parse_real<T: Real>(&self) -> Result<T, DxfError> {
+        Ok(T::from(self.parse_f64()?).unwrap())
+    }
+
+    fn parse_i32(&self) -> Result<i32, DxfError> {
+        self.value.parse().map_err(|_| DxfError::InvalidValue {
+            line: self.line,
+            group_code: self.code,
+        })
+    }
+}
+
+/// DXF entity, `name` is the value of the 0 group code and `pairs` are all the group pairs that
+/// follow it up to the next 0 group code.
+#[derive(Debug, Clone, Copy)]
+struct Entity<'a> {
+    name: &'a str,
+    pairs: &'a [GroupPair<'a>],
+}
+
+impl Entity<'_> {
+    /// Returns true if the entity extrusion direction is negative Z (object coordinates are
+    /// mirrored in X relative to world coordinates).
+    fn is_extrusion_flipped(&self) -> Result<bool, DxfError> {
+        for p in self.pairs.iter() {
+            if p.code == 230 {
+                return Ok(p.parse_f64()? < 0.0);
+            }
+        }
+        Ok(false)
+    }
+
+    fn flags(&self) -> Result<i32, DxfError> {
+        for p in self.pairs.iter() {
+            if p.code == 70 {
+                return p.parse_i32();
+            }
+        }
+        Ok(0)
+    }
+
+    /// Get the real values for each of the group `codes` given (missing values default to 0).
+    fn reals<T: Real, const N: usize>(&self, codes: [i32; N]) -> Result<[T; N], DxfError> {
+        let mut values = [T::zero(); N];
+        for p in self.pairs.iter() {
+            if let Some(i) = codes.iter().position(|&c| c == p.code) {
+                values[i] = p.parse_real()?;
+            }
+        }
+        Ok(values)
+    }
+}
+
+fn parse_group_pairs(input: &str) -> Result<Vec<GroupPair<'_>>, DxfError> {
+    let mut pairs = Vec::new();
+    let mut lines = input.lines().enumerate();
+    while let Some((code_line_idx, code_line)) = lines.next() {
+        let code_line = code_line.trim();
+        if code_line.is_empty() {
+            // allow trailing blank lines
+            if lines.clone().all(|(_, l)| l.trim().is_empty()) {
+                break;
+            }
+        }
+
+        let code = code_line.parse().map_err(|_| DxfError::InvalidGroupCode {
+            line: code_line_idx + 1,
+        })?;
+
+        let (value_line_idx, value) = lines.next().ok_or(DxfError::UnexpectedEndOfInput)?;
+        pairs.push(GroupPair {
+            code,
+            value: value.trim(),
+            line: value_line_idx + 1,
+        });
+    }
+
+    Ok(pairs)
+}
+
+/// Split the group pairs of the `ENTITIES` section into entities.
+fn entities_section<'a>(pairs: &'a [GroupPair<'a>]) -> Vec<Entity<'a>> {
+    let mut entities = Vec::new();
+    let mut in_entities = false;
+    let mut i = 0;
+    while i < pairs.len() {
+        let p = &pairs[i];
+        if p.code != 0 {
+            i += 1;
+            continue;
+        }
+
+        let end = pairs[i + 1..]
+            .iter()
+            .position(|q| q.code == 0)
+            .map_or(pairs.len(), |n| i + 1 + n);
+        let entity = Entity {
+            name: p.value,
+            pairs: &pairs[i + 1..end],
+        };
+
+        match entity.name {
+            "SECTION" => {
+                in_entities = entity
+                    .pairs
+                    .iter()
+                    .any(|q| q.code == 2 && q.value == "ENTITIES");
+            }
+            "ENDSEC" => {
+                in_entities = false;
+            }
+            _ if in_entities => {
+                entities.push(entity);
+            }
+            _ => {}
+        }
+
+        i = end;
+    }
+
+    entities
+}
+
+/// Mirror the polyline in X if `flipped` is true (converting from object coordinates with
+/// negative Z extrusion direction to world coordinates).
+fn apply_extrusion<T>(mut pline: Polyline<T>, flipped: bool) -> Polyline<T>
+where
+    T: Real,
+{
+    if flipped {
+        for i in 0..pline.vertex_count() {
+            let v = pline.at(i);
+            pline.set(i, -v.x, v.y, -v.bulge);
+        }
+    }
+
+    pline
+}
+
+fn read_lwpolyline<T>(entity: &Entity) -> Result<Polyline<T>, DxfError>
+where
+    T: Real,
+{
+    let is_closed = entity.flags()? & 1 != 0;
+    let mut pline = Polyline::with_capacity(0, is_closed);
+    for p in entity.pairs.iter() {
+        match p.code {
+            10 => {
+                pline.add(p.parse_real()?, T::zero(), T::zero());
+            }
+            20 => {
+                if let Some(last) = pline.last() {
+                    pline.set_last(PlineVertex::new(last.x, p.parse_real()?, last.bulge));
+                }
+            }
+            42 => {
+                if let Some(last) = pline.last() {
+                    pline.set_last(last.with_bulge(p.parse_real()?));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(apply_extrusion(pline, entity.is_extrusion_flipped()?))
+}
+
+fn read_polyline<T>(entity: &Entity, vertexes: &[Entity]) -> Result<Option<Polyline<T>>, DxfError>
+where
+    T: Real,
+{
+    let flags = entity.flags()?;
+    // skip polygon meshes (16) and polyface meshes (64)
+    if flags & (16 | 64) != 0 {
+        return Ok(None);
+    }
+
+    let is_closed = flags & 1 != 0;
+    let is_3d = flags & 8 != 0;
+    let mut pline = Polyline::with_capacity(vertexes.len(), is_closed);
+    for vertex in vertexes.iter() {
+        // skip spline frame control points
+        if vertex.flags()? & 16 != 0 {
+            continue;
+        }
+        let [x, y, bulge] = vertex.reals([10, 20, 42])?;
+        // 3D polylines have no arc segments
+        let bulge = if is_3d { T::zero() } else { bulge };
+        pline.add(x, y, bulge);
+    }
+
+    // 3D polylines are in world coordinates
+    let flipped = !is_3d && entity.is_extrusion_flipped()?;
+    Ok(Some(apply_extrusion(pline, flipped)))
+}
+
+fn circle_pline<T>(center: Vector2<T>, radius: T) -> Polyline<T>
+where
+    T: Real,
+{
+    let mut pline = Polyline::with_capacity(2, true);
+    pline.add(center.x - radius, center.y, T::one());
+    pline.add(center.x + radius, center.y, T::one());
+    pline
+}
+
+fn read_circle<T>(entity: &Entity) -> Result<Polyline<T>, DxfError>
+where
+    T: Real,
+{
+    let [x, y, radius]: [T; 3] = entity.reals([10, 20, 40])?;
+    let pline = circle_pline(Vector2::new(x, y), radius.abs());
+    Ok(apply_extrusion(pline, entity.is_extrusion_flipped()?))
+}
+
+/// Line or arc segment to be chained together with other segments.
+#[derive(Debug, Clone, Copy)]
+struct ChainSegment<T> {
+    start: Vector2<T>,
+    end: Vector2<T>,
+    bulge: T,
+}
+
+impl<T> ChainSegment<T>
+where
+    T: Real,
+{
+    fn reversed(&self) -> Self {
+        Self {
+            start: self.end,
+            end: self.start,
+            bulge: -self.bulge,
+        }
+    }
+}
+
+fn read_line<T>(entity: &Entity) -> Result<ChainSegment<T>, DxfError>
+where
+    T: Real,
+{
+    // line end points are always in world coordinates
+    let [x1, y1, x2, y2] = entity.reals([10, 20, 11, 21])?;
+    Ok(ChainSegment {
+        start: Vector2::new(x1, y1),
+        end: Vector2::new(x2, y2),
+        bulge: T::zero(),
+    })
+}
+
+enum ArcEntity<T> {
+    Segment(ChainSegment<T>),
+    /// Arc entity that sweeps a full circle.
+    Circle(Polyline<T>),
+}
+
+fn read_arc<T>(entity: &Entity) -> Result<ArcEntity<T>, DxfError>
+where
+    T: Real,
+{
+    let [x, y, radius, start_deg, end_deg]: [T; 5] = entity.reals([10, 20, 40, 50, 51])?;
+    let center = Vector2::new(x, y);
+    let radius = radius.abs();
+    let flipped = entity.is_extrusion_flipped()?;
+    let start_angle = start_deg.to_radians();
+    let end_angle = end_deg.to_radians();
+    // arcs always go counter clockwise from start angle to end angle
+    let sweep = normalize_radians(end_angle - start_angle);
+    if sweep.fuzzy_eq_zero() || sweep.fuzzy_eq(T::tau()) {
+        return Ok(ArcEntity::Circle(apply_extrusion(
+            circle_pline(center, radius),
+            flipped,
+        )));
+    }
+
+    let start = point_on_circle(radius, center, start_angle);
+    let end = point_on_circle(radius, center, end_angle);
+    let bulge = bulge_from_angle(sweep);
+    let seg = if flipped {
+        ChainSegment {
+            start: Vector2::new(-start.x, start.y),
+            end: Vector2::new(-end.x, end.y),
+            bulge: -bulge,
+        }
+    } else {
+        ChainSegment { start, end, bulge }
+    };
+
+    Ok(ArcEntity::Segment(seg))
+}
+
+/// Chain segments together end to end to form polylines.
+///
+/// Segments are visited in order, each unvisited segment starts a new chain which is extended
+/// forward and then backward by connecting segments (reversing them as needed).
+fn chain_segments_together<T>(segments: &[ChainSegment<T>], pos_equal_eps: T) -> Vec<Polyline<T>>
+where
+    T: Real,
+{
+    let mut result = Vec::new();
+    if segments.is_empty() {
+        return result;
+    }
+
+    // load all segment end points into spatial index, index i / 2 is the segment and i % 2 == 0
+    // is the start point
+    let aabb_index = {
+        let mut builder = StaticAABB2DIndexBuilder::new(2 * segments.len());
+        for seg in segments.iter() {
+            builder.add(seg.start.x, seg.start.y, seg.start.x, seg.start.y);
+            builder.add(seg.end.x, seg.end.y, seg.end.x, seg.end.y);
+        }
+        builder.build().unwrap()
+    };
+
+    let mut visited = vec![false; segments.len()];
+    let mut query_stack = Vec::new();
+
+    // find the lowest index unvisited segment with an end point at `pt`, returns the segment
+    // oriented so that it starts at `pt` if `starts_at` is true otherwise oriented so it ends at
+    // `pt`
+    let mut find_connected = |pt: Vector2<T>, starts_at: bool, visited: &mut [bool]| {
+        let mut found: Option<usize> = None;
+        aabb_index.visit_query_with_stack(
+            pt.x - pos_equal_eps,
+            pt.y - pos_equal_eps,
+            pt.x + pos_equal_eps,
+            pt.y + pos_equal_eps,
+            &mut |i: usize| {
+                if !visited[i / 2] && found.is_none_or(|f| i < f) {
+                    found = Some(i);
+                }
+            },
+            &mut query_stack,
+        );
+
+        found.map(|i| {
+            visited[i / 2] = true;
+            let seg = segments[i / 2];
+            let is_start_pt = i % 2 == 0;
+            if is_start_pt == starts_at {
+                seg
+            } else {
+                seg.reversed()
+            }
+        })
+    };
+
+    for i in 0..segments.len() {
+        if visited[i] {
+            continue;
+        }
+        visited[i] = true;
+
+        let first = segments[i];
+        let mut forward = vec![first];
+        let mut is_closed = false;
+        loop {
+            let end = forward.last().unwrap().end;
+            if end.fuzzy_eq_eps(first.start, pos_equal_eps) && (forward.len() > 1) {
+                is_closed = true;
+                break;
+            }
+            match find_connected(end, true, &mut visited) {
+                Some(seg) => forward.push(seg),
+                None => break,
+            }
+        }
+
+        let mut backward = Vec::new();
+        if !is_closed {
+            let mut start = first.start;
+            while let Some(seg) = find_connected(start, false, &mut visited) {
+                start = seg.start;
+                backward.push(seg);
+            }
+        }
+
+        let mut pline = Polyline::with_capacity(backward.len() + forward.len() + 1, is_closed);
+        for seg in backward.iter().rev().chain(forward.iter()) {
+            pline.add(seg.start.x, seg.start.y, seg.bulge);
+        }
+        if !is_closed {
+            let end = forward.last().unwrap().end;
+            pline.add(end.x, end.y, T::zero());
+        }
+
+        result.push(pline);
+    }
+
+    result
+}
@@ -0,0 +1,83 @@
+use std::fmt::Write;
+
+use crate::{core::math::to_f64, polyline::PlineSource};
+
+/// Write polylines to an ASCII DXF string with each polyline written as a `LWPOLYLINE` entity.
+///
+/// Vertex positions, bulges, and closed flags are preserved. The output is a minimal AutoCAD 2000
+/// (`$ACADVER` `AC1015`) DXF document containing a `HEADER` section and an `ENTITIES` section (all
+/// entities are placed on layer "0" and given sequential handles), empty polylines are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_closed;
+/// # use cavalier_contours::dxf::*;
+/// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+/// let dxf_str = write_dxf([&circle]);
+/// let read_back: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+/// assert_eq!(read_back.len(), 1);
+/// assert!(read_back[0].fuzzy_eq(&circle));
+/// ```
+pub fn write_dxf<'a, P, I>(plines: I) -> String
+where
+    P: PlineSource + ?Sized + 'a,
+    I: IntoIterator<Item = &'a P>,
+{
+    let mut entities = String::new();
+    // handle 0 is not valid, start at 1
+    let mut next_handle = 1u64;
+    for pline in plines {
+        if pline.vertex_count() == 0 {
+            continue;
+        }
+        write_lwpolyline(&mut entities, pline, next_handle);
+        next_handle += 1;
+    }
+
+    let mut output = String::new();
+    write_group(&mut output, 0, "SECTION");
+    write_group(&mut output, 2, "HEADER");
+    write_group(&mut output, 9, "$ACADVER");
+    write_group(&mut output, 1, "AC1015");
+    // handle seed must be greater than all handles used
+    write_group(&mut output, 9, "$HANDSEED");
+    write_group(&mut output, 5, format_args!("{next_handle:X}"));
+    write_group(&mut output, 0, "ENDSEC");
+    write_group(&mut output, 0, "SECTION");
+    write_group(&mut output, 2, "ENTITIES");
+    output.push_str(&entities);
+    write_group(&mut output, 0, "ENDSEC");
+    write_group(&mut output, 0, "EOF");
+    output
+}
+
+fn write_lwpolyline<P>(output: &mut String, pline: &P, handle: u64)
+where
+    P: PlineSource + ?Sized,
+{
+    write_group(output, 0, "LWPOLYLINE");
+    write_group(output, 5, format_args!("{handle:X}"));
+    write_group(output, 100, "AcDbEntity");
+    write_group(output, 8, "0");
+    write_group(output, 100, "AcDbPolyline");
+    write_group(output, 90, pline.vertex_count());
+    write_group(output, 70, if pline.is_closed() { 1 } else { 0 });
+    for v in pline.iter_vertexes() {
+        write_group(output, 10, to_f64(v.x));
+        write_group(output, 20, to_f64(v.y));
+        if !v.bulge_is_zero() {
+            write_group(output, 42, to_f64(v.bulge));
+        }
+    }
+}
+
+#[inline]
+fn write_group<V>(output: &mut String, group_code: i32, value: V)
+where
+    V: std::fmt::Display,
+{
+    // writing to a String never fails
+    writeln!(output, "{group_code:>3}\n{value}").unwrap();
+}
@@ -3,6 +3,16 @@
 //! Not expected to be used directly as part of the library but may be used to help learn about the
 //! algorithms.
 pub mod pline_boolean;
+pub mod pline_clip;
+pub mod pline_closest_point;
 pub mod pline_contains;
+pub mod pline_convex_hull;
+pub mod pline_corners;
+pub mod pline_feature_width;
+pub mod pline_fit_arcs;
 pub mod pline_intersects;
 pub mod pline_offset;
+pub mod pline_resolve;
+pub mod pline_simplify;
+pub mod pline_validate;
+pub mod pline_variable_offset;
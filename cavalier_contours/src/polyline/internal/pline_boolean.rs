@@ -8,12 +8,13 @@ use crate::{
 };
 use std::collections::BTreeMap;
 
-use super::pline_intersects::{
-    OverlappingSlice, find_intersects, sort_and_join_overlapping_intersects,
+use super::{
+    pline_intersects::{OverlappingSlice, find_intersects, sort_and_join_overlapping_intersects},
+    pline_offset::ScratchBuffers,
 };
 use crate::{
     core::{math::Vector2, traits::Real},
-    polyline::PlineOrientation,
+    polyline::{OffsetContext, PlineOrientation},
 };
 use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
 
@@ -345,7 +346,40 @@ where
     F: FnMut(Vector2<T>) -> bool,
     U: FnMut(Vector2<T>) -> bool,
 {
-    let mut slices_remaining = Vec::new();
+    prune_slices_into(
+        pline1,
+        pline2,
+        boolean_info,
+        pline1_point_on_slice_pred,
+        pline2_point_on_slice_pred,
+        set_opposing_direction,
+        pos_equal_eps,
+        Vec::new(),
+    )
+}
+
+/// Same as [prune_slices] but `buffer` is cleared and used to hold the remaining slices (allows
+/// reusing the allocation from a previous call).
+#[allow(clippy::too_many_arguments)]
+pub fn prune_slices_into<P, R, T, F, U>(
+    pline1: &P,
+    pline2: &R,
+    boolean_info: &ProcessForBooleanResult<T>,
+    pline1_point_on_slice_pred: &mut F,
+    pline2_point_on_slice_pred: &mut U,
+    set_opposing_direction: bool,
+    pos_equal_eps: T,
+    buffer: Vec<BooleanPlineSlice<T>>,
+) -> PrunedSlices<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    F: FnMut(Vector2<T>) -> bool,
+    U: FnMut(Vector2<T>) -> bool,
+{
+    let mut slices_remaining = buffer;
+    slices_remaining.clear();
     // slice pline1
     slice_at_intersects(
         pline1,
@@ -552,6 +586,53 @@ impl StitchSelector for NotXorStitchSelector {
     }
 }
 
+/// Remove slices that repeat another slice (coincident boundaries with the same direction).
+/// `end_points` returns the start and end point of a slice in its direction of travel, `midpoints`
+/// holds the midpoint of the first segment of each slice. Slices are compared by sweeping over them
+/// sorted by start point x.
+pub(crate) fn remove_repeat_slices<S, T, F>(
+    slices: Vec<S>,
+    midpoints: &[Vector2<T>],
+    eps: T,
+    end_points: F,
+) -> Vec<S>
+where
+    T: Real,
+    F: Fn(&S) -> (Vector2<T>, Vector2<T>),
+{
+    let ends: Vec<_> = slices.iter().map(end_points).collect();
+    let mut sorted: Vec<usize> = (0..slices.len()).collect();
+    sorted.sort_unstable_by(|&a, &b| ends[a].0.x.total_cmp(&ends[b].0.x));
+
+    let mut is_repeat = vec![false; slices.len()];
+    for (k, &a) in sorted.iter().enumerate() {
+        if is_repeat[a] {
+            continue;
+        }
+
+        let (a_start, a_end) = ends[a];
+        for &b in sorted[k + 1..].iter() {
+            let (b_start, b_end) = ends[b];
+            if b_start.x - a_start.x > eps {
+                break;
+            }
+
+            if a_start.fuzzy_eq_eps(b_start, eps)
+                && a_end.fuzzy_eq_eps(b_end, eps)
+                && midpoints[a].fuzzy_eq_eps(midpoints[b], eps)
+            {
+                is_repeat[b] = true;
+            }
+        }
+    }
+
+    slices
+        .into_iter()
+        .zip(is_repeat)
+        .filter_map(|(s, repeat)| (!repeat).then_some(s))
+        .collect()
+}
+
 /// Stitches open polyline slices together into closed polylines. The open polylines must be
 /// ordered/agree on direction (every start point connects with an end point). `stitch_selector` is
 /// used to determine priority of stitching in the case multiple possibilities exist.
@@ -562,6 +643,33 @@ pub fn stitch_slices_into_closed_polylines<P, R, T, S, O>(
     stitch_selector: &S,
     pos_equal_eps: T,
 ) -> Vec<BooleanResultPline<O>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    S: StitchSelector,
+    O: PlineCreation<Num = T>,
+{
+    stitch_slices_into_closed_polylines_with(
+        slices,
+        source_pline1,
+        source_pline2,
+        stitch_selector,
+        pos_equal_eps,
+        &mut ScratchBuffers::new(),
+    )
+}
+
+/// Same as [stitch_slices_into_closed_polylines] but `scratch` is used for the intermediate
+/// buffers.
+pub fn stitch_slices_into_closed_polylines_with<P, R, T, S, O>(
+    slices: &[BooleanPlineSlice<T>],
+    source_pline1: &P,
+    source_pline2: &R,
+    stitch_selector: &S,
+    pos_equal_eps: T,
+    scratch: &mut ScratchBuffers<T>,
+) -> Vec<BooleanResultPline<O>>
 where
     P: PlineSource<Num = T> + ?Sized,
     R: PlineSource<Num = T> + ?Sized,
@@ -590,7 +698,14 @@ where
         builder.build().unwrap()
     };
 
-    let mut visited_slice_idx = vec![false; slices.len()];
+    let ScratchBuffers {
+        visited: visited_slice_idx,
+        query_results,
+        query_stack,
+        ..
+    } = scratch;
+    visited_slice_idx.clear();
+    visited_slice_idx.resize(slices.len(), false);
 
     let mut close_pline = |mut pline: O, subslices: Vec<BooleanPlineSlice<T>>| {
         // sanity assert (start should connect back with end)
@@ -610,9 +725,6 @@ where
         result.push(BooleanResultPline::new(pline, subslices));
     };
 
-    let mut query_results = Vec::new();
-    let mut query_stack = Vec::with_capacity(8);
-
     let slice_to_pline = |s: &BooleanPlineSlice<T>| {
         if s.source_is_pline1 {
             O::create_from_remove_repeat(&s.view(source_pline1), pos_equal_eps)
@@ -668,7 +780,7 @@ where
                 ep.x + pos_equal_eps,
                 ep.y + pos_equal_eps,
                 &mut query_visitor,
-                &mut query_stack,
+                query_stack,
             );
 
             if query_results.is_empty() {
@@ -677,7 +789,7 @@ where
                 break;
             }
 
-            match stitch_selector.select(current_slice_idx, &query_results) {
+            match stitch_selector.select(current_slice_idx, query_results) {
                 None => {
                     // discard current polyline
                     break;
@@ -751,13 +863,14 @@ where
     let mut point_in_pline1 = |point: Vector2<T>| pline1.winding_number(point) != 0;
     let mut point_in_pline2 = |point: Vector2<T>| pline2.winding_number(point) != 0;
 
-    // helper functions (assuming no intersects between pline1 and pline2)
-    let is_pline1_in_pline2 = || point_in_pline2(pline1.at(0).pos());
-    let is_pline2_in_pline1 = || point_in_pline1(pline2.at(0).pos());
+    // helper functions (assuming no intersects between pline1 and pline2), the point in pline
+    // helpers only hold references so are copied into these closures
+    let is_pline1_in_pline2 = move || point_in_pline2(pline1.at(0).pos());
+    let is_pline2_in_pline1 = move || point_in_pline1(pline2.at(0).pos());
 
     let pos_equal_eps = options.pos_equal_eps;
 
-    match operation {
+    OffsetContext::with_buffers(options.context, |buffers| match operation {
         BooleanOp::Or => {
             if boolean_info.completely_overlapping() {
                 // pline1 completely overlapping pline2 just return pline2
@@ -791,7 +904,7 @@ where
             } else {
                 // keep all slices of pline1 that are not in pline2 and all slices of pline2 that
                 // are not in pline1
-                let pruned_slices = prune_slices(
+                let pruned_slices = prune_slices_into(
                     pline1,
                     pline2,
                     &boolean_info,
@@ -799,17 +912,21 @@ where
                     &mut |pt| !point_in_pline1(pt),
                     false,
                     pos_equal_eps,
+                    std::mem::take(&mut buffers.boolean_slices),
                 );
 
                 let stitch_selector = OrAndStitchSelector::from_pruned_slices(&pruned_slices);
 
-                let remaining: Vec<BooleanResultPline<O>> = stitch_slices_into_closed_polylines(
-                    &pruned_slices.slices_remaining,
-                    pline1,
-                    pline2,
-                    &stitch_selector,
-                    pos_equal_eps,
-                );
+                let remaining: Vec<BooleanResultPline<O>> =
+                    stitch_slices_into_closed_polylines_with(
+                        &pruned_slices.slices_remaining,
+                        pline1,
+                        pline2,
+                        &stitch_selector,
+                        pos_equal_eps,
+                        &mut buffers.scratch,
+                    );
+                buffers.boolean_slices = pruned_slices.slices_remaining;
 
                 let mut pos_plines = Vec::new();
                 let mut neg_plines = Vec::new();
@@ -858,7 +975,7 @@ where
             } else {
                 // keep all slices from pline1 that are in pline2 and all slices from pline2 that
                 // are in pline1
-                let pruned_slices = prune_slices(
+                let pruned_slices = prune_slices_into(
                     pline1,
                     pline2,
                     &boolean_info,
@@ -866,16 +983,19 @@ where
                     &mut point_in_pline1,
                     false,
                     pos_equal_eps,
+                    std::mem::take(&mut buffers.boolean_slices),
                 );
 
                 let stitch_selector = OrAndStitchSelector::from_pruned_slices(&pruned_slices);
-                let pos_plines = stitch_slices_into_closed_polylines(
+                let pos_plines = stitch_slices_into_closed_polylines_with(
                     &pruned_slices.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector,
                     pos_equal_eps,
+                    &mut buffers.scratch,
                 );
+                buffers.boolean_slices = pruned_slices.slices_remaining;
 
                 BooleanResult::new(pos_plines, Vec::new(), BooleanResultInfo::Intersected)
             }
@@ -906,7 +1026,7 @@ where
             } else {
                 // keep all slices from pline1 that are not in pline2 and all slices on pline2 that
                 // are in pline1
-                let pruned_slices = prune_slices(
+                let pruned_slices = prune_slices_into(
                     pline1,
                     pline2,
                     &boolean_info,
@@ -914,17 +1034,20 @@ where
                     &mut point_in_pline1,
                     true,
                     pos_equal_eps,
+                    std::mem::take(&mut buffers.boolean_slices),
                 );
 
                 let stitch_selector = NotXorStitchSelector::from_pruned_slices(&pruned_slices);
 
-                let pos_plines = stitch_slices_into_closed_polylines(
+                let pos_plines = stitch_slices_into_closed_polylines_with(
                     &pruned_slices.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector,
                     pos_equal_eps,
+                    &mut buffers.scratch,
                 );
+                buffers.boolean_slices = pruned_slices.slices_remaining;
 
                 BooleanResult::new(pos_plines, Vec::new(), BooleanResultInfo::Intersected)
             }
@@ -955,7 +1078,7 @@ where
                 }
             } else {
                 // collect pline1 NOT pline2 results
-                let pruned_slices1 = prune_slices(
+                let pruned_slices1 = prune_slices_into(
                     pline1,
                     pline2,
                     &boolean_info,
@@ -963,19 +1086,22 @@ where
                     &mut point_in_pline1,
                     true,
                     pos_equal_eps,
+                    std::mem::take(&mut buffers.boolean_slices),
                 );
 
                 let stitch_selector1 = NotXorStitchSelector::from_pruned_slices(&pruned_slices1);
-                let mut remaining1 = stitch_slices_into_closed_polylines(
+                let mut remaining1 = stitch_slices_into_closed_polylines_with(
                     &pruned_slices1.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector1,
                     pos_equal_eps,
+                    &mut buffers.scratch,
                 );
+                buffers.boolean_slices = pruned_slices1.slices_remaining;
 
                 // collect pline2 NOT pline1 results
-                let pruned_slices2 = prune_slices(
+                let pruned_slices2 = prune_slices_into(
                     pline1,
                     pline2,
                     &boolean_info,
@@ -983,20 +1109,23 @@ where
                     &mut |pt| !point_in_pline1(pt),
                     true,
                     pos_equal_eps,
+                    std::mem::take(&mut buffers.boolean_slices),
                 );
 
                 let stitch_selector2 = NotXorStitchSelector::from_pruned_slices(&pruned_slices2);
-                let remaining2 = stitch_slices_into_closed_polylines(
+                let remaining2 = stitch_slices_into_closed_polylines_with(
                     &pruned_slices2.slices_remaining,
                     pline1,
                     pline2,
                     &stitch_selector2,
                     pos_equal_eps,
+                    &mut buffers.scratch,
                 );
+                buffers.boolean_slices = pruned_slices2.slices_remaining;
 
                 remaining1.extend(remaining2);
                 BooleanResult::new(remaining1, Vec::new(), BooleanResultInfo::Intersected)
             }
         }
-    }
+    })
 }
@@ -0,0 +1,204 @@
+//! Clipping polylines by a region (returning the slices inside, outside, and on the boundary).
+use static_aabb2d_index::StaticAABB2DIndex;
+
+use crate::{
+    core::{
+        math::{Vector2, dist_squared},
+        traits::Real,
+    },
+    polyline::{
+        ClipLocation, ClipResultPline, FindIntersectsOptions, PlineClipOptions, PlineClipResult,
+        PlineCreation, PlineSource, PlineViewData, seg_closest_point, seg_midpoint,
+    },
+};
+
+/// Slice `polyline` at the `points` given (segment index and position pairs) and add each slice to
+/// the result using `locate` to determine its location. `locate` is called with the midpoint of the
+/// first segment of the slice.
+///
+/// If `polyline` is open the first slice starts at the first vertex and the last slice ends at the
+/// last vertex, if `polyline` is closed and there are less than 2 points then the entire polyline
+/// is added as a single slice.
+pub fn clip_at_points<P, T, O, F>(
+    polyline: &P,
+    points: &mut Vec<(usize, Vector2<T>)>,
+    pos_equal_eps: T,
+    mut locate: F,
+) -> PlineClipResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+    F: FnMut(Vector2<T>) -> ClipLocation,
+{
+    let mut result = PlineClipResult::new();
+    let vc = polyline.vertex_count();
+    if vc < 2 {
+        return result;
+    }
+
+    // points at the end of a segment use the next segment index (if there is a next segment)
+    for (seg_idx, pos) in points.iter_mut() {
+        let next_idx = polyline.next_wrapping_index(*seg_idx);
+        if (polyline.is_closed() || next_idx + 1 < vc)
+            && pos.fuzzy_eq_eps(polyline.at(next_idx).pos(), pos_equal_eps)
+        {
+            *seg_idx = next_idx;
+        }
+    }
+
+    // sort by the segment index, then if both points on the same segment sort by distance from
+    // start of segment
+    points.sort_unstable_by(|a, b| {
+        a.0.cmp(&b.0).then_with(|| {
+            let seg_start = polyline.at(a.0).pos();
+            let dist1 = dist_squared(a.1, seg_start);
+            let dist2 = dist_squared(b.1, seg_start);
+            dist1.total_cmp(&dist2)
+        })
+    });
+    points.dedup_by(|a, b| a.0 == b.0 && a.1.fuzzy_eq_eps(b.1, pos_equal_eps));
+
+    let mut add_slice = |v_data: PlineViewData<T>| {
+        let view = v_data.view(polyline);
+        let midpoint = seg_midpoint(view.at(0), view.at(1));
+        let location = locate(midpoint);
+        let pline = O::create_from_remove_repeat(&view, pos_equal_eps);
+        result.push(location, ClipResultPline::new(pline, v_data));
+    };
+
+    if polyline.is_closed() {
+        if points.len() < 2 {
+            add_slice(PlineViewData::from_entire_pline(polyline));
+            return result;
+        }
+
+        let create_slice = |pt1: (usize, Vector2<T>), pt2: (usize, Vector2<T>)| {
+            PlineViewData::from_slice_points(polyline, pt1.1, pt1.0, pt2.1, pt2.0, pos_equal_eps)
+        };
+
+        for w in points.windows(2) {
+            if let Some(v_data) = create_slice(w[0], w[1]) {
+                add_slice(v_data);
+            }
+        }
+
+        // slice from last point back around to first point
+        if let Some(v_data) = create_slice(*points.last().unwrap(), points[0]) {
+            add_slice(v_data);
+        }
+
+        return result;
+    }
+
+    // open polyline, slices between the start, all the points, and the end
+    let start = (0, polyline.at(0).pos());
+    let end = (vc - 2, polyline.last().unwrap().pos());
+    let mut prev = start;
+    for &pt in points.iter().chain(std::iter::once(&end)) {
+        if let Some(v_data) =
+            PlineViewData::from_slice_points(polyline, prev.1, prev.0, pt.1, pt.0, pos_equal_eps)
+        {
+            add_slice(v_data);
+        }
+        prev = pt;
+    }
+
+    result
+}
+
+/// Returns true if `point` lies on `polyline` (within `eps`), `aabb_index` is the spatial index of
+/// the `polyline` segments.
+pub fn point_on_pline<P, T>(
+    polyline: &P,
+    aabb_index: &StaticAABB2DIndex<T>,
+    point: Vector2<T>,
+    eps: T,
+    query_stack: &mut Vec<usize>,
+) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    aabb_index
+        .query_with_stack(
+            point.x - eps,
+            point.y - eps,
+            point.x + eps,
+            point.y + eps,
+            query_stack,
+        )
+        .into_iter()
+        .any(|seg_idx| {
+            let v1 = polyline.at(seg_idx);
+            let v2 = polyline.at(polyline.next_wrapping_index(seg_idx));
+            let closest_point = seg_closest_point(v1, v2, point, eps);
+            dist_squared(closest_point, point) < eps * eps
+        })
+}
+
+/// Clip `polyline` by the area of the closed polyline `region`.
+///
+/// See [PlineSource::clip_opt] for more information.
+pub fn clip<P, R, T, O>(
+    polyline: &P,
+    region: &R,
+    options: &PlineClipOptions<T>,
+) -> PlineClipResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    if polyline.vertex_count() < 2 {
+        return PlineClipResult::new();
+    }
+
+    let mut points = Vec::new();
+    if region.vertex_count() > 1 {
+        let intrs_opts = FindIntersectsOptions {
+            pline1_aabb_index: options.pline1_aabb_index,
+            pos_equal_eps,
+        };
+
+        let intersects = polyline.find_intersects_opt(region, &intrs_opts);
+        points.extend(
+            intersects
+                .basic_intersects
+                .iter()
+                .map(|intr| (intr.start_index1, intr.point)),
+        );
+
+        for intr in intersects.overlapping_intersects.iter() {
+            points.push((intr.start_index1, intr.point1));
+            points.push((intr.start_index1, intr.point2));
+        }
+    }
+
+    let constructed_index;
+    let region_index = match options.region_aabb_index {
+        Some(x) => Some(x),
+        None if region.vertex_count() > 1 => {
+            constructed_index = region.create_approx_aabb_index();
+            Some(&constructed_index)
+        }
+        None => None,
+    };
+
+    let mut query_stack = Vec::new();
+    clip_at_points(polyline, &mut points, pos_equal_eps, |midpoint| {
+        let on_boundary = region_index.is_some_and(|index| {
+            point_on_pline(region, index, midpoint, pos_equal_eps, &mut query_stack)
+        });
+
+        if on_boundary {
+            ClipLocation::OnBoundary
+        } else if region.is_closed() && region.winding_number(midpoint) != 0 {
+            ClipLocation::Inside
+        } else {
+            ClipLocation::Outside
+        }
+    })
+}
@@ -0,0 +1,55 @@
+use static_aabb2d_index::{Control, StaticAABB2DIndex};
+
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{ClosestPointResult, PlineSource, seg_closest_point},
+};
+
+/// Find the closest point on `polyline` to `point` using `aabb_index` (spatial index of the
+/// polyline segments, e.g. from [PlineSource::create_approx_aabb_index]) to skip segments that
+/// cannot be closer than the closest point found so far.
+///
+/// Segments are visited in order of bounding box distance to `point` so for large polylines only a
+/// few segments are tested. Returns `None` if the polyline is empty.
+pub fn closest_point_with_index<P, T>(
+    polyline: &P,
+    aabb_index: &StaticAABB2DIndex<T>,
+    point: Vector2<T>,
+    pos_equal_eps: T,
+) -> Option<ClosestPointResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if polyline.vertex_count() < 2 {
+        return polyline.closest_point(point, pos_equal_eps);
+    }
+
+    let mut result: Option<ClosestPointResult<T>> = None;
+    let mut visitor = |i: usize, box_dist_squared: T| {
+        if let Some(r) = &result
+            && box_dist_squared > r.distance * r.distance
+        {
+            // all remaining segments are further away
+            return Control::Break(());
+        }
+
+        let v1 = polyline.at(i);
+        let v2 = polyline.at(polyline.next_wrapping_index(i));
+        let cp = seg_closest_point(v1, v2, point, pos_equal_eps);
+        let distance = (point - cp).length();
+        if result.as_ref().is_none_or(|r| distance < r.distance) {
+            result = Some(ClosestPointResult {
+                seg_start_index: i,
+                seg_point: cp,
+                distance,
+            });
+        }
+
+        Control::Continue
+    };
+
+    aabb_index.visit_neighbors(point.x, point.y, &mut visitor);
+
+    result
+}
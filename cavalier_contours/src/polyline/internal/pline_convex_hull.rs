@@ -0,0 +1,375 @@
+use crate::{
+    core::{
+        math::{Vector2, angle, angle_from_bulge, bulge_from_angle, normalize_radians},
+        traits::Real,
+    },
+    polyline::{PlineCreation, PlineSource, PlineVertex, Polyline, seg_arc_radius_and_center},
+};
+
+/// Compute the convex hull of all the given polylines.
+///
+/// Returns a closed counter clockwise polyline, arc segments (or the portions of arc segments) that
+/// lie on the hull are kept as arcs and are joined to the rest of the hull by tangent lines. Both
+/// open and closed polylines may be given, for closed polylines the closing segment is included.
+///
+/// If no vertexes are given then an empty closed polyline is returned, if all the vertexes lie on a
+/// line (and there are no arcs) then the two end points of the line are returned.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::traits::*;
+/// # use cavalier_contours::pline_closed;
+/// let square = pline_closed![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0), (0.0, 2.0, 0.0)];
+/// // circle with radius 1 to the right of the square
+/// let circle = pline_closed![(3.0, 1.0, 1.0), (5.0, 1.0, 1.0)];
+/// let hull = plines_convex_hull([&square, &circle]);
+/// assert!(hull.is_closed());
+/// // right half of the circle is kept (as portions of the two circle arcs)
+/// assert!(hull.area().fuzzy_eq(8.0 + std::f64::consts::PI / 2.0));
+/// assert!(hull.extents().unwrap().max_x.fuzzy_eq(5.0));
+/// assert_eq!(hull.iter_vertexes().filter(|v| v.bulge_is_pos()).count(), 2);
+/// ```
+pub fn plines_convex_hull<'a, P, T, I>(plines: I) -> Polyline<T>
+where
+    I: IntoIterator<Item = &'a P>,
+    P: PlineSource<Num = T> + ?Sized + 'a,
+    T: Real,
+{
+    convex_hull(plines)
+}
+
+/// Point or arc that may be part of the convex hull boundary, points are represented as a circle
+/// with zero radius.
+#[derive(Debug, Clone, Copy)]
+struct HullSite<T> {
+    center: Vector2<T>,
+    radius: T,
+    /// Start angle of the counter clockwise arc sweep (unused for points).
+    start_angle: T,
+    /// Counter clockwise sweep angle of the arc (unused for points).
+    sweep_angle: T,
+    /// Index of the point site at the end of the counter clockwise arc sweep (unused for points).
+    end_site: usize,
+}
+
+impl<T> HullSite<T>
+where
+    T: Real,
+{
+    fn point(pos: Vector2<T>) -> Self {
+        Self {
+            center: pos,
+            radius: T::zero(),
+            start_angle: T::zero(),
+            sweep_angle: T::zero(),
+            end_site: 0,
+        }
+    }
+
+    #[inline]
+    fn is_arc(&self) -> bool {
+        self.radius > T::zero()
+    }
+
+    /// Point on the site where the outward normal has `normal_angle`.
+    #[inline]
+    fn contact(&self, normal_angle: T) -> Vector2<T> {
+        let (sin, cos) = normal_angle.sin_cos();
+        self.center + Vector2::new(cos, sin).scale(self.radius)
+    }
+
+    /// Sweep angle remaining on the arc after `normal_angle`, negative if `normal_angle` is not
+    /// within the arc sweep (always zero for points).
+    fn remaining_sweep(&self, normal_angle: T, eps: T) -> T {
+        if !self.is_arc() {
+            return T::zero();
+        }
+        let mut offset = normalize_radians(normal_angle - self.start_angle);
+        if offset > T::tau() - eps {
+            offset = T::zero();
+        }
+        self.sweep_angle - offset
+    }
+
+    /// Outward normal angle of the line tangent to both this site and `other` such that both
+    /// sites lie to the left when going from this site to `other`. Returns `None` if no such line
+    /// exists (one site is inside the other or they are concentric), or if `other` is inside and
+    /// touching this site (the line only touches `other` where it touches this site).
+    fn tangent_angle(&self, other: &Self, eps: T) -> Option<T> {
+        let d = other.center - self.center;
+        let dist = d.length();
+        if dist < eps {
+            return None;
+        }
+        let radius_diff = self.radius - other.radius;
+        if dist < radius_diff.abs() + eps {
+            // one site inside the other, if this site touches the inside of `other` the line
+            // touches at the point through the centers (acos is too sensitive to round off here)
+            return (radius_diff < T::zero() && dist > radius_diff.abs() - eps)
+                .then(|| normalize_radians(d.y.atan2(d.x) + T::pi()));
+        }
+        Some(normalize_radians(
+            d.y.atan2(d.x) - (radius_diff / dist).acos(),
+        ))
+    }
+}
+
+/// Convex hull of the points (counter clockwise, collinear points removed) using the monotone
+/// chain algorithm.
+fn point_hull<T>(mut points: Vec<Vector2<T>>, eps: T) -> Vec<Vector2<T>>
+where
+    T: Real,
+{
+    points.sort_unstable_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup_by(|a, b| a.fuzzy_eq_eps(*b, eps));
+    if points.len() < 3 {
+        return points;
+    }
+
+    let turns_left =
+        |a: Vector2<T>, b: Vector2<T>, c: Vector2<T>| (b - a).perp_dot(c - a) > T::zero();
+    let mut hull: Vec<Vector2<T>> = Vec::with_capacity(points.len() + 1);
+    // lower hull
+    for &p in points.iter() {
+        while hull.len() >= 2 && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // upper hull
+    let lower_len = hull.len() + 1;
+    for &p in points.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], p)
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // last point is the same as the first
+    hull.pop();
+    hull
+}
+
+/// Compute the convex hull of the polylines, see [plines_convex_hull].
+pub(crate) fn convex_hull<'a, P, T, O, I>(plines: I) -> O
+where
+    I: IntoIterator<Item = &'a P>,
+    P: PlineSource<Num = T> + ?Sized + 'a,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let eps = T::fuzzy_epsilon();
+    let mut points = Vec::new();
+    // (center, radius, ccw start angle, ccw sweep angle, ccw end point)
+    let mut arcs = Vec::new();
+    for pline in plines {
+        points.extend(pline.iter_vertexes().map(|v| v.pos()));
+        for (v1, v2) in pline.iter_segments() {
+            if v1.bulge_is_zero() || v1.pos().fuzzy_eq_eps(v2.pos(), eps) {
+                continue;
+            }
+            let (radius, center) = seg_arc_radius_and_center(v1, v2);
+            let sweep_angle = angle_from_bulge(v1.bulge).abs();
+            let (start, end) = if v1.bulge_is_pos() {
+                (v1.pos(), v2.pos())
+            } else {
+                (v2.pos(), v1.pos())
+            };
+            arcs.push((center, radius, angle(center, start), sweep_angle, end));
+        }
+    }
+
+    // vertexes inside the hull of all vertexes can never be on the hull (arcs only add area)
+    let hull_points = point_hull(points, eps);
+
+    if arcs.is_empty() || hull_points.is_empty() {
+        let mut result = O::with_capacity(hull_points.len(), true);
+        for p in hull_points {
+            result.add_vertex(PlineVertex::from_vector2(p, T::zero()));
+        }
+        return result;
+    }
+
+    let mut sites = hull_points
+        .into_iter()
+        .map(HullSite::point)
+        .collect::<Vec<_>>();
+    for (center, radius, start_angle, sweep_angle, end) in arcs {
+        sites.push(HullSite::point(end));
+        sites.push(HullSite {
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+            end_site: sites.len() - 1,
+        });
+    }
+
+    // start at the lowest (then left most) point on the hull
+    let start_normal_angle = normalize_radians(-T::pi() / T::two());
+    let (start_site, start_pos) = sites
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.remaining_sweep(start_normal_angle, eps) >= -eps)
+        .map(|(i, s)| (i, s.contact(start_normal_angle)))
+        .min_by(|(_, a), (_, b)| {
+            if a.y.fuzzy_eq_eps(b.y, eps) {
+                a.x.total_cmp(&b.x)
+            } else {
+                a.y.total_cmp(&b.y)
+            }
+        })
+        .unwrap();
+
+    let mut vertexes: Vec<PlineVertex<T>> = Vec::new();
+    let mut push = |pos: Vector2<T>, bulge: T| match vertexes.last_mut() {
+        // zero length segment, replace it
+        Some(last) if last.pos().fuzzy_eq_eps(pos, eps) => last.bulge = bulge,
+        _ => vertexes.push(PlineVertex::from_vector2(pos, bulge)),
+    };
+
+    // start point is part way along an arc (or at its start) rather than at its end
+    let starts_on_arc = sites[start_site].remaining_sweep(start_normal_angle, eps) > eps;
+    let mut ended_on_start_arc = false;
+    let mut site_idx = start_site;
+    let mut normal_angle = start_normal_angle;
+    let mut pos = start_pos;
+    // set once the walk moves away from the start position
+    let mut left_start = false;
+    // each site should only be visited once, limit iterations in case of round off problems
+    for _ in 0..(2 * sites.len() + 4) {
+        let site = sites[site_idx];
+        let remaining = site.remaining_sweep(normal_angle, eps);
+
+        // find the next site the supporting line touches as it rotates counter clockwise
+        let mut next: Option<(usize, T, T, Vector2<T>)> = None;
+        for (i, other) in sites.iter().enumerate() {
+            if i == site_idx {
+                continue;
+            }
+            let Some(tangent_angle) = site.tangent_angle(other, eps) else {
+                continue;
+            };
+            let mut delta = normalize_radians(tangent_angle - normal_angle);
+            if delta > T::tau() - eps {
+                delta = T::zero();
+            }
+            if site.is_arc() && delta > remaining + eps {
+                // tangent point is not on the current arc
+                continue;
+            }
+            let other_remaining = other.remaining_sweep(tangent_angle, eps);
+            if other_remaining < -eps {
+                continue;
+            }
+            let contact = other.contact(tangent_angle);
+            if contact.fuzzy_eq_eps(pos, eps)
+                && (other_remaining <= eps || (delta <= eps && other.radius <= site.radius + eps))
+            {
+                // same position with nowhere to go, or an arc tangent to the inside of the
+                // current arc
+                continue;
+            }
+
+            let is_better =
+                next.as_ref()
+                    .is_none_or(|&(best_idx, best_delta, best_angle, best)| {
+                        if !delta.fuzzy_eq_eps(best_delta, eps) {
+                            return delta < best_delta;
+                        }
+                        if contact.fuzzy_eq_eps(best, eps) {
+                            // touching at the same point, the largest arc lies outside the others
+                            return other.radius > sites[best_idx].radius + eps;
+                        }
+                        // touching the same line, take the furthest along it
+                        let (sin, cos) = best_angle.sin_cos();
+                        let dir = Vector2::new(-sin, cos);
+                        contact.dot(dir) > best.dot(dir)
+                    });
+            if is_better {
+                next = Some((i, delta, tangent_angle, contact));
+            }
+        }
+
+        if site.is_arc() {
+            if left_start
+                && (start_pos - site.center)
+                    .length()
+                    .fuzzy_eq_eps(site.radius, eps)
+            {
+                let to_start = normalize_radians(angle(site.center, start_pos) - normal_angle);
+                if to_start <= remaining + eps
+                    && next
+                        .as_ref()
+                        .is_none_or(|&(_, d, _, _)| to_start <= d + eps)
+                {
+                    // arc passes back through the start
+                    push(pos, bulge_from_angle(to_start));
+                    ended_on_start_arc = site_idx == start_site && starts_on_arc;
+                    break;
+                }
+            }
+
+            if next
+                .as_ref()
+                .is_none_or(|&(_, d, _, _)| remaining <= d + eps)
+            {
+                // follow the arc to its end (the tangent line starts from the end point)
+                push(pos, bulge_from_angle(remaining));
+                site_idx = site.end_site;
+                normal_angle = normalize_radians(normal_angle + remaining);
+                pos = sites[site_idx].center;
+            } else {
+                let (i, delta, tangent_angle, contact) = next.unwrap();
+                push(pos, bulge_from_angle(delta));
+                let arc_end = site.contact(tangent_angle);
+                if left_start && arc_end.fuzzy_eq_eps(start_pos, eps) {
+                    break;
+                }
+                push(arc_end, T::zero());
+                site_idx = i;
+                normal_angle = tangent_angle;
+                pos = contact;
+            }
+        } else {
+            let Some((i, _, tangent_angle, contact)) = next else {
+                // only one distinct position
+                push(pos, T::zero());
+                break;
+            };
+            push(pos, T::zero());
+            site_idx = i;
+            normal_angle = tangent_angle;
+            pos = contact;
+        }
+
+        if !pos.fuzzy_eq_eps(start_pos, eps) {
+            left_start = true;
+        } else if left_start {
+            break;
+        }
+    }
+
+    if vertexes.len() > 1
+        && vertexes[0]
+            .pos()
+            .fuzzy_eq_eps(vertexes[vertexes.len() - 1].pos(), eps)
+    {
+        vertexes.pop();
+    }
+
+    if ended_on_start_arc && vertexes.len() > 2 && !vertexes[0].bulge_is_zero() {
+        // start point is part way along an arc, join the arc portions before and after it
+        let first = vertexes.remove(0);
+        let last = vertexes.last_mut().unwrap();
+        last.bulge = bulge_from_angle(angle_from_bulge(last.bulge) + angle_from_bulge(first.bulge));
+    }
+
+    let mut result = O::with_capacity(vertexes.len(), true);
+    for v in vertexes {
+        result.add_vertex(v);
+    }
+    result
+}
@@ -0,0 +1,466 @@
+//! Fillet and chamfer of polyline corners.
+use crate::{
+    core::{
+        math::{
+            CircleCircleIntr, LineCircleIntr, LineLineIntr, Vector2, angle, bulge_from_angle,
+            circle_circle_intr, delta_angle, line_circle_intr, line_line_intr, normalize_radians,
+            point_on_circle,
+        },
+        traits::Real,
+    },
+    polyline::{
+        CornerModifyResult, CornerSelector, PlineCreation, PlineSource, PlineVertex,
+        seg_arc_radius_and_center, seg_length, seg_split_at_point, seg_tangent_vector,
+    },
+};
+
+/// Replaces each selected corner of `polyline` with a tangent arc of the given `radius`.
+///
+/// See [PlineSource::fillet] for more information.
+pub fn fillet<P, T, O, S>(
+    polyline: &P,
+    radius: T,
+    selector: &mut S,
+    pos_equal_eps: T,
+) -> CornerModifyResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+    S: CornerSelector + ?Sized,
+{
+    modify_corners(polyline, radius, selector, pos_equal_eps, &fillet_cut)
+}
+
+/// Replaces each selected corner of `polyline` with a straight line cut at the given path
+/// `distance` from the corner along both adjacent segments.
+///
+/// See [PlineSource::chamfer] for more information.
+pub fn chamfer<P, T, O, S>(
+    polyline: &P,
+    distance: T,
+    selector: &mut S,
+    pos_equal_eps: T,
+) -> CornerModifyResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+    S: CornerSelector + ?Sized,
+{
+    modify_corners(polyline, distance, selector, pos_equal_eps, &chamfer_cut)
+}
+
+/// Cut made at a corner, `start` lies on the segment going into the corner and `end` lies on the
+/// segment going out of the corner, `bulge` is for the segment joining `start` to `end`.
+#[derive(Debug, Clone, Copy)]
+struct CornerCut<T> {
+    start: Vector2<T>,
+    end: Vector2<T>,
+    bulge: T,
+}
+
+/// Parameters passed to the function creating a corner cut.
+#[derive(Debug, Clone, Copy)]
+struct CornerCutInput<T> {
+    /// Segment going into the corner (`seg1.1` is the corner vertex).
+    seg1: (PlineVertex<T>, PlineVertex<T>),
+    /// Segment going out of the corner (`seg2.0` is the corner vertex).
+    seg2: (PlineVertex<T>, PlineVertex<T>),
+    /// Cut must start at or after this path length along `seg1`.
+    seg1_min_length: T,
+    /// Cut must end at or before this path length along `seg2`.
+    seg2_max_length: T,
+    /// True if the corner turns counter clockwise (left turn).
+    is_left_turn: bool,
+    /// Fillet radius or chamfer distance.
+    size: T,
+    pos_equal_eps: T,
+}
+
+type CutFn<T> = dyn Fn(&CornerCutInput<T>) -> Option<CornerCut<T>>;
+
+fn modify_corners<P, T, O, S>(
+    polyline: &P,
+    size: T,
+    selector: &mut S,
+    pos_equal_eps: T,
+    cut_fn: &CutFn<T>,
+) -> CornerModifyResult<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+    S: CornerSelector + ?Sized,
+{
+    let vc = polyline.vertex_count();
+    let mut skipped_vertexes = Vec::new();
+    if vc < 2 || size <= T::zero() || size.fuzzy_eq_zero() {
+        return CornerModifyResult {
+            pline: O::create_from(polyline),
+            skipped_vertexes,
+        };
+    }
+
+    debug_assert!(
+        polyline.remove_repeat_pos(pos_equal_eps).is_none(),
+        "bug: input assumed to not have repeat position vertexes"
+    );
+
+    let is_closed = polyline.is_closed();
+    let corner_indexes = if is_closed { 0..vc } else { 1..vc - 1 };
+    let mut cuts: Vec<Option<CornerCut<T>>> = vec![None; vc];
+
+    for i in corner_indexes {
+        if !selector.select(i) {
+            continue;
+        }
+
+        let prev_i = polyline.prev_wrapping_index(i);
+        let next_i = polyline.next_wrapping_index(i);
+        let v_prev = polyline.at(prev_i);
+        let v = polyline.at(i);
+        let v_next = polyline.at(next_i);
+
+        let dir1 = seg_tangent_vector(v_prev, v, v.pos()).normalize();
+        let dir2 = seg_tangent_vector(v, v_next, v.pos()).normalize();
+        let turn = dir1.perp_dot(dir2);
+        if turn.fuzzy_eq_zero() {
+            if dir1.dot(dir2) > T::zero() {
+                // tangent continuous, not a corner
+                continue;
+            }
+            // segments fold back on each other, cannot be cut
+            skipped_vertexes.push(i);
+            continue;
+        }
+
+        let seg1 = (v_prev, v);
+        let seg2 = (v, v_next);
+
+        // limit cut by any cut already made at the other end of the adjacent segments
+        let seg1_min_length = match cuts[prev_i] {
+            Some(prev_cut) if prev_i != i => seg_path_length_to(seg1.0, seg1.1, prev_cut.end),
+            _ => T::zero(),
+        };
+        let seg2_max_length = match cuts[next_i] {
+            Some(next_cut) if next_i != i => seg_path_length_to(seg2.0, seg2.1, next_cut.start),
+            _ => seg_length(seg2.0, seg2.1),
+        };
+
+        let input = CornerCutInput {
+            seg1,
+            seg2,
+            seg1_min_length,
+            seg2_max_length,
+            is_left_turn: turn > T::zero(),
+            size,
+            pos_equal_eps,
+        };
+
+        match cut_fn(&input) {
+            Some(cut) => cuts[i] = Some(cut),
+            None => skipped_vertexes.push(i),
+        }
+    }
+
+    // cuts may consume entire segments so add_or_replace is used to avoid repeat positions
+    let mut pline = O::with_capacity(vc + cuts.iter().flatten().count(), is_closed);
+    pline.set_userdata_values(polyline.get_userdata_values());
+    for i in 0..vc {
+        let v = polyline.at(i);
+        let seg_start = match cuts[i] {
+            Some(cut) => {
+                pline.add_or_replace(cut.start.x, cut.start.y, cut.bulge, pos_equal_eps);
+                cut.end
+            }
+            None => v.pos(),
+        };
+
+        if !is_closed && i == vc - 1 {
+            pline.add_or_replace_vertex(v, pos_equal_eps);
+            break;
+        }
+
+        let next_i = polyline.next_wrapping_index(i);
+        let v_next = polyline.at(next_i);
+        let seg_end = cuts[next_i].map_or(v_next.pos(), |cut| cut.start);
+        let sub_start = sub_seg_start(v, v_next, seg_start, seg_end, pos_equal_eps);
+        pline.add_or_replace_vertex(sub_start, pos_equal_eps);
+    }
+
+    if is_closed && pline.vertex_count() > 1 {
+        let first = pline.at(0);
+        let last = pline.at(pline.vertex_count() - 1);
+        if first.pos().fuzzy_eq_eps(last.pos(), pos_equal_eps) {
+            pline.remove_last();
+        }
+    }
+
+    CornerModifyResult {
+        pline,
+        skipped_vertexes,
+    }
+}
+
+/// Returns the start vertex (with updated bulge) of the portion of the segment `v1->v2` going
+/// from `start` to `end` (both points assumed to lie on the segment).
+fn sub_seg_start<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    start: Vector2<T>,
+    end: Vector2<T>,
+    pos_equal_eps: T,
+) -> PlineVertex<T>
+where
+    T: Real,
+{
+    let after_start = if start.fuzzy_eq_eps(v1.pos(), pos_equal_eps) {
+        v1
+    } else {
+        seg_split_at_point(v1, v2, start, pos_equal_eps).split_vertex
+    };
+
+    if end.fuzzy_eq_eps(v2.pos(), pos_equal_eps)
+        || end.fuzzy_eq_eps(after_start.pos(), pos_equal_eps)
+    {
+        after_start
+    } else {
+        seg_split_at_point(after_start, v2, end, pos_equal_eps).updated_start
+    }
+}
+
+/// Path length along the segment `v1->v2` to the `point` (point assumed to lie on or near the
+/// segment). For arcs points outside the arc sweep are measured to the nearest end (returning
+/// negative values for points before the start).
+fn seg_path_length_to<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, point: Vector2<T>) -> T
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let dir = (v2.pos() - v1.pos()).normalize();
+        return (point - v1.pos()).dot(dir);
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let sweep = bulge_sweep_abs(v1.bulge);
+    let start_angle = angle(center, v1.pos());
+    let point_angle = angle(center, point);
+    let mut a = if v1.bulge_is_pos() {
+        normalize_radians(point_angle - start_angle)
+    } else {
+        normalize_radians(start_angle - point_angle)
+    };
+
+    // normalize_radians may return 2PI
+    if a >= T::tau() {
+        a = a - T::tau();
+    }
+
+    if a > (sweep + T::tau()) / T::two() {
+        a = a - T::tau();
+    }
+
+    a * radius
+}
+
+#[inline]
+fn bulge_sweep_abs<T: Real>(bulge: T) -> T {
+    T::four() * bulge.abs().atan()
+}
+
+/// Returns true if `length` lies within the range allowed for a cut on the segment.
+#[inline]
+fn length_in_range<T: Real>(length: T, min: T, max: T, eps: T) -> bool {
+    length >= min - eps && length <= max + eps
+}
+
+fn chamfer_cut<T>(input: &CornerCutInput<T>) -> Option<CornerCut<T>>
+where
+    T: Real,
+{
+    let (u1, u2) = input.seg1;
+    let (w1, w2) = input.seg2;
+    let eps = input.pos_equal_eps;
+    let seg1_len = seg_length(u1, u2);
+    let start_len = seg1_len - input.size;
+    if !length_in_range(start_len, input.seg1_min_length, seg1_len, eps)
+        || !length_in_range(input.size, T::zero(), input.seg2_max_length, eps)
+    {
+        return None;
+    }
+
+    Some(CornerCut {
+        start: seg_point_at_length(u1, u2, start_len),
+        end: seg_point_at_length(w1, w2, input.size),
+        bulge: T::zero(),
+    })
+}
+
+/// Point on the segment `v1->v2` at the path `length` from the start.
+fn seg_point_at_length<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, length: T) -> Vector2<T>
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let dir = (v2.pos() - v1.pos()).normalize();
+        return v1.pos() + dir.scale(length);
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    let delta = length / radius;
+    let a = if v1.bulge_is_pos() {
+        start_angle + delta
+    } else {
+        start_angle - delta
+    };
+    point_on_circle(radius, center, a)
+}
+
+/// Segment represented as an infinite line or full circle (used to find fillet centers).
+#[derive(Debug, Clone, Copy)]
+enum SegCurve<T> {
+    Line { p0: Vector2<T>, p1: Vector2<T> },
+    Circle { center: Vector2<T>, radius: T },
+}
+
+impl<T> SegCurve<T>
+where
+    T: Real,
+{
+    /// Curve of the segment `v1->v2` offset by `offset` (positive is left of segment direction),
+    /// returns `None` if arc collapses.
+    fn offset_from_seg(v1: PlineVertex<T>, v2: PlineVertex<T>, offset: T) -> Option<Self> {
+        if v1.bulge_is_zero() {
+            let shift = (v2.pos() - v1.pos()).unit_perp().scale(offset);
+            return Some(SegCurve::Line {
+                p0: v1.pos() + shift,
+                p1: v2.pos() + shift,
+            });
+        }
+
+        let (radius, center) = seg_arc_radius_and_center(v1, v2);
+        // left of counter clockwise arc is toward the center
+        let radius = if v1.bulge_is_pos() {
+            radius - offset
+        } else {
+            radius + offset
+        };
+
+        if radius <= T::zero() || radius.fuzzy_eq_zero() {
+            return None;
+        }
+
+        Some(SegCurve::Circle { center, radius })
+    }
+
+    /// Closest point on the curve of the segment `v1->v2` to `point`.
+    fn seg_curve_closest_point(
+        v1: PlineVertex<T>,
+        v2: PlineVertex<T>,
+        point: Vector2<T>,
+    ) -> Vector2<T> {
+        if v1.bulge_is_zero() {
+            let dir = (v2.pos() - v1.pos()).normalize();
+            return v1.pos() + dir.scale((point - v1.pos()).dot(dir));
+        }
+
+        let (radius, center) = seg_arc_radius_and_center(v1, v2);
+        center + (point - center).normalize().scale(radius)
+    }
+
+    fn intersects(&self, other: &Self, eps: T) -> Vec<Vector2<T>> {
+        let mut result = Vec::new();
+        match (*self, *other) {
+            (SegCurve::Line { p0, p1 }, SegCurve::Line { p0: q0, p1: q1 }) => {
+                match line_line_intr(p0, p1, q0, q1, eps) {
+                    LineLineIntr::TrueIntersect { seg1_t, .. }
+                    | LineLineIntr::FalseIntersect { seg1_t, .. } => {
+                        result.push(p0 + (p1 - p0).scale(seg1_t));
+                    }
+                    LineLineIntr::NoIntersect | LineLineIntr::Overlapping { .. } => {}
+                }
+            }
+            (SegCurve::Line { p0, p1 }, SegCurve::Circle { center, radius })
+            | (SegCurve::Circle { center, radius }, SegCurve::Line { p0, p1 }) => {
+                match line_circle_intr(p0, p1, radius, center, eps) {
+                    LineCircleIntr::TangentIntersect { t0 } => {
+                        result.push(p0 + (p1 - p0).scale(t0));
+                    }
+                    LineCircleIntr::TwoIntersects { t0, t1 } => {
+                        result.push(p0 + (p1 - p0).scale(t0));
+                        result.push(p0 + (p1 - p0).scale(t1));
+                    }
+                    LineCircleIntr::NoIntersect => {}
+                }
+            }
+            (
+                SegCurve::Circle { center, radius },
+                SegCurve::Circle {
+                    center: center2,
+                    radius: radius2,
+                },
+            ) => match circle_circle_intr(radius, center, radius2, center2, eps) {
+                CircleCircleIntr::TangentIntersect { point } => {
+                    result.push(point);
+                }
+                CircleCircleIntr::TwoIntersects { point1, point2 } => {
+                    result.push(point1);
+                    result.push(point2);
+                }
+                CircleCircleIntr::NoIntersect | CircleCircleIntr::Overlapping => {}
+            },
+        }
+
+        result
+    }
+}
+
+fn fillet_cut<T>(input: &CornerCutInput<T>) -> Option<CornerCut<T>>
+where
+    T: Real,
+{
+    let (u1, u2) = input.seg1;
+    let (w1, w2) = input.seg2;
+    let eps = input.pos_equal_eps;
+    let radius = input.size;
+    // fillet arc center lies on the inside of the corner
+    let offset = if input.is_left_turn { radius } else { -radius };
+    let curve1 = SegCurve::offset_from_seg(u1, u2, offset)?;
+    let curve2 = SegCurve::offset_from_seg(w1, w2, offset)?;
+
+    let seg1_len = seg_length(u1, u2);
+    let mut best: Option<(T, CornerCut<T>)> = None;
+    for center in curve1.intersects(&curve2, eps) {
+        let start = SegCurve::seg_curve_closest_point(u1, u2, center);
+        let end = SegCurve::seg_curve_closest_point(w1, w2, center);
+        let start_len = seg_path_length_to(u1, u2, start);
+        let end_len = seg_path_length_to(w1, w2, end);
+        if !length_in_range(start_len, input.seg1_min_length, seg1_len, eps)
+            || !length_in_range(end_len, T::zero(), input.seg2_max_length, eps)
+        {
+            continue;
+        }
+
+        let sweep = delta_angle(angle(center, start), angle(center, end));
+        if sweep.fuzzy_eq_zero() || (sweep > T::zero()) != input.is_left_turn {
+            continue;
+        }
+
+        // prefer fillet closest to the corner
+        let dist_from_corner = (seg1_len - start_len) + end_len;
+        if best.is_none_or(|(d, _)| dist_from_corner < d) {
+            best = Some((
+                dist_from_corner,
+                CornerCut {
+                    start,
+                    end,
+                    bulge: bulge_from_angle(sweep),
+                },
+            ));
+        }
+    }
+
+    best.map(|(_, cut)| cut)
+}
@@ -0,0 +1,333 @@
+use static_aabb2d_index::{Control, StaticAABB2DIndex};
+
+use crate::{
+    core::{
+        math::{Vector2, midpoint, point_within_arc_sweep},
+        traits::Real,
+    },
+    polyline::{
+        MinFeatureWidthResult, PlineSource, PlineVertex, seg_arc_radius_and_center,
+        seg_fast_approx_bounding_box, seg_midpoint, seg_tangent_vector,
+    },
+};
+
+use super::pline_closest_point::closest_point_with_index;
+
+/// Compute the narrowest width of the region enclosed by a closed polyline.
+///
+/// The width is measured between two boundary points whose boundary normals face each other
+/// across the region (e.g. the two sides of a channel or neck, or the diameter of a circle) where
+/// the circle having the two points as its diameter lies inside the region without crossing the
+/// boundary. Widths closing in to a convex corner are not counted.
+///
+/// Candidate point pairs are found exactly for each pair of segments (line/line, line/arc, and
+/// arc/arc) and for each non-convex vertex, a spatial index of the segments is used to only test
+/// segments that may be closer than the narrowest width found so far.
+///
+/// Returns `None` if the polyline is open, has no area, or has no such width (e.g. a triangle).
+pub fn min_feature_width<P, T>(polyline: &P, pos_equal_eps: T) -> Option<MinFeatureWidthResult<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if !polyline.is_closed() || polyline.vertex_count() < 2 {
+        return None;
+    }
+
+    let area = polyline.area();
+    if area.fuzzy_eq_zero() {
+        return None;
+    }
+    let is_ccw = area > T::zero();
+
+    // tangent vectors in and out of each vertex where the boundary does not turn towards the
+    // region (reflex or tangent), only these vertexes can be the end of a width
+    let vertex_tangents = (0..polyline.vertex_count())
+        .map(|i| {
+            let prev = polyline.prev_wrapping_index(i);
+            let v = polyline.at(i);
+            let t_in = seg_tangent_vector(polyline.at(prev), v, v.pos()).normalize();
+            let next = polyline.next_wrapping_index(i);
+            let t_out = seg_tangent_vector(v, polyline.at(next), v.pos()).normalize();
+            let turn = t_in.perp_dot(t_out);
+            let is_convex = if is_ccw {
+                turn > pos_equal_eps
+            } else {
+                turn < -pos_equal_eps
+            };
+            (!is_convex).then_some((t_in, t_out))
+        })
+        .collect::<Vec<_>>();
+
+    let aabb_index = polyline.create_approx_aabb_index();
+    let finder = WidthFinder {
+        polyline,
+        aabb_index: &aabb_index,
+        vertex_tangents: &vertex_tangents,
+        pos_equal_eps,
+    };
+
+    let mut result: Option<MinFeatureWidthResult<T>> = None;
+    for (i, next) in polyline.iter_segment_indexes() {
+        let v1 = polyline.at(i);
+        let v2 = polyline.at(next);
+        let seg_box = seg_fast_approx_bounding_box(v1, v2);
+        let box_center = Vector2::new(
+            (seg_box.min_x + seg_box.max_x) / T::two(),
+            (seg_box.min_y + seg_box.max_y) / T::two(),
+        );
+        let box_half_diagonal = (Vector2::new(seg_box.max_x, seg_box.max_y) - box_center).length();
+
+        let mut visitor = |j: usize, box_dist_squared: T| {
+            if let Some(r) = &result {
+                let max_dist = r.width + box_half_diagonal;
+                if box_dist_squared > max_dist * max_dist {
+                    // all remaining segments are too far away to form a narrower width
+                    return Control::Break(());
+                }
+            }
+
+            if j != i {
+                finder.visit_seg_pair_candidates(i, j, &mut |candidate| {
+                    if result.as_ref().is_none_or(|r| candidate.width < r.width)
+                        && finder.is_valid(&candidate)
+                    {
+                        result = Some(candidate);
+                    }
+                });
+            }
+
+            Control::Continue
+        };
+
+        aabb_index.visit_neighbors(box_center.x, box_center.y, &mut visitor);
+    }
+
+    result
+}
+
+/// Holds the polyline data used to find and validate width candidates.
+struct WidthFinder<'a, P: ?Sized, T>
+where
+    T: Real,
+{
+    polyline: &'a P,
+    aabb_index: &'a StaticAABB2DIndex<T>,
+    vertex_tangents: &'a [Option<(Vector2<T>, Vector2<T>)>],
+    pos_equal_eps: T,
+}
+
+impl<P, T> WidthFinder<'_, P, T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    /// Returns true if the circle with the candidate points as its diameter is inside the region
+    /// and does not cross the boundary.
+    fn is_valid(&self, candidate: &MinFeatureWidthResult<T>) -> bool {
+        let center = midpoint(candidate.point1, candidate.point2);
+        let radius = candidate.width / T::two();
+        closest_point_with_index(self.polyline, self.aabb_index, center, self.pos_equal_eps)
+            .is_some_and(|r| r.distance > radius - self.pos_equal_eps)
+            && self.polyline.winding_number(center) != 0
+    }
+
+    /// Visit all the candidate widths from segment `i` (or its start vertex) to segment `j` (or
+    /// its start vertex), widths from the start vertex of `j` to segment `i` are visited when
+    /// `i` and `j` are swapped.
+    fn visit_seg_pair_candidates<F>(&self, i: usize, j: usize, visitor: &mut F)
+    where
+        F: FnMut(MinFeatureWidthResult<T>),
+    {
+        let pl = self.polyline;
+        let eps = self.pos_equal_eps;
+        let (a1, a2) = (pl.at(i), pl.at(pl.next_wrapping_index(i)));
+        let (b1, b2) = (pl.at(j), pl.at(pl.next_wrapping_index(j)));
+
+        let mut add = |p: Vector2<T>, q: Vector2<T>| {
+            let width = (q - p).length();
+            if width > eps {
+                visitor(MinFeatureWidthResult {
+                    width,
+                    seg_start_index1: i,
+                    point1: p,
+                    seg_start_index2: j,
+                    point2: q,
+                });
+            }
+        };
+
+        // start vertex of i to interior of j or start vertex of j
+        if let Some(tangents_i) = self.vertex_tangents[i] {
+            let p = a1.pos();
+            for q in normal_feet(b1, b2, p, eps).into_iter().flatten() {
+                if within_normal_cone(tangents_i, q - p, eps) {
+                    add(p, q);
+                }
+            }
+            if let Some(tangents_j) = self.vertex_tangents[j] {
+                let q = b1.pos();
+                if within_normal_cone(tangents_i, q - p, eps)
+                    && within_normal_cone(tangents_j, p - q, eps)
+                {
+                    add(p, q);
+                }
+            }
+        }
+
+        // interior of i to interior of j
+        match (a1.bulge_is_zero(), b1.bulge_is_zero()) {
+            (true, true) => {
+                let da = a2.pos() - a1.pos();
+                let db = b2.pos() - b1.pos();
+                let len_squared = da.length_squared();
+                if da.perp_dot(db).abs() > eps * da.length() * db.length()
+                    || len_squared < eps * eps
+                {
+                    // not parallel, no widths along the segment interiors
+                    return;
+                }
+                // overlapping portion of the parallel segments, all widths along it are equal
+                // but parts may be blocked by other segments so test several positions
+                let t1 = (b1.pos() - a1.pos()).dot(da) / len_squared;
+                let t2 = (b2.pos() - a1.pos()).dot(da) / len_squared;
+                let (t1, t2) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+                let start = if t1 > T::zero() { t1 } else { T::zero() };
+                let end = if t2 < T::one() { t2 } else { T::one() };
+                if start > end {
+                    return;
+                }
+                let sample_count = 4;
+                for k in 0..=sample_count {
+                    let t = start
+                        + (end - start) * T::from(k).unwrap() / T::from(sample_count).unwrap();
+                    let p = a1.pos() + da.scale(t);
+                    for q in normal_feet(b1, b2, p, eps).into_iter().flatten() {
+                        add(p, q);
+                    }
+                }
+            }
+            (true, false) => {
+                let (_, center) = seg_arc_radius_and_center(b1, b2);
+                if let Some(p) = line_normal_foot(a1.pos(), a2.pos(), center, eps) {
+                    for q in normal_feet(b1, b2, p, eps).into_iter().flatten() {
+                        add(p, q);
+                    }
+                }
+            }
+            (false, true) => {
+                let (_, center) = seg_arc_radius_and_center(a1, a2);
+                if let Some(q) = line_normal_foot(b1.pos(), b2.pos(), center, eps) {
+                    for p in normal_feet(a1, a2, q, eps).into_iter().flatten() {
+                        add(p, q);
+                    }
+                }
+            }
+            (false, false) => {
+                let (radius_a, center_a) = seg_arc_radius_and_center(a1, a2);
+                let (radius_b, center_b) = seg_arc_radius_and_center(b1, b2);
+                // widths between arcs lie along the line through the arc centers, if the arcs
+                // are concentric then any radial line works so use the arc midpoints
+                let directions = if center_a.fuzzy_eq_eps(center_b, eps) {
+                    [
+                        Some((seg_midpoint(a1, a2) - center_a).normalize()),
+                        Some((seg_midpoint(b1, b2) - center_b).normalize()),
+                    ]
+                } else {
+                    [Some((center_b - center_a).normalize()), None]
+                };
+                for dir in directions.into_iter().flatten() {
+                    for p in arc_points_along(a1, a2, center_a, radius_a, dir, eps)
+                        .into_iter()
+                        .flatten()
+                    {
+                        for q in arc_points_along(b1, b2, center_b, radius_b, dir, eps)
+                            .into_iter()
+                            .flatten()
+                        {
+                            add(p, q);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if a circle touching a vertex (with unit tangents `t_in` and `t_out`) and
+/// extending in direction `dir` from the vertex is not crossed by the segments at the vertex.
+fn within_normal_cone<T>((t_in, t_out): (Vector2<T>, Vector2<T>), dir: Vector2<T>, eps: T) -> bool
+where
+    T: Real,
+{
+    let dir = dir.normalize();
+    dir.dot(t_in) > -eps && dir.dot(t_out) < eps
+}
+
+/// Foot of the perpendicular from `point` to the line segment `p0` to `p1`, returns `None` if it
+/// does not lie on the segment.
+fn line_normal_foot<T>(
+    p0: Vector2<T>,
+    p1: Vector2<T>,
+    point: Vector2<T>,
+    eps: T,
+) -> Option<Vector2<T>>
+where
+    T: Real,
+{
+    let d = p1 - p0;
+    let len_squared = d.length_squared();
+    if len_squared < eps * eps {
+        return None;
+    }
+    let t = (point - p0).dot(d) / len_squared;
+    let t_eps = eps / len_squared.sqrt();
+    if t < -t_eps || t > T::one() + t_eps {
+        return None;
+    }
+    Some(p0 + d.scale(t))
+}
+
+/// Points on the segment `v1` to `v2` where the segment normal passes through `point`.
+fn normal_feet<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    point: Vector2<T>,
+    eps: T,
+) -> [Option<Vector2<T>>; 2]
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        return [line_normal_foot(v1.pos(), v2.pos(), point, eps), None];
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    if point.fuzzy_eq_eps(center, eps) {
+        // every point on the arc is normal to the center
+        return [None, None];
+    }
+    arc_points_along(v1, v2, center, radius, (point - center).normalize(), eps)
+}
+
+/// Points on the arc segment `v1` to `v2` at `center` plus and minus `radius` along the unit
+/// direction `dir`, only points within the arc sweep are returned.
+fn arc_points_along<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    center: Vector2<T>,
+    radius: T,
+    dir: Vector2<T>,
+    eps: T,
+) -> [Option<Vector2<T>>; 2]
+where
+    T: Real,
+{
+    let point_if_on_arc = |p: Vector2<T>| {
+        point_within_arc_sweep(center, v1.pos(), v2.pos(), v1.bulge_is_neg(), p, eps).then_some(p)
+    };
+    [
+        point_if_on_arc(center + dir.scale(radius)),
+        point_if_on_arc(center - dir.scale(radius)),
+    ]
+}
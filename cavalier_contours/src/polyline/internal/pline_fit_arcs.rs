@@ -0,0 +1,304 @@
+//! Fitting runs of line segments with arcs.
+use crate::{
+    core::{
+        math::{
+            Vector2, angle, bulge_from_angle, is_left, line_seg_closest_point, normalize_radians,
+            point_on_circle,
+        },
+        traits::Real,
+    },
+    polyline::{PlineCreation, PlineSource, PlineVertex},
+};
+
+/// Merge runs of line segments in `polyline` into single line or arc segments where the merged
+/// segment deviates from the original path by at most `tolerance`.
+///
+/// See [PlineSource::fit_arcs] for more information.
+pub fn fit_arcs<P, T, O>(polyline: &P, tolerance: T, pos_equal_eps: T) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let input = O::create_from_remove_repeat(polyline, pos_equal_eps);
+    let vc = input.vertex_count();
+    if vc < 3 {
+        return input;
+    }
+
+    let is_closed = input.is_closed();
+    let start_index = if is_closed {
+        closed_start_index(&input)
+    } else {
+        0
+    };
+
+    // vertexes in traversal order, closed polylines repeat the start vertex at the end so every
+    // segment is (vertexes[i], vertexes[i + 1])
+    let mut vertexes = Vec::with_capacity(vc + 1);
+    vertexes.extend((0..vc).map(|i| input.at((start_index + i) % vc)));
+    if is_closed {
+        vertexes.push(vertexes[0]);
+    }
+
+    let seg_count = vertexes.len() - 1;
+    let mut result = O::with_capacity(vc, is_closed);
+    result.set_userdata_values(polyline.get_userdata_values());
+
+    let mut i = 0;
+    while i < seg_count {
+        let v = vertexes[i];
+        if !v.bulge_is_zero() {
+            result.add_vertex(v);
+            i += 1;
+            continue;
+        }
+
+        let run_end = (i..seg_count)
+            .find(|&j| !vertexes[j].bulge_is_zero())
+            .unwrap_or(seg_count);
+
+        let try_fit = |end: usize| {
+            let points = &vertexes[i..=end];
+            if line_fits(points, tolerance, pos_equal_eps) {
+                Some(T::zero())
+            } else {
+                arc_fit(points, tolerance, pos_equal_eps)
+            }
+        };
+
+        // extend the fit segment as far as possible, doubling the step until the fit fails then
+        // binary searching for the furthest fit (avoids quadratic cost on long runs)
+        let mut fit_end = i + 1;
+        let mut fit_bulge = T::zero();
+        let mut fail_end = run_end + 1;
+        let mut step = 1;
+        while fit_end < run_end {
+            let end = (fit_end + step).min(run_end);
+            if let Some(bulge) = try_fit(end) {
+                fit_end = end;
+                fit_bulge = bulge;
+                step *= 2;
+            } else {
+                fail_end = end;
+                break;
+            }
+        }
+
+        while fail_end - fit_end > 1 {
+            let end = fit_end + (fail_end - fit_end) / 2;
+            if let Some(bulge) = try_fit(end) {
+                fit_end = end;
+                fit_bulge = bulge;
+            } else {
+                fail_end = end;
+            }
+        }
+
+        result.add_vertex(v.with_bulge(fit_bulge));
+        i = fit_end;
+    }
+
+    if !is_closed {
+        result.add_vertex(vertexes[seg_count]);
+    }
+
+    result
+}
+
+/// Start closed polylines at a vertex which is a natural break in the path so runs of line
+/// segments are not split at an arbitrary point. Picks the end of the first arc segment if there
+/// is one, otherwise the vertex with the sharpest turn.
+fn closed_start_index<P, T>(polyline: &P) -> usize
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    if let Some(i) = (0..vc).find(|&i| !polyline.at(i).bulge_is_zero()) {
+        return polyline.next_wrapping_index(i);
+    }
+
+    let mut start_index = 0;
+    let mut min_dot = T::two();
+    for i in 0..vc {
+        let prev = polyline.at(polyline.prev_wrapping_index(i)).pos();
+        let curr = polyline.at(i).pos();
+        let next = polyline.at(polyline.next_wrapping_index(i)).pos();
+        let dot = (curr - prev).normalize().dot((next - curr).normalize());
+        if dot < min_dot {
+            min_dot = dot;
+            start_index = i;
+        }
+    }
+
+    start_index
+}
+
+/// Deviations within `eps` are always accepted so exactly collinear or co-circular vertexes are
+/// merged even with a tolerance of zero.
+#[inline]
+fn within_tolerance<T: Real>(deviation: T, tolerance: T, eps: T) -> bool {
+    deviation <= tolerance || deviation < eps
+}
+
+/// Returns true if all the line segments joining `points` lie within `tolerance` of the line
+/// segment from the first point to the last point (and progress along it without doubling back).
+fn line_fits<T>(points: &[PlineVertex<T>], tolerance: T, eps: T) -> bool
+where
+    T: Real,
+{
+    let p0 = points[0].pos();
+    let p1 = points[points.len() - 1].pos();
+    let length = (p1 - p0).length();
+    if length < eps {
+        return false;
+    }
+
+    let dir = (p1 - p0).scale(T::one() / length);
+    let mut prev_t = T::zero();
+    for v in &points[1..points.len() - 1] {
+        let w = v.pos() - p0;
+        let t = w.dot(dir);
+        if t < prev_t - eps || t > length + eps {
+            return false;
+        }
+
+        if !within_tolerance(dir.perp_dot(w).abs(), tolerance, eps) {
+            return false;
+        }
+
+        prev_t = t;
+    }
+
+    true
+}
+
+/// Center of the circle passing through the 3 points given or `None` if the points are
+/// collinear.
+fn circle_center_from_points<T>(
+    p0: Vector2<T>,
+    p1: Vector2<T>,
+    p2: Vector2<T>,
+    eps: T,
+) -> Option<Vector2<T>>
+where
+    T: Real,
+{
+    let a = p1 - p0;
+    let b = p2 - p0;
+    let d = T::two() * a.perp_dot(b);
+    if d.abs() < eps {
+        return None;
+    }
+
+    let a_len_sq = a.length_squared();
+    let b_len_sq = b.length_squared();
+    let x = (b.y * a_len_sq - a.y * b_len_sq) / d;
+    let y = (a.x * b_len_sq - b.x * a_len_sq) / d;
+    Some(Vector2::new(p0.x + x, p0.y + y))
+}
+
+/// Attempt to fit an arc from the first point to the last point of `points` which passes
+/// through the middle point, returns the bulge of the arc if all the line segments joining
+/// `points` lie within `tolerance` of the arc.
+fn arc_fit<T>(points: &[PlineVertex<T>], tolerance: T, eps: T) -> Option<T>
+where
+    T: Real,
+{
+    let last = points.len() - 1;
+    let p0 = points[0].pos();
+    let pm = points[last / 2].pos();
+    let p1 = points[last].pos();
+    let center = circle_center_from_points(p0, pm, p1, eps)?;
+    let radius = (p0 - center).length();
+    // arc bulges to the right of the chord when counter clockwise
+    let is_ccw = !is_left(p0, p1, pm);
+
+    let start_angle = angle(center, p0);
+    // angle swept from the start to the point given in the direction of the arc
+    let sweep_to = |pt: Vector2<T>| {
+        let a = angle(center, pt);
+        let sweep = if is_ccw {
+            normalize_radians(a - start_angle)
+        } else {
+            normalize_radians(start_angle - a)
+        };
+        // normalize_radians may return 2PI
+        if sweep >= T::tau() {
+            sweep - T::tau()
+        } else {
+            sweep
+        }
+    };
+
+    let total_sweep = sweep_to(p1);
+    // polyline arc segments cannot sweep more than a half circle
+    if total_sweep > T::pi() {
+        return None;
+    }
+
+    let angle_eps = tolerance / radius + eps;
+    let mut prev_sweep = T::zero();
+    let mut prev_pt = p0;
+    for v in &points[1..] {
+        let pt = v.pos();
+        let pt_sweep = if pt.fuzzy_eq_eps(p1, eps) {
+            total_sweep
+        } else {
+            sweep_to(pt)
+        };
+
+        // points must progress along the arc
+        if pt_sweep < prev_sweep - angle_eps || pt_sweep > total_sweep + angle_eps {
+            return None;
+        }
+
+        // radial deviation is greatest at the segment end points or at the closest point to the
+        // center
+        let closest = line_seg_closest_point(prev_pt, pt, center);
+        let deviation = {
+            let d1 = ((pt - center).length() - radius).abs();
+            let d2 = radius - (closest - center).length();
+            if d1 > d2 { d1 } else { d2 }
+        };
+
+        if !within_tolerance(deviation, tolerance, eps) {
+            return None;
+        }
+
+        // arc portion spanning the segment must also lie within tolerance of the segment, the
+        // arc is furthest from the segment at the points where the arc tangent is parallel to the
+        // segment (if within the portion) or at the portion mid point
+        let mid_angle = if is_ccw {
+            start_angle + (prev_sweep + pt_sweep) / T::two()
+        } else {
+            start_angle - (prev_sweep + pt_sweep) / T::two()
+        };
+        let seg_normal = (pt - prev_pt).unit_perp();
+        let arc_points = [
+            point_on_circle(radius, center, mid_angle),
+            center + seg_normal.scale(radius),
+            center - seg_normal.scale(radius),
+        ];
+        for (i, arc_pt) in arc_points.into_iter().enumerate() {
+            if i != 0 {
+                let s = sweep_to(arc_pt);
+                if s < prev_sweep || s > pt_sweep {
+                    continue;
+                }
+            }
+
+            let seg_closest = line_seg_closest_point(prev_pt, pt, arc_pt);
+            if !within_tolerance((arc_pt - seg_closest).length(), tolerance, eps) {
+                return None;
+            }
+        }
+
+        prev_sweep = pt_sweep;
+        prev_pt = pt;
+    }
+
+    let bulge = bulge_from_angle(total_sweep);
+    Some(if is_ccw { bulge } else { -bulge })
+}
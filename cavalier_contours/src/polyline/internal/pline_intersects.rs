@@ -246,12 +246,35 @@ where
     P: PlineSource<Num = T> + ?Sized,
     T: Real,
 {
-    struct Visitor<U> {
-        intrs: Vec<PlineBasicIntersect<U>>,
+    let mut result = Vec::new();
+    all_self_intersects_as_basic_into(
+        polyline,
+        aabb_index,
+        include_overlapping,
+        pos_equal_eps,
+        &mut result,
+    );
+    result
+}
+
+/// Same as [all_self_intersects_as_basic] but the intersects are written to `output` (`output` is
+/// cleared first).
+pub fn all_self_intersects_as_basic_into<P, T>(
+    polyline: &P,
+    aabb_index: &StaticAABB2DIndex<T>,
+    include_overlapping: bool,
+    pos_equal_eps: T,
+    output: &mut Vec<PlineBasicIntersect<T>>,
+) where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    struct Visitor<'a, U> {
+        intrs: &'a mut Vec<PlineBasicIntersect<U>>,
         include_overlapping: bool,
     }
 
-    impl<U> PlineIntersectVisitor<U, Control> for Visitor<U>
+    impl<U> PlineIntersectVisitor<U, Control> for Visitor<'_, U>
     where
         U: Real,
     {
@@ -279,15 +302,14 @@ where
         }
     }
 
+    output.clear();
     let mut visitor = Visitor {
-        intrs: Vec::new(),
+        intrs: output,
         include_overlapping,
     };
 
     visit_local_self_intersects(polyline, &mut visitor, pos_equal_eps);
     visit_global_self_intersects(polyline, aabb_index, &mut visitor, pos_equal_eps);
-
-    visitor.intrs
 }
 
 // Visit all intersections between two polylines.
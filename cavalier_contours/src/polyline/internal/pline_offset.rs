@@ -8,11 +8,15 @@ use crate::{
         traits::Real,
     },
     polyline::{
-        FindIntersectsOptions, PlineCreation, PlineOffsetOptions, PlineSegIntr, PlineSource,
-        PlineSourceMut, PlineVertex, PlineViewData,
-        internal::pline_intersects::{all_self_intersects_as_basic, find_intersects},
+        BooleanPlineSlice, EndCapStyle, FindIntersectsOptions, JoinStyle, OffsetContext,
+        PlineBasicIntersect, PlineBufferOptions, PlineCreation, PlineOffsetOptions,
+        PlineOrientation, PlineSegIntr, PlineSource, PlineSourceMut, PlineVertex, PlineViewData,
+        Polyline,
+        internal::pline_intersects::{
+            all_self_intersects_as_basic, all_self_intersects_as_basic_into, find_intersects,
+        },
         pline_seg_intr, seg_arc_radius_and_center, seg_closest_point, seg_fast_approx_bounding_box,
-        seg_midpoint,
+        seg_midpoint, seg_tangent_vector,
     },
 };
 use static_aabb2d_index::{Control, StaticAABB2DIndex, StaticAABB2DIndexBuilder};
@@ -30,11 +34,104 @@ where
     pub collapsed_arc: bool,
 }
 
+/// Buffers reused across calls to the offset and boolean algorithms (held by [OffsetContext]). All
+/// buffers are cleared before use so their contents between calls are not meaningful, only their
+/// allocated capacity is reused.
+#[derive(Debug)]
+pub struct OffsetBuffers<T>
+where
+    T: Real,
+{
+    pub raw_offset_segs: Vec<RawPlineOffsetSeg<T>>,
+    pub raw_offset: Polyline<T>,
+    pub dual_raw_offset: Polyline<T>,
+    pub slices: Vec<PlineViewData<T>>,
+    pub boolean_slices: Vec<BooleanPlineSlice<T>>,
+    pub scratch: ScratchBuffers<T>,
+}
+
+impl<T> OffsetBuffers<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self {
+            raw_offset_segs: Vec::new(),
+            raw_offset: Polyline::new(),
+            dual_raw_offset: Polyline::new(),
+            slices: Vec::new(),
+            boolean_slices: Vec::new(),
+            scratch: ScratchBuffers::new(),
+        }
+    }
+}
+
+impl<T> Default for OffsetBuffers<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Intermediate buffers used while slicing and stitching, see [OffsetBuffers].
+#[derive(Debug)]
+pub struct ScratchBuffers<T> {
+    pub intrs: Vec<PlineBasicIntersect<T>>,
+    pub intr_points: Vec<(usize, Vector2<T>)>,
+    pub visited: Vec<bool>,
+    pub query_results: Vec<usize>,
+    pub query_stack: Vec<usize>,
+}
+
+impl<T> ScratchBuffers<T> {
+    pub fn new() -> Self {
+        Self {
+            intrs: Vec::new(),
+            intr_points: Vec::new(),
+            visited: Vec::new(),
+            query_results: Vec::new(),
+            query_stack: Vec::new(),
+        }
+    }
+}
+
+impl<T> Default for ScratchBuffers<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Create all the raw parallel offset segments of a polyline using the `offset` value given.
 pub fn create_untrimmed_raw_offset_segs<P, T>(polyline: &P, offset: T) -> Vec<RawPlineOffsetSeg<T>>
 where
     P: PlineSource<Num = T> + ?Sized,
     T: Real,
+{
+    // Note: using with_capacity to ensure exact allocation required for the end result (avoids
+    // over allocating and resize allocations)
+    let mut result = Vec::with_capacity(polyline.segment_count());
+    create_untrimmed_raw_offset_segs_into(polyline, offset, &mut result);
+
+    debug_assert_eq!(
+        result.capacity(),
+        polyline.segment_count(),
+        "ensure exact allocation"
+    );
+
+    result
+}
+
+/// Same as [create_untrimmed_raw_offset_segs] but the segments are written to `output` (`output`
+/// is cleared first).
+pub fn create_untrimmed_raw_offset_segs_into<P, T>(
+    polyline: &P,
+    offset: T,
+    output: &mut Vec<RawPlineOffsetSeg<T>>,
+) where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
 {
     let process_line_seg = |v1: PlineVertex<T>, v2: PlineVertex<T>| -> RawPlineOffsetSeg<T> {
         let line_v = v2.pos() - v1.pos();
@@ -71,24 +168,14 @@ where
         }
     };
 
-    // Note: using with_capacity to ensure exact allocation required for the end result (avoids
-    // over allocating and resize allocations)
-    let mut result = Vec::with_capacity(polyline.segment_count());
-    result.extend(polyline.iter_segments().map(|(v1, v2)| {
+    output.clear();
+    output.extend(polyline.iter_segments().map(|(v1, v2)| {
         if v1.bulge_is_zero() {
             process_line_seg(v1, v2)
         } else {
             process_arc_seg(v1, v2)
         }
     }));
-
-    debug_assert_eq!(
-        result.capacity(),
-        polyline.segment_count(),
-        "ensure exact allocation"
-    );
-
-    result
 }
 
 /// Test if parametric value `t` represents a false intersect or not. False intersect is defined as
@@ -138,14 +225,109 @@ fn connect_using_arc<T, O>(
     result.add_or_replace(ep.x, ep.y, s2.v1.bulge, pos_equal_eps);
 }
 
+/// Connect two raw offset segments by joining them with a line and push the vertexes to the
+/// `result` output parameter.
+#[inline]
+fn connect_using_line<T, O>(
+    s1: &RawPlineOffsetSeg<T>,
+    s2: &RawPlineOffsetSeg<T>,
+    result: &mut O,
+    pos_equal_eps: T,
+) where
+    T: Real,
+    O: PlineSourceMut<Num = T>,
+{
+    let sp = s1.v2.pos();
+    let ep = s2.v1.pos();
+    result.add_or_replace(sp.x, sp.y, T::zero(), pos_equal_eps);
+    result.add_or_replace(ep.x, ep.y, s2.v1.bulge, pos_equal_eps);
+}
+
+/// Connect two raw offset segments by extending them along their tangents until they meet and push
+/// the vertexes to the `result` output parameter. Falls back to connecting using a line if the
+/// tangents do not meet or the miter point exceeds the `miter_limit`.
+fn connect_using_miter<T, O>(
+    s1: &RawPlineOffsetSeg<T>,
+    s2: &RawPlineOffsetSeg<T>,
+    miter_limit: T,
+    result: &mut O,
+    pos_equal_eps: T,
+) where
+    T: Real,
+    O: PlineSourceMut<Num = T>,
+{
+    let sp = s1.v2.pos();
+    let ep = s2.v1.pos();
+    let t1 = seg_tangent_vector(s1.v1, s1.v2, sp);
+    let t2 = seg_tangent_vector(s2.v1, s2.v2, ep);
+    let denom = t1.perp_dot(t2);
+    if denom.fuzzy_eq_zero() {
+        // tangents are parallel (no miter point)
+        connect_using_line(s1, s2, result, pos_equal_eps);
+        return;
+    }
+
+    // solve sp + t1 * a = ep - t2 * b for a and b (both must be positive for the tangents to
+    // meet going forward from s1 and backward from s2)
+    let sp_to_ep = ep - sp;
+    let a = sp_to_ep.perp_dot(t2) / denom;
+    let b = sp_to_ep.perp_dot(t1) / -denom;
+    if a <= T::zero() || b <= T::zero() {
+        connect_using_line(s1, s2, result, pos_equal_eps);
+        return;
+    }
+
+    let miter_point = sp + t1.scale(a);
+    let corner = s1.orig_v2_pos;
+    let max_dist = (sp - corner).length() * miter_limit;
+    if dist_squared(miter_point, corner) > max_dist * max_dist {
+        connect_using_line(s1, s2, result, pos_equal_eps);
+        return;
+    }
+
+    result.add_or_replace(sp.x, sp.y, T::zero(), pos_equal_eps);
+    result.add_or_replace(miter_point.x, miter_point.y, T::zero(), pos_equal_eps);
+    result.add_or_replace(ep.x, ep.y, s2.v1.bulge, pos_equal_eps);
+}
+
 /// Parameters passed to segment join functions used to form raw offset polyline.
 struct JoinParams<T> {
     /// If true then connection arcs should be counter clockwise, otherwise clockwise.
     connection_arcs_ccw: bool,
+    /// Style used to connect segments at outward corners.
+    join_style: JoinStyle<T>,
     /// Epsilon to use for testing if positions are fuzzy equal.
     pos_equal_eps: T,
 }
 
+/// Connect two raw offset segments at an outward corner using the join style in `params` and push
+/// the vertexes to the `result` output parameter. Connections to/from collapsed arcs always use an
+/// arc.
+#[inline]
+fn connect_using_join_style<T, O>(
+    s1: &RawPlineOffsetSeg<T>,
+    s2: &RawPlineOffsetSeg<T>,
+    params: &JoinParams<T>,
+    result: &mut O,
+) where
+    T: Real,
+    O: PlineSourceMut<Num = T>,
+{
+    let pos_equal_eps = params.pos_equal_eps;
+    if s1.collapsed_arc || s2.collapsed_arc {
+        connect_using_arc(s1, s2, params.connection_arcs_ccw, result, pos_equal_eps);
+        return;
+    }
+
+    match params.join_style {
+        JoinStyle::Round => {
+            connect_using_arc(s1, s2, params.connection_arcs_ccw, result, pos_equal_eps)
+        }
+        JoinStyle::Miter { limit } => connect_using_miter(s1, s2, limit, result, pos_equal_eps),
+        JoinStyle::Bevel => connect_using_line(s1, s2, result, pos_equal_eps),
+    }
+}
+
 /// Join two adjacent raw offset segments where both segments are lines.
 fn line_line_join<T, O>(
     s1: &RawPlineOffsetSeg<T>,
@@ -194,8 +376,8 @@ fn line_line_join<T, O>(
             }
             LineLineIntr::FalseIntersect { seg1_t, seg2_t } => {
                 if seg1_t > T::one() && is_false_intersect(seg2_t) {
-                    // extend and join the lines together using arc
-                    connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+                    // extend and join the lines together
+                    connect_using_join_style(s1, s2, params, result);
                 } else {
                     result.add_or_replace(v2.x, v2.y, T::zero(), pos_equal_eps);
                     result.add_or_replace(u1.x, u1.y, u1.bulge, pos_equal_eps);
@@ -261,7 +443,7 @@ fn line_arc_join<T, O>(
         }
 
         if t > T::one() && !true_arc_intr {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join_style(s1, s2, params, result);
             return;
         }
 
@@ -277,7 +459,7 @@ fn line_arc_join<T, O>(
 
     match line_circle_intr(v1.pos(), v2.pos(), arc_radius, arc_center, pos_equal_eps) {
         LineCircleIntr::NoIntersect => {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join_style(s1, s2, params, result);
         }
         LineCircleIntr::TangentIntersect { t0 } => {
             process_intersect(t0, point_from_parametric(v1.pos(), v2.pos(), t0));
@@ -308,7 +490,6 @@ fn arc_line_join<T, O>(
     T: Real,
     O: PlineSourceMut<Num = T>,
 {
-    let connection_arcs_ccw = params.connection_arcs_ccw;
     let pos_equal_eps = params.pos_equal_eps;
     let v1 = &s1.v1;
     let v2 = &s1.v2;
@@ -355,12 +536,12 @@ fn arc_line_join<T, O>(
             return;
         }
 
-        connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+        connect_using_join_style(s1, s2, params, result);
     };
 
     match line_circle_intr(u1.pos(), u2.pos(), arc_radius, arc_center, pos_equal_eps) {
         LineCircleIntr::NoIntersect => {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join_style(s1, s2, params, result);
         }
         LineCircleIntr::TangentIntersect { t0 } => {
             process_intersect(t0, point_from_parametric(u1.pos(), u2.pos(), t0));
@@ -396,7 +577,6 @@ fn arc_arc_join<T, O>(
     T: Real,
     O: PlineSourceMut<Num = T>,
 {
-    let connection_arcs_ccw = params.connection_arcs_ccw;
     let pos_equal_eps = params.pos_equal_eps;
     let v1 = &s1.v1;
     let v2 = &s1.v2;
@@ -431,7 +611,7 @@ fn arc_arc_join<T, O>(
 
     let mut process_intersect = |intersect: Vector2<T>, true_intersect: bool| {
         if !true_intersect {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join_style(s1, s2, params, result);
         } else {
             let prev_vertex = result.last().unwrap();
 
@@ -478,7 +658,7 @@ fn arc_arc_join<T, O>(
         pos_equal_eps,
     ) {
         CircleCircleIntr::NoIntersect => {
-            connect_using_arc(s1, s2, connection_arcs_ccw, result, pos_equal_eps);
+            connect_using_join_style(s1, s2, params, result);
         }
         CircleCircleIntr::TangentIntersect { point } => {
             process_intersect(point, both_arcs_sweep_point(point));
@@ -510,30 +690,77 @@ fn arc_arc_join<T, O>(
     }
 }
 
+/// Create the raw offset polyline (offset segments joined together but not yet trimmed/sliced at
+/// self intersects) using [JoinStyle::Round] to join segments at outward corners.
 pub fn create_raw_offset_polyline<P, T, O>(polyline: &P, offset: T, pos_equal_eps: T) -> O
 where
     P: PlineSource<Num = T> + ?Sized,
     T: Real,
     O: PlineCreation<Num = T>,
 {
+    create_raw_offset_polyline_with_join(polyline, offset, JoinStyle::Round, pos_equal_eps)
+}
+
+/// Create the raw offset polyline (offset segments joined together but not yet trimmed/sliced at
+/// self intersects) using the `join_style` given to join segments at outward corners.
+pub fn create_raw_offset_polyline_with_join<P, T, O>(
+    polyline: &P,
+    offset: T,
+    join_style: JoinStyle<T>,
+    pos_equal_eps: T,
+) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let mut result = O::empty();
+    create_raw_offset_polyline_into(
+        polyline,
+        offset,
+        join_style,
+        pos_equal_eps,
+        &mut Vec::new(),
+        &mut result,
+    );
+    result
+}
+
+/// Same as [create_raw_offset_polyline_with_join] but the raw offset polyline is written to
+/// `result` (`result` is cleared first) and `raw_offset_segs` is used to hold the untrimmed raw
+/// offset segments.
+pub fn create_raw_offset_polyline_into<P, T, O>(
+    polyline: &P,
+    offset: T,
+    join_style: JoinStyle<T>,
+    pos_equal_eps: T,
+    raw_offset_segs: &mut Vec<RawPlineOffsetSeg<T>>,
+    result: &mut O,
+) where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    result.clear();
     let vc = polyline.vertex_count();
     if vc < 2 {
-        return O::empty();
+        return;
     }
 
-    let raw_offset_segs = create_untrimmed_raw_offset_segs(polyline, offset);
+    create_untrimmed_raw_offset_segs_into(polyline, offset, raw_offset_segs);
     if raw_offset_segs.is_empty() {
-        return O::empty();
+        return;
     }
 
     // detect single collapsed arc segment
     if raw_offset_segs.len() == 1 && raw_offset_segs[0].collapsed_arc {
-        return O::empty();
+        return;
     }
 
     let connection_arcs_ccw = offset < T::zero();
     let join_params = JoinParams {
         connection_arcs_ccw,
+        join_style,
         pos_equal_eps,
     };
 
@@ -548,7 +775,8 @@ where
         }
     };
 
-    let mut result = O::with_capacity(vc, polyline.is_closed());
+    result.set_is_closed(polyline.is_closed());
+    result.reserve(vc);
 
     // add the very first vertex
     result.add_vertex(raw_offset_segs.first().unwrap().v1);
@@ -557,13 +785,13 @@ where
     // last two segment joins for closed polyline)
     let mut offset_seg_pairs = raw_offset_segs.windows(2);
     if let Some([s1, s2]) = offset_seg_pairs.next() {
-        join_seg_pair(s1, s2, &mut result);
+        join_seg_pair(s1, s2, result);
     }
 
     let first_vertex_replaced = result.vertex_count() == 1;
 
     while let Some([s1, s2]) = offset_seg_pairs.next() {
-        join_seg_pair(s1, s2, &mut result);
+        join_seg_pair(s1, s2, result);
     }
 
     if polyline.is_closed() && result.vertex_count() > 1 {
@@ -643,8 +871,6 @@ where
     if result.vertex_count() == 1 {
         result.clear();
     }
-
-    result
 }
 
 #[inline]
@@ -698,25 +924,64 @@ where
     P: PlineSource<Num = T> + ?Sized,
     R: PlineSource<Num = T> + ?Sized,
     T: Real,
+{
+    let mut result = Vec::new();
+    slices_from_raw_offset_into(
+        original_polyline,
+        raw_offset_polyline,
+        orig_polyline_index,
+        offset,
+        options,
+        &mut ScratchBuffers::new(),
+        &mut result,
+    );
+    result
+}
+
+/// Same as [slices_from_raw_offset] but the slices are written to `result` (`result` is cleared
+/// first) and `scratch` is used for the intermediate buffers.
+pub fn slices_from_raw_offset_into<P, R, T>(
+    original_polyline: &P,
+    raw_offset_polyline: &R,
+    orig_polyline_index: &StaticAABB2DIndex<T>,
+    offset: T,
+    options: &PlineOffsetOptions<T>,
+    scratch: &mut ScratchBuffers<T>,
+    result: &mut Vec<PlineViewData<T>>,
+) where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
 {
     debug_assert!(
         raw_offset_polyline.is_closed(),
         "only supports closed polylines, use slices_from_dual_raw_offsets for open polylines"
     );
 
-    let mut result = Vec::new();
+    result.clear();
     if raw_offset_polyline.vertex_count() < 2 {
-        return result;
+        return;
     }
 
     let pos_equal_eps = options.pos_equal_eps;
     let offset_dist_eps = options.offset_dist_eps;
 
     let raw_offset_index = raw_offset_polyline.create_approx_aabb_index();
-    let self_intrs =
-        all_self_intersects_as_basic(raw_offset_polyline, &raw_offset_index, false, pos_equal_eps);
+    let ScratchBuffers {
+        intrs: self_intrs,
+        intr_points,
+        query_stack,
+        ..
+    } = scratch;
+
+    all_self_intersects_as_basic_into(
+        raw_offset_polyline,
+        &raw_offset_index,
+        false,
+        pos_equal_eps,
+        self_intrs,
+    );
 
-    let mut query_stack = Vec::new();
     if self_intrs.is_empty() {
         // no self intersects, test point on polyline is valid
         if !point_valid_for_offset(
@@ -724,44 +989,36 @@ where
             offset,
             orig_polyline_index,
             raw_offset_polyline.at(0).pos(),
-            &mut query_stack,
+            query_stack,
             pos_equal_eps,
             offset_dist_eps,
         ) {
             // not valid
-            return result;
+            return;
         }
 
         // is valid
         let slice = PlineViewData::from_entire_pline(raw_offset_polyline);
         result.push(slice);
-        return result;
+        return;
     }
 
-    // using BTreeMap as it is faster than HashMap in testing (note: sorted iteration order is not
-    // required)
-    let mut intersects_lookup = BTreeMap::<usize, Vec<Vector2<T>>>::new();
-
-    for si in &self_intrs {
-        intersects_lookup
-            .entry(si.start_index1)
-            .or_default()
-            .push(si.point);
-        intersects_lookup
-            .entry(si.start_index2)
-            .or_default()
-            .push(si.point);
+    // intersects grouped by segment start index (flat list rather than map to allow reusing the
+    // buffer), within each group sorted by distance from segment start vertex
+    intr_points.clear();
+    for si in self_intrs.iter() {
+        intr_points.push((si.start_index1, si.point));
+        intr_points.push((si.start_index2, si.point));
     }
 
-    // sort intersects by distance from segment start vertex
-    for (&i, intr_list) in intersects_lookup.iter_mut() {
-        let start_pos = raw_offset_polyline.at(i).pos();
-        intr_list.sort_unstable_by(|&si1, &si2| {
+    intr_points.sort_unstable_by(|&(i1, si1), &(i2, si2)| {
+        i1.cmp(&i2).then_with(|| {
+            let start_pos = raw_offset_polyline.at(i1).pos();
             let dist1 = dist_squared(si1, start_pos);
             let dist2 = dist_squared(si2, start_pos);
             dist1.total_cmp(&dist2)
-        });
-    }
+        })
+    });
 
     let intersects_original_pline =
         |v1: PlineVertex<T>, v2: PlineVertex<T>, query_stack: &mut Vec<usize>| -> bool {
@@ -870,9 +1127,19 @@ where
         point_valid_dist(slice.end_point, query_stack)
     };
 
-    for (&start_index, intr_list) in intersects_lookup.iter() {
+    let intr_points = &intr_points[..];
+    let mut group_start = 0;
+    while group_start < intr_points.len() {
+        let start_index = intr_points[group_start].0;
+        let group_len = intr_points[group_start..]
+            .iter()
+            .take_while(|(i, _)| *i == start_index)
+            .count();
+        let intr_list = &intr_points[group_start..group_start + group_len];
+        group_start += group_len;
+
         let mut intr_list_iter = intr_list.windows(2);
-        while let Some(&[intr1, intr2]) = intr_list_iter.next() {
+        while let Some(&[(_, intr1), (_, intr2)]) = intr_list_iter.next() {
             let slice = PlineViewData::from_slice_points(
                 raw_offset_polyline,
                 intr1,
@@ -883,7 +1150,7 @@ where
             );
 
             if let Some(s) = slice
-                && slice_is_valid(&s, &mut query_stack)
+                && slice_is_valid(&s, query_stack)
             {
                 result.push(s);
             }
@@ -891,36 +1158,34 @@ where
 
         // build the slice between the last intersect in the intr_list and the next intersect found
         let next_index = raw_offset_polyline.next_wrapping_index(start_index);
-
-        let (found_index, next_intr_list) =
-            if let Some(list) = intersects_lookup.range(next_index..).next() {
-                list
-            } else {
-                // wrap around polyline
-                intersects_lookup.range(..=start_index).next().unwrap()
-            };
+        let next_group_start = intr_points.partition_point(|(i, _)| *i < next_index);
+        let (found_index, next_intr) = if next_group_start < intr_points.len() {
+            intr_points[next_group_start]
+        } else {
+            // wrap around polyline
+            intr_points[0]
+        };
 
         let slice = PlineViewData::from_slice_points(
             raw_offset_polyline,
-            *intr_list.last().unwrap(),
+            intr_list.last().unwrap().1,
             start_index,
-            next_intr_list[0],
-            *found_index,
+            next_intr,
+            found_index,
             pos_equal_eps,
         );
 
         if let Some(s) = slice
-            && slice_is_valid(&s, &mut query_stack)
+            && slice_is_valid(&s, query_stack)
         {
             result.push(s);
         }
     }
-
-    result
 }
 
-/// Adds circle intersects to the `intersect_lookups` given.
-fn visit_circle_intersects<P, T, F>(
+/// Calls `visitor` with the segment index and point of each intersect between `pline` and the
+/// circle given.
+pub fn visit_circle_intersects<P, T, F>(
     pline: &P,
     circle_center: Vector2<T>,
     circle_radius: T,
@@ -1033,17 +1298,59 @@ where
     T: Real,
 {
     let mut result = Vec::new();
+    slices_from_dual_raw_offsets_into(
+        original_polyline,
+        raw_offset_polyline,
+        dual_raw_offset_polyline,
+        orig_polyline_index,
+        offset,
+        options,
+        &mut ScratchBuffers::new(),
+        &mut result,
+    );
+    result
+}
+
+/// Same as [slices_from_dual_raw_offsets] but the slices are written to `result` (`result` is
+/// cleared first) and `scratch` is used for the intermediate buffers.
+#[allow(clippy::too_many_arguments)]
+pub fn slices_from_dual_raw_offsets_into<P, R, T>(
+    original_polyline: &P,
+    raw_offset_polyline: &R,
+    dual_raw_offset_polyline: &R,
+    orig_polyline_index: &StaticAABB2DIndex<T>,
+    offset: T,
+    options: &PlineOffsetOptions<T>,
+    scratch: &mut ScratchBuffers<T>,
+    result: &mut Vec<PlineViewData<T>>,
+) where
+    P: PlineSource<Num = T> + ?Sized,
+    R: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    result.clear();
     if raw_offset_polyline.vertex_count() < 2 {
-        return result;
+        return;
     }
 
     let pos_equal_eps = options.pos_equal_eps;
     let offset_dist_eps = options.offset_dist_eps;
 
     let raw_offset_index = raw_offset_polyline.create_approx_aabb_index();
+    let ScratchBuffers {
+        intrs: self_intrs,
+        intr_points,
+        query_stack,
+        ..
+    } = scratch;
 
-    let self_intrs =
-        all_self_intersects_as_basic(raw_offset_polyline, &raw_offset_index, false, pos_equal_eps);
+    all_self_intersects_as_basic_into(
+        raw_offset_polyline,
+        &raw_offset_index,
+        false,
+        pos_equal_eps,
+        self_intrs,
+    );
 
     let dual_intrs = find_intersects(
         raw_offset_polyline,
@@ -1054,15 +1361,16 @@ where
         },
     );
 
-    // using BTreeMap rather than  HashMap since we want to construct the slices in vertex index
-    // order and we do so by looping through all intersects (required later when slices are stitched
-    // together, because slices may not all form closed loops/polylines so must go in order of
-    // indexes to ensure longest stitched results are formed)
-    let mut intersects_lookup = BTreeMap::<usize, Vec<Vector2<T>>>::new();
+    // intersects grouped by segment start index (flat list rather than map to allow reusing the
+    // buffer), the groups are sorted by index since we want to construct the slices in vertex index
+    // order (required later when slices are stitched together, because slices may not all form
+    // closed loops/polylines so must go in order of indexes to ensure longest stitched results are
+    // formed)
+    intr_points.clear();
 
     // helper function to add intersects to the lookup
     let mut add_intr = |start_index: usize, intr: Vector2<T>| {
-        intersects_lookup.entry(start_index).or_default().push(intr);
+        intr_points.push((start_index, intr));
     };
 
     if !original_polyline.is_closed() {
@@ -1100,37 +1408,36 @@ where
     }
     // Note not adding any overlapping intersects (they can only arise due to collapsing regions)
 
-    let mut query_stack = Vec::with_capacity(8);
-
-    if intersects_lookup.is_empty() {
+    if intr_points.is_empty() {
         // test a point on raw offset polyline
         if !point_valid_for_offset(
             original_polyline,
             offset,
             orig_polyline_index,
             raw_offset_polyline.at(0).pos(),
-            &mut query_stack,
+            query_stack,
             pos_equal_eps,
             offset_dist_eps,
         ) {
-            return result;
+            return;
         }
 
         // is valid
         let slice = PlineViewData::from_entire_pline(raw_offset_polyline);
         result.push(slice);
-        return result;
+        return;
     }
 
-    // sort intersects by distance from segment start vertex
-    for (&i, intr_list) in intersects_lookup.iter_mut() {
-        let start_pos = raw_offset_polyline.at(i).pos();
-        intr_list.sort_unstable_by(|&si1, &si2| {
+    // sort by segment index then by distance from segment start vertex
+    intr_points.sort_unstable_by(|&(i1, si1), &(i2, si2)| {
+        i1.cmp(&i2).then_with(|| {
+            let start_pos = raw_offset_polyline.at(i1).pos();
             let dist1 = dist_squared(si1, start_pos);
             let dist2 = dist_squared(si2, start_pos);
             dist1.total_cmp(&dist2)
-        });
-    }
+        })
+    });
+    let intr_points = &intr_points[..];
 
     let intersects_original_pline =
         |v1: PlineVertex<T>, v2: PlineVertex<T>, query_stack: &mut Vec<usize>| -> bool {
@@ -1242,27 +1549,35 @@ where
     if !original_polyline.is_closed() {
         // build first slice that ends at the first intersect since we will not wrap back to
         // capture it as in the case of a closed polyline
-        let (intr_idx, intr_list) = intersects_lookup.iter().next().unwrap();
-        let intr = intr_list[0];
+        let (intr_idx, intr) = intr_points[0];
         let slice = PlineViewData::from_slice_points(
             raw_offset_polyline,
             raw_offset_polyline.at(0).pos(),
             0,
             intr,
-            *intr_idx,
+            intr_idx,
             pos_equal_eps,
         );
 
         if let Some(s) = slice
-            && slice_is_valid(&s, &mut query_stack)
+            && slice_is_valid(&s, query_stack)
         {
             result.push(s);
         }
     }
 
-    for (&start_index, intr_list) in intersects_lookup.iter() {
+    let mut group_start = 0;
+    while group_start < intr_points.len() {
+        let start_index = intr_points[group_start].0;
+        let group_len = intr_points[group_start..]
+            .iter()
+            .take_while(|(i, _)| *i == start_index)
+            .count();
+        let intr_list = &intr_points[group_start..group_start + group_len];
+        group_start += group_len;
+
         let mut intr_list_iter = intr_list.windows(2);
-        while let Some(&[intr1, intr2]) = intr_list_iter.next() {
+        while let Some(&[(_, intr1), (_, intr2)]) = intr_list_iter.next() {
             let slice = PlineViewData::from_slice_points(
                 raw_offset_polyline,
                 intr1,
@@ -1273,7 +1588,7 @@ where
             );
 
             if let Some(s) = slice
-                && slice_is_valid(&s, &mut query_stack)
+                && slice_is_valid(&s, query_stack)
             {
                 result.push(s);
             }
@@ -1282,47 +1597,45 @@ where
         // build the slice between the last intersect in the intr_list and the next intersect found
         let next_index = raw_offset_polyline.next_wrapping_index(start_index);
 
-        let (found_index, next_intr_list) =
-            if let Some(list) = intersects_lookup.range(next_index..).next() {
-                list
-            } else if original_polyline.is_closed() {
-                // wrap around polyline
-                intersects_lookup.range(..=start_index).next().unwrap()
-            } else {
-                // open polyline and didn't find next intersect, we're done
-                let slice = PlineViewData::from_slice_points(
-                    raw_offset_polyline,
-                    *intr_list.last().unwrap(),
-                    start_index,
-                    raw_offset_polyline.last().unwrap().pos(),
-                    raw_offset_polyline.vertex_count() - 1,
-                    pos_equal_eps,
-                );
-                if let Some(s) = slice
-                    && slice_is_valid(&s, &mut query_stack)
-                {
-                    result.push(s);
-                }
-                return result;
-            };
+        let next_group_start = intr_points.partition_point(|(i, _)| *i < next_index);
+        let (found_index, next_intr) = if next_group_start < intr_points.len() {
+            intr_points[next_group_start]
+        } else if original_polyline.is_closed() {
+            // wrap around polyline
+            intr_points[0]
+        } else {
+            // open polyline and didn't find next intersect, we're done
+            let slice = PlineViewData::from_slice_points(
+                raw_offset_polyline,
+                intr_list.last().unwrap().1,
+                start_index,
+                raw_offset_polyline.last().unwrap().pos(),
+                raw_offset_polyline.vertex_count() - 1,
+                pos_equal_eps,
+            );
+            if let Some(s) = slice
+                && slice_is_valid(&s, query_stack)
+            {
+                result.push(s);
+            }
+            return;
+        };
 
         let slice = PlineViewData::from_slice_points(
             raw_offset_polyline,
-            *intr_list.last().unwrap(),
+            intr_list.last().unwrap().1,
             start_index,
-            next_intr_list[0],
-            *found_index,
+            next_intr,
+            found_index,
             pos_equal_eps,
         );
 
         if let Some(s) = slice
-            && slice_is_valid(&s, &mut query_stack)
+            && slice_is_valid(&s, query_stack)
         {
             result.push(s);
         }
     }
-
-    result
 }
 
 pub fn stitch_slices_together<P, T, O>(
@@ -1332,6 +1645,30 @@ pub fn stitch_slices_together<P, T, O>(
     orig_max_index: usize,
     options: &PlineOffsetOptions<T>,
 ) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    stitch_slices_together_with(
+        raw_offset_pline,
+        slices,
+        is_closed,
+        orig_max_index,
+        options,
+        &mut ScratchBuffers::new(),
+    )
+}
+
+/// Same as [stitch_slices_together] but `scratch` is used for the intermediate buffers.
+pub fn stitch_slices_together_with<P, T, O>(
+    raw_offset_pline: &P,
+    slices: &[PlineViewData<T>],
+    is_closed: bool,
+    orig_max_index: usize,
+    options: &PlineOffsetOptions<T>,
+    scratch: &mut ScratchBuffers<T>,
+) -> Vec<O>
 where
     P: PlineSource<Num = T> + ?Sized,
     T: Real,
@@ -1378,9 +1715,14 @@ where
         builder.build().unwrap()
     };
 
-    let mut visited_indexes = vec![false; slices.len()];
-    let mut query_results = Vec::new();
-    let mut query_stack = Vec::with_capacity(8);
+    let ScratchBuffers {
+        visited: visited_indexes,
+        query_results,
+        query_stack,
+        ..
+    } = scratch;
+    visited_indexes.clear();
+    visited_indexes.resize(slices.len(), false);
 
     for i in 0..slices.len() {
         if visited_indexes[i] {
@@ -1422,7 +1764,7 @@ where
                 current_end_point.x + join_eps,
                 current_end_point.y + join_eps,
                 &mut aabb_index_visitor,
-                &mut query_stack,
+                query_stack,
             );
 
             let get_index_dist = |i: usize| -> usize {
@@ -1495,37 +1837,70 @@ where
         &constructed_index
     };
 
-    let raw_offset: O = create_raw_offset_polyline(polyline, offset, options.pos_equal_eps);
-    let mut result = if raw_offset.is_empty() {
-        Vec::new()
-    } else if polyline.is_closed() && !options.handle_self_intersects {
-        let slices = slices_from_raw_offset(polyline, &raw_offset, index, offset, options);
-        stitch_slices_together(
-            &raw_offset,
-            &slices,
-            true,
-            raw_offset.vertex_count() - 1,
-            options,
-        )
-    } else {
-        let dual_raw_offset = create_raw_offset_polyline(polyline, -offset, options.pos_equal_eps);
-        let slices = slices_from_dual_raw_offsets(
+    let mut result = OffsetContext::with_buffers(options.context, |buffers| {
+        let OffsetBuffers {
+            raw_offset_segs,
+            raw_offset,
+            dual_raw_offset,
+            slices,
+            scratch,
+            ..
+        } = buffers;
+
+        create_raw_offset_polyline_into(
             polyline,
-            &raw_offset,
-            &dual_raw_offset,
-            index,
             offset,
-            options,
+            options.join_style,
+            options.pos_equal_eps,
+            raw_offset_segs,
+            raw_offset,
         );
+        let raw_offset = &*raw_offset;
 
-        stitch_slices_together(
-            &raw_offset,
-            &slices,
-            polyline.is_closed(),
-            raw_offset.vertex_count(),
-            options,
-        )
-    };
+        if raw_offset.is_empty() {
+            Vec::new()
+        } else if polyline.is_closed() && !options.handle_self_intersects {
+            slices_from_raw_offset_into(
+                polyline, raw_offset, index, offset, options, scratch, slices,
+            );
+            stitch_slices_together_with(
+                raw_offset,
+                slices,
+                true,
+                raw_offset.vertex_count() - 1,
+                options,
+                scratch,
+            )
+        } else {
+            create_raw_offset_polyline_into(
+                polyline,
+                -offset,
+                JoinStyle::Round,
+                options.pos_equal_eps,
+                raw_offset_segs,
+                dual_raw_offset,
+            );
+            slices_from_dual_raw_offsets_into(
+                polyline,
+                raw_offset,
+                &*dual_raw_offset,
+                index,
+                offset,
+                options,
+                scratch,
+                slices,
+            );
+
+            stitch_slices_together_with(
+                raw_offset,
+                slices,
+                polyline.is_closed(),
+                raw_offset.vertex_count(),
+                options,
+                scratch,
+            )
+        }
+    });
 
     debug_assert!(
         result
@@ -1540,3 +1915,471 @@ where
 
     result
 }
+
+/// Create the closed polylines that form the buffer (stroke) around the `polyline` given, with
+/// `distance` being the distance from the polyline to the buffer boundary on each side.
+///
+/// If `polyline` is open the result is formed from the valid slices of the raw offsets on both
+/// sides of the polyline joined together by end caps (see [EndCapStyle]). If `polyline` is closed
+/// the result is the parallel offsets on both sides of the polyline, outer polylines are oriented
+/// counter clockwise and inner polylines (holes) are oriented clockwise.
+pub fn buffer<P, T, O>(polyline: &P, distance: T, options: &PlineBufferOptions<T>) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let distance = distance.abs();
+    if polyline.vertex_count() < 2 || distance.fuzzy_eq_zero() {
+        return Vec::new();
+    }
+    debug_assert!(
+        polyline.remove_repeat_pos(options.pos_equal_eps).is_none(),
+        "bug: input assumed to not have repeat position vertexes"
+    );
+
+    let offset_options = PlineOffsetOptions {
+        aabb_index: None,
+        handle_self_intersects: false,
+        pos_equal_eps: options.pos_equal_eps,
+        slice_join_eps: options.slice_join_eps,
+        offset_dist_eps: options.offset_dist_eps,
+        join_style: options.join_style,
+        context: None,
+    };
+
+    let mut result: Vec<O> = if polyline.is_closed() {
+        closed_pline_buffer(polyline, distance, &offset_options)
+    } else if options.end_cap == EndCapStyle::Square {
+        // square cap is the same as butt cap with the ends extended along their tangents
+        let extended = extend_open_pline_ends::<P, T, O>(polyline, distance);
+        open_pline_buffer(&extended, distance, EndCapStyle::Butt, &offset_options)
+    } else {
+        open_pline_buffer(polyline, distance, options.end_cap, &offset_options)
+    };
+
+    for cursor in result.iter_mut() {
+        cursor.set_userdata_values(polyline.get_userdata_values());
+    }
+
+    result
+}
+
+/// Buffer of a closed polyline (offsets on both sides of the polyline).
+fn closed_pline_buffer<P, T, O>(
+    polyline: &P,
+    distance: T,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let index = polyline.create_approx_aabb_index();
+    let options = PlineOffsetOptions {
+        aabb_index: Some(&index),
+        ..options.clone()
+    };
+
+    let (outer_offset, inner_offset) =
+        if polyline.orientation() == PlineOrientation::CounterClockwise {
+            (-distance, distance)
+        } else {
+            (distance, -distance)
+        };
+
+    let mut result: Vec<O> = parallel_offset(polyline, outer_offset, &options);
+    for pline in result.iter_mut() {
+        if pline.area() < T::zero() {
+            pline.invert_direction_mut();
+        }
+    }
+
+    let inner_start = result.len();
+    result.extend(parallel_offset::<P, T, O>(polyline, inner_offset, &options));
+    for pline in result[inner_start..].iter_mut() {
+        if pline.area() > T::zero() {
+            pline.invert_direction_mut();
+        }
+    }
+
+    result
+}
+
+/// Create a copy of an open polyline with line segments added at each end that extend the
+/// polyline along its end tangents by `distance`.
+fn extend_open_pline_ends<P, T, O>(polyline: &P, distance: T) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let vc = polyline.vertex_count();
+    let first = polyline.at(0);
+    let start_tangent = seg_tangent_vector(first, polyline.at(1), first.pos()).normalize();
+    let last = polyline.at(vc - 1);
+    let end_tangent = seg_tangent_vector(polyline.at(vc - 2), last, last.pos()).normalize();
+
+    let mut result = O::with_capacity(vc + 2, false);
+    let start = first.pos() - start_tangent.scale(distance);
+    result.add(start.x, start.y, T::zero());
+    for v in polyline.iter_vertexes().take(vc - 1) {
+        result.add_vertex(v);
+    }
+    result.add(last.x, last.y, T::zero());
+    let end = last.pos() + end_tangent.scale(distance);
+    result.add(end.x, end.y, T::zero());
+
+    result
+}
+
+/// Buffer of an open polyline using the `end_cap` style given (must be round or butt).
+///
+/// The raw offsets to the right of the polyline going forward and going backward are joined
+/// together with end caps to form a closed counter clockwise loop. For round caps the valid slices
+/// of each raw offset (found using [slices_from_dual_raw_offsets]) and the end cap slices that lie
+/// on the boundary of the loop are then stitched together. For butt caps the whole loop is sliced
+/// at its self intersects and the slices that lie on the boundary of the loop are stitched
+/// together.
+fn open_pline_buffer<P, T, O>(
+    polyline: &P,
+    distance: T,
+    end_cap: EndCapStyle,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    debug_assert!(
+        end_cap != EndCapStyle::Square,
+        "square cap must be converted to butt cap"
+    );
+    let pos_equal_eps = options.pos_equal_eps;
+    let mut reversed = O::create_from(polyline);
+    reversed.invert_direction_mut();
+
+    let fwd_raw: O = create_raw_offset_polyline_with_join(
+        polyline,
+        -distance,
+        options.join_style,
+        pos_equal_eps,
+    );
+    let rev_raw: O = create_raw_offset_polyline_with_join(
+        &reversed,
+        -distance,
+        options.join_style,
+        pos_equal_eps,
+    );
+
+    // if one side entirely collapsed (arc with radius smaller than distance) then that side is
+    // replaced with a line between its end points (which lies inside of the buffer)
+    let fwd_collapsed = fwd_raw.vertex_count() < 2;
+    let rev_collapsed = rev_raw.vertex_count() < 2;
+    if fwd_collapsed && rev_collapsed {
+        return Vec::new();
+    }
+    let fwd_side = if fwd_collapsed {
+        collapsed_side_line(polyline, distance)
+    } else {
+        fwd_raw
+    };
+    let rev_side = if rev_collapsed {
+        collapsed_side_line(&reversed, distance)
+    } else {
+        rev_raw
+    };
+
+    // form closed loop with the end caps as the connecting segments
+    let cap_bulge = match end_cap {
+        EndCapStyle::Round => T::one(),
+        _ => T::zero(),
+    };
+    let fwd_count = fwd_side.vertex_count();
+    let mut raw_loop = O::with_capacity(fwd_count + rev_side.vertex_count(), true);
+    for v in fwd_side.iter_vertexes() {
+        raw_loop.add_vertex(v);
+    }
+    raw_loop.set_last(raw_loop.last().unwrap().with_bulge(cap_bulge));
+    for v in rev_side.iter_vertexes() {
+        raw_loop.add_vertex(v);
+    }
+    raw_loop.set_last(raw_loop.last().unwrap().with_bulge(cap_bulge));
+
+    let loop_index = raw_loop.create_approx_aabb_index();
+    let slices = if end_cap == EndCapStyle::Round {
+        // raw offset of the reversed polyline is the dual raw offset (other side of the polyline)
+        let fwd_slices = if fwd_collapsed {
+            Vec::new()
+        } else {
+            slices_from_dual_raw_offsets(
+                polyline,
+                &fwd_side,
+                &rev_side,
+                &polyline.create_approx_aabb_index(),
+                -distance,
+                options,
+            )
+        };
+        let rev_slices = if rev_collapsed {
+            Vec::new()
+        } else {
+            slices_from_dual_raw_offsets(
+                &reversed,
+                &rev_side,
+                &fwd_side,
+                &reversed.create_approx_aabb_index(),
+                -distance,
+                options,
+            )
+        };
+
+        let end_cap_slices = cap_slices(&raw_loop, &loop_index, fwd_count - 1, options);
+        let start_cap_slices =
+            cap_slices(&raw_loop, &loop_index, raw_loop.vertex_count() - 1, options);
+
+        // slices must be in index order for stitching, raw offsets from each side may overlap
+        // where they wrap around the polyline (only keep one of the overlapping slices)
+        let mut slices = Vec::with_capacity(
+            fwd_slices.len() + end_cap_slices.len() + rev_slices.len() + start_cap_slices.len(),
+        );
+        let rev_slices = rev_slices.into_iter().map(|s| PlineViewData {
+            start_index: s.start_index + fwd_count,
+            ..s
+        });
+        for s in fwd_slices
+            .into_iter()
+            .chain(end_cap_slices)
+            .chain(rev_slices)
+            .chain(start_cap_slices)
+        {
+            if !slice_is_repeat(&slices, &s, pos_equal_eps) {
+                slices.push(s);
+            }
+        }
+        slices
+    } else {
+        // offset slice validity is determined by distance to the polyline which does not hold
+        // near the ends for flat caps (flat ends do not cover the end circles), so instead slice
+        // the whole loop and keep the slices that lie on its boundary
+        loop_boundary_slices(&raw_loop, &loop_index, options)
+    };
+
+    stitch_slices_together(
+        &raw_loop,
+        &slices,
+        true,
+        raw_loop.vertex_count() - 1,
+        options,
+    )
+}
+
+/// Create a line from the start point to the end point of the raw offset of an open polyline
+/// (offset to the right by `distance`), used when all of the raw offset segments collapse.
+fn collapsed_side_line<P, T, O>(polyline: &P, distance: T) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let vc = polyline.vertex_count();
+    let first = polyline.at(0);
+    let last = polyline.at(vc - 1);
+    let start_tangent = seg_tangent_vector(first, polyline.at(1), first.pos()).normalize();
+    let end_tangent = seg_tangent_vector(polyline.at(vc - 2), last, last.pos()).normalize();
+    let right_normal = |t: Vector2<T>| Vector2::new(t.y, -t.x);
+    let start = first.pos() + right_normal(start_tangent).scale(distance);
+    let end = last.pos() + right_normal(end_tangent).scale(distance);
+
+    let mut result = O::with_capacity(2, false);
+    result.add(start.x, start.y, T::zero());
+    result.add(end.x, end.y, T::zero());
+    result
+}
+
+/// Returns true if the `slice` of the counter clockwise `raw_loop` lies on the boundary of the
+/// region enclosed by the loop (non-zero winding number), tested using a point just outside (to the
+/// right) of the midpoint of the first segment of the slice.
+fn slice_on_loop_boundary<P, T>(raw_loop: &P, slice: &PlineViewData<T>, test_dist: T) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let v1 = slice.updated_start;
+    let v2 = if slice.end_index_offset == 0 {
+        PlineVertex::from_vector2(slice.end_point, T::zero())
+    } else {
+        raw_loop.at(raw_loop.next_wrapping_index(slice.start_index))
+    };
+    let midpoint = seg_midpoint(v1, v2);
+    let tangent = seg_tangent_vector(v1, v2, midpoint).normalize();
+    let test_point = midpoint + Vector2::new(tangent.y, -tangent.x).scale(test_dist);
+    raw_loop.winding_number(test_point) == 0
+}
+
+/// Returns true if `slice` has the same start and end point as one of the `slices` given.
+fn slice_is_repeat<T>(slices: &[PlineViewData<T>], slice: &PlineViewData<T>, eps: T) -> bool
+where
+    T: Real,
+{
+    slices.iter().any(|s| {
+        s.updated_start
+            .pos()
+            .fuzzy_eq_eps(slice.updated_start.pos(), eps)
+            && s.end_point.fuzzy_eq_eps(slice.end_point, eps)
+    })
+}
+
+/// Slices of the end cap segment at `cap_index` in the `raw_loop` that lie on the boundary of the
+/// loop (cap segment is split at all intersects with the rest of the loop).
+fn cap_slices<P, T>(
+    raw_loop: &P,
+    loop_index: &StaticAABB2DIndex<T>,
+    cap_index: usize,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<PlineViewData<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    let v1 = raw_loop.at(cap_index);
+    let v2 = raw_loop.at(raw_loop.next_wrapping_index(cap_index));
+
+    let mut split_points = vec![v1.pos()];
+    let bb = seg_fast_approx_bounding_box(v1, v2);
+    for i in loop_index.query(bb.min_x, bb.min_y, bb.max_x, bb.max_y) {
+        if i == cap_index {
+            continue;
+        }
+        let u1 = raw_loop.at(i);
+        let u2 = raw_loop.at(raw_loop.next_wrapping_index(i));
+        match pline_seg_intr(v1, v2, u1, u2, pos_equal_eps) {
+            PlineSegIntr::NoIntersect => {}
+            PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+                split_points.push(point);
+            }
+            PlineSegIntr::TwoIntersects { point1, point2 }
+            | PlineSegIntr::OverlappingLines { point1, point2 }
+            | PlineSegIntr::OverlappingArcs { point1, point2 } => {
+                split_points.push(point1);
+                split_points.push(point2);
+            }
+        }
+    }
+
+    // sort by distance from cap start (cap is at most a half circle so distance increases along
+    // the cap)
+    let start = v1.pos();
+    split_points
+        .sort_unstable_by(|&p1, &p2| dist_squared(p1, start).total_cmp(&dist_squared(p2, start)));
+    split_points.push(v2.pos());
+    split_points.dedup_by(|p1, p2| p1.fuzzy_eq_eps(*p2, pos_equal_eps));
+
+    let mut result = Vec::new();
+    for pair in split_points.windows(2) {
+        let slice = PlineViewData::from_slice_points(
+            raw_loop,
+            pair[0],
+            cap_index,
+            pair[1],
+            cap_index,
+            pos_equal_eps,
+        );
+
+        if let Some(s) = slice
+            && slice_on_loop_boundary(raw_loop, &s, options.offset_dist_eps)
+        {
+            result.push(s);
+        }
+    }
+
+    result
+}
+
+/// Slices of the `raw_loop` (split at all of its self intersects) that lie on the boundary of the
+/// loop, overlapping slices in the same direction are only returned once.
+fn loop_boundary_slices<P, T>(
+    raw_loop: &P,
+    loop_index: &StaticAABB2DIndex<T>,
+    options: &PlineOffsetOptions<T>,
+) -> Vec<PlineViewData<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    let test_dist = options.offset_dist_eps;
+    let mut result = Vec::new();
+
+    let self_intrs = all_self_intersects_as_basic(raw_loop, loop_index, true, pos_equal_eps);
+    if self_intrs.is_empty() {
+        let slice = PlineViewData::from_entire_pline(raw_loop);
+        if slice_on_loop_boundary(raw_loop, &slice, test_dist) {
+            result.push(slice);
+        }
+        return result;
+    }
+
+    let mut intersects_lookup = BTreeMap::<usize, Vec<Vector2<T>>>::new();
+    for si in self_intrs.iter() {
+        intersects_lookup
+            .entry(si.start_index1)
+            .or_default()
+            .push(si.point);
+        intersects_lookup
+            .entry(si.start_index2)
+            .or_default()
+            .push(si.point);
+    }
+
+    for (&i, intr_list) in intersects_lookup.iter_mut() {
+        let start_pos = raw_loop.at(i).pos();
+        intr_list.sort_unstable_by(|&p1, &p2| {
+            dist_squared(p1, start_pos).total_cmp(&dist_squared(p2, start_pos))
+        });
+        intr_list.dedup_by(|p1, p2| p1.fuzzy_eq_eps(*p2, pos_equal_eps));
+    }
+
+    let mut add_slice = |slice: Option<PlineViewData<T>>| {
+        if let Some(s) = slice
+            && slice_on_loop_boundary(raw_loop, &s, test_dist)
+            && !slice_is_repeat(&result, &s, pos_equal_eps)
+        {
+            result.push(s);
+        }
+    };
+
+    for (&start_index, intr_list) in intersects_lookup.iter() {
+        for pair in intr_list.windows(2) {
+            add_slice(PlineViewData::from_slice_points(
+                raw_loop,
+                pair[0],
+                start_index,
+                pair[1],
+                start_index,
+                pos_equal_eps,
+            ));
+        }
+
+        // slice from the last intersect in the list to the next intersect (wrapping around)
+        let next_index = raw_loop.next_wrapping_index(start_index);
+        let (&found_index, next_intr_list) = intersects_lookup
+            .range(next_index..)
+            .next()
+            .unwrap_or_else(|| intersects_lookup.iter().next().unwrap());
+
+        add_slice(PlineViewData::from_slice_points(
+            raw_loop,
+            *intr_list.last().unwrap(),
+            start_index,
+            next_intr_list[0],
+            found_index,
+            pos_equal_eps,
+        ));
+    }
+
+    result
+}
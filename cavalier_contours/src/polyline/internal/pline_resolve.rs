@@ -0,0 +1,185 @@
+//! Resolving self intersecting closed polylines into simple closed polylines.
+use std::collections::BTreeMap;
+
+use crate::{
+    core::{
+        math::{Vector2, dist_squared},
+        traits::Real,
+    },
+    polyline::{
+        BooleanPlineSlice, FillRule, PlineCreation, PlineResolveOptions, PlineSource,
+        PlineViewData, Polyline, seg_midpoint, seg_tangent_vector,
+    },
+};
+
+use super::{
+    pline_boolean::{StitchSelector, remove_repeat_slices, stitch_slices_into_closed_polylines},
+    pline_intersects::all_self_intersects_as_basic,
+};
+
+/// Stitch selector that always picks the lowest available slice index. Slices are stitched in
+/// index order so the slice that started the current polyline always has the lowest index, this
+/// closes polylines as soon as possible (polylines that touch at a point are kept separate).
+struct LowestIndexStitchSelector;
+
+impl StitchSelector for LowestIndexStitchSelector {
+    fn select(&self, _current_slice_idx: usize, available_idx: &[usize]) -> Option<usize> {
+        available_idx.iter().copied().min()
+    }
+}
+
+/// Split a closed polyline at its self intersects and stitch back together the slices that form
+/// the boundary of the filled region according to `fill_rule`.
+///
+/// See [PlineSource::resolve_self_intersects_opt] for more information.
+pub fn resolve_self_intersects<P, T, O>(
+    polyline: &P,
+    fill_rule: FillRule,
+    options: &PlineResolveOptions<T>,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    if !polyline.is_closed() || polyline.vertex_count() < 2 {
+        return Vec::new();
+    }
+
+    let pos_equal_eps = options.pos_equal_eps;
+
+    let constructed_index;
+    let aabb_index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    let self_intrs = all_self_intersects_as_basic(polyline, aabb_index, true, pos_equal_eps);
+
+    if self_intrs.is_empty() {
+        // already simple, only need to orient counter clockwise
+        let area = polyline.area();
+        if area.abs() <= pos_equal_eps {
+            return Vec::new();
+        }
+
+        let mut result = O::create_from(polyline);
+        if area < T::zero() {
+            result.invert_direction_mut();
+        }
+
+        return vec![result];
+    }
+
+    let mut intersects_lookup = BTreeMap::<usize, Vec<_>>::new();
+    for si in &self_intrs {
+        intersects_lookup
+            .entry(si.start_index1)
+            .or_default()
+            .push(si.point);
+        intersects_lookup
+            .entry(si.start_index2)
+            .or_default()
+            .push(si.point);
+    }
+
+    // sort intersects by distance from segment start vertex and remove repeats (e.g. more than two
+    // segments intersecting at the same point)
+    for (&i, intr_list) in intersects_lookup.iter_mut() {
+        let start_pos = polyline.at(i).pos();
+        intr_list.sort_unstable_by(|&si1, &si2| {
+            let dist1 = dist_squared(si1, start_pos);
+            let dist2 = dist_squared(si2, start_pos);
+            dist1.total_cmp(&dist2)
+        });
+        intr_list.dedup_by(|a, b| a.fuzzy_eq_eps(*b, pos_equal_eps));
+    }
+
+    let mut slices = Vec::new();
+    for (&start_index, intr_list) in intersects_lookup.iter() {
+        for w in intr_list.windows(2) {
+            slices.extend(PlineViewData::from_slice_points(
+                polyline,
+                w[0],
+                start_index,
+                w[1],
+                start_index,
+                pos_equal_eps,
+            ));
+        }
+
+        // slice between the last intersect in the list and the next intersect found
+        let next_index = polyline.next_wrapping_index(start_index);
+        let (&found_index, next_intr_list) =
+            if let Some(list) = intersects_lookup.range(next_index..).next() {
+                list
+            } else {
+                // wrap around polyline
+                intersects_lookup.range(..=start_index).next().unwrap()
+            };
+
+        slices.extend(PlineViewData::from_slice_points(
+            polyline,
+            *intr_list.last().unwrap(),
+            start_index,
+            next_intr_list[0],
+            found_index,
+            pos_equal_eps,
+        ));
+    }
+
+    // keep slices that separate filled and unfilled regions, oriented so the filled region is to
+    // the left, test points are offset from the slice (must not be on the boundary)
+    let test_offset = T::from(10.0).unwrap() * pos_equal_eps;
+    let mut candidates = Vec::new();
+    let mut midpoints = Vec::new();
+    for slice in slices {
+        let view = slice.view(polyline);
+        let (v1, v2) = view.iter_segments().next().unwrap();
+        let midpoint = seg_midpoint(v1, v2);
+        let tangent = seg_tangent_vector(v1, v2, midpoint);
+        if tangent.length_squared().fuzzy_eq_zero() {
+            continue;
+        }
+        let left_normal = tangent.unit_perp().scale(test_offset);
+        let left_filled = fill_rule.is_filled(polyline.winding_number(midpoint + left_normal));
+        let right_filled = fill_rule.is_filled(polyline.winding_number(midpoint - left_normal));
+        if left_filled != right_filled {
+            let s = BooleanPlineSlice::from_open_pline_slice(&slice, true, right_filled);
+            candidates.push(s);
+            midpoints.push(midpoint);
+        }
+    }
+
+    // skip repeat slices (segments of the polyline on top of each other in the same direction)
+    let kept = remove_repeat_slices(candidates, &midpoints, pos_equal_eps, slice_end_points);
+
+    let empty = Polyline::<T>::new();
+    stitch_slices_into_closed_polylines::<_, _, _, _, O>(
+        &kept,
+        polyline,
+        &empty,
+        &LowestIndexStitchSelector,
+        pos_equal_eps,
+    )
+    .into_iter()
+    .map(|r| r.pline)
+    .filter(|p| p.area().abs() > pos_equal_eps)
+    .collect()
+}
+
+/// Start and end point of the slice following its direction.
+fn slice_end_points<T>(slice: &BooleanPlineSlice<T>) -> (Vector2<T>, Vector2<T>)
+where
+    T: Real,
+{
+    let start = slice.view_data.updated_start.pos();
+    let end = slice.view_data.end_point;
+    if slice.view_data.inverted_direction {
+        (end, start)
+    } else {
+        (start, end)
+    }
+}
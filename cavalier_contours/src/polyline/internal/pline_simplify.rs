@@ -0,0 +1,402 @@
+//! Tolerance driven simplification of polylines.
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{
+    core::{
+        math::{Vector2, dist_squared, line_seg_closest_point, point_within_arc_sweep},
+        traits::Real,
+    },
+    polyline::{
+        PlineCreation, PlineIntersect, PlineSource, PlineVertex, SimplifyMethod,
+        seg_arc_radius_and_center, seg_midpoint,
+    },
+};
+
+/// Remove vertexes from `polyline` such that the resulting path deviates from the original by at
+/// most `tolerance`.
+///
+/// See [PlineSource::simplify] for more information.
+pub fn simplify<P, T, O>(polyline: &P, tolerance: T, method: SimplifyMethod, pos_equal_eps: T) -> O
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    let input = O::create_from_remove_repeat(polyline, pos_equal_eps);
+    let is_closed = input.is_closed();
+    let min_count = if is_closed { 3 } else { 2 };
+    if input.vertex_count() <= min_count {
+        return input;
+    }
+
+    // segment index pairs of the self intersects already in the polyline
+    let mut input_intersects = Vec::new();
+    input.visit_self_intersects(&mut |intr: PlineIntersect<T>| match intr {
+        PlineIntersect::Basic(b) => input_intersects.push((b.start_index1, b.start_index2)),
+        PlineIntersect::Overlapping(o) => input_intersects.push((o.start_index1, o.start_index2)),
+    });
+
+    let simplifier = Simplifier {
+        vertexes: input.iter_vertexes().collect(),
+        is_closed,
+        tolerance,
+        pos_equal_eps,
+        input_intersects,
+    };
+
+    let mut kept = match method {
+        SimplifyMethod::DouglasPeucker => simplifier.douglas_peucker(),
+        SimplifyMethod::Visvalingam => simplifier.visvalingam(min_count),
+    };
+
+    // closed polyline must remain a polygon (not collapse to a back and forth line)
+    if is_closed {
+        while kept.iter().filter(|&&k| k).count() < min_count {
+            let longest_range = simplifier
+                .kept_ranges(&kept)
+                .max_by_key(|&(a, b)| b - a)
+                .unwrap();
+            if !simplifier.restore_vertex(&mut kept, longest_range) {
+                break;
+            }
+        }
+    }
+
+    // restore vertexes for any simplified segments involved in self intersects, intersects between
+    // segments whose original paths already intersected are left as is
+    loop {
+        let result: O = simplifier.create_pline(&kept);
+        let ranges: Vec<_> = simplifier.kept_ranges(&kept).collect();
+        let mut involved = vec![false; ranges.len()];
+        let mut mark_involved = |i: usize, j: usize| {
+            if !simplifier.intersect_in_input(ranges[i], ranges[j]) {
+                involved[i] = true;
+                involved[j] = true;
+            }
+        };
+        result.visit_self_intersects(&mut |intr: PlineIntersect<T>| match intr {
+            PlineIntersect::Basic(b) => mark_involved(b.start_index1, b.start_index2),
+            PlineIntersect::Overlapping(o) => mark_involved(o.start_index1, o.start_index2),
+        });
+
+        let mut restored = false;
+        for (range, _) in ranges.into_iter().zip(involved).filter(|(_, i)| *i) {
+            restored |= simplifier.restore_vertex(&mut kept, range);
+        }
+
+        if !restored {
+            let mut result = result;
+            result.set_userdata_values(polyline.get_userdata_values());
+            return result;
+        }
+    }
+}
+
+struct Simplifier<T> {
+    vertexes: Vec<PlineVertex<T>>,
+    is_closed: bool,
+    tolerance: T,
+    pos_equal_eps: T,
+    /// Segment start index pairs of the self intersects in the original polyline.
+    input_intersects: Vec<(usize, usize)>,
+}
+
+impl<T> Simplifier<T>
+where
+    T: Real,
+{
+    /// Vertex at `index` (wrapping for closed polylines).
+    #[inline]
+    fn vertex(&self, index: usize) -> PlineVertex<T> {
+        self.vertexes[index % self.vertexes.len()]
+    }
+
+    /// Iterate over ranges `(a, b)` of original vertex indexes spanned by each segment of the
+    /// simplified polyline, for closed polylines the last range wraps (`b` is greater than the
+    /// vertex count).
+    fn kept_ranges<'a>(&'a self, kept: &'a [bool]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let n = self.vertexes.len();
+        let first = kept.iter().position(|&k| k).unwrap_or(0);
+        let mut current = first;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let next = (current + 1..n).find(|&i| kept[i]);
+            let range = match next {
+                Some(next) => (current, next),
+                None if self.is_closed => {
+                    done = true;
+                    (current, first + n)
+                }
+                None => return None,
+            };
+            current = range.1;
+            Some(range)
+        })
+    }
+
+    /// Max distance between the original path from vertex `a` to vertex `b` and the line segment
+    /// joining them. Arc segments are measured by their true path rather than chord.
+    fn range_deviation(&self, a: usize, b: usize) -> T {
+        let p0 = self.vertex(a).pos();
+        let p1 = self.vertex(b).pos();
+        let mut max = T::zero();
+        for i in a..b {
+            let d = self.seg_deviation(self.vertex(i), self.vertex(i + 1), p0, p1);
+            if d > max {
+                max = d;
+            }
+        }
+
+        max
+    }
+
+    /// Max distance from the segment `v1->v2` to the line segment `p0->p1`.
+    fn seg_deviation(
+        &self,
+        v1: PlineVertex<T>,
+        v2: PlineVertex<T>,
+        p0: Vector2<T>,
+        p1: Vector2<T>,
+    ) -> T {
+        let dist = |pt: Vector2<T>| (pt - line_seg_closest_point(p0, p1, pt)).length();
+        let d1 = dist(v1.pos());
+        let d2 = dist(v2.pos());
+        let mut max = if d1 > d2 { d1 } else { d2 };
+        if v1.bulge_is_zero() {
+            // distance to a line segment is convex along a line so max is at an end point
+            return max;
+        }
+
+        // for arcs the max is at an end point, a point where the arc tangent is parallel to the
+        // line segment, or a point furthest from one of the line segment end points
+        let (radius, center) = seg_arc_radius_and_center(v1, v2);
+        let mut candidates = Vec::with_capacity(5);
+        candidates.push(seg_midpoint(v1, v2));
+        let chord = p1 - p0;
+        if chord.length() > self.pos_equal_eps {
+            let normal = chord.unit_perp().scale(radius);
+            candidates.push(center + normal);
+            candidates.push(center - normal);
+        }
+        for p in [p0, p1] {
+            let dir = center - p;
+            if dir.length() > self.pos_equal_eps {
+                candidates.push(center + dir.normalize().scale(radius));
+            }
+        }
+
+        for pt in candidates {
+            if point_within_arc_sweep(
+                center,
+                v1.pos(),
+                v2.pos(),
+                v1.bulge_is_neg(),
+                pt,
+                self.pos_equal_eps,
+            ) {
+                let d = dist(pt);
+                if d > max {
+                    max = d;
+                }
+            }
+        }
+
+        max
+    }
+
+    /// Interior vertex (between `a` and `b`) furthest from the line segment joining them.
+    fn furthest_interior(&self, a: usize, b: usize) -> Option<usize> {
+        let p0 = self.vertex(a).pos();
+        let p1 = self.vertex(b).pos();
+        let mut result = None;
+        let mut max = -T::one();
+        for i in a + 1..b {
+            let pt = self.vertex(i).pos();
+            let d = dist_squared(pt, line_seg_closest_point(p0, p1, pt));
+            if d > max {
+                max = d;
+                result = Some(i);
+            }
+        }
+
+        result
+    }
+
+    /// Restore the interior vertex of the range `(a, b)` furthest from the segment joining them,
+    /// returns false if the range has no interior vertexes.
+    fn restore_vertex(&self, kept: &mut [bool], (a, b): (usize, usize)) -> bool {
+        match self.furthest_interior(a, b) {
+            Some(i) => {
+                kept[i % self.vertexes.len()] = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns true if the original path spanned by range `r1` intersects the original path
+    /// spanned by range `r2`.
+    fn intersect_in_input(&self, r1: (usize, usize), r2: (usize, usize)) -> bool {
+        let n = self.vertexes.len();
+        let in_range =
+            |i: usize, (a, b): (usize, usize)| (a..b).contains(&i) || (a..b).contains(&(i + n));
+        self.input_intersects.iter().any(|&(i, j)| {
+            (in_range(i, r1) && in_range(j, r2)) || (in_range(i, r2) && in_range(j, r1))
+        })
+    }
+
+    #[inline]
+    fn within_tolerance(&self, a: usize, b: usize) -> bool {
+        self.range_deviation(a, b) <= self.tolerance
+    }
+
+    fn douglas_peucker(&self) -> Vec<bool> {
+        let n = self.vertexes.len();
+        let mut kept = vec![false; n];
+        let mut stack = Vec::new();
+        kept[0] = true;
+        if self.is_closed {
+            // split closed polyline at the vertex furthest from the start
+            let start = self.vertexes[0].pos();
+            let furthest = (1..n)
+                .max_by(|&i, &j| {
+                    dist_squared(start, self.vertexes[i].pos())
+                        .total_cmp(&dist_squared(start, self.vertexes[j].pos()))
+                })
+                .unwrap();
+            kept[furthest] = true;
+            stack.push((0, furthest));
+            stack.push((furthest, n));
+        } else {
+            kept[n - 1] = true;
+            stack.push((0, n - 1));
+        }
+
+        while let Some((a, b)) = stack.pop() {
+            if b - a < 2 || self.within_tolerance(a, b) {
+                continue;
+            }
+
+            let i = self.furthest_interior(a, b).unwrap();
+            kept[i % n] = true;
+            stack.push((a, i));
+            stack.push((i, b));
+        }
+
+        kept
+    }
+
+    fn visvalingam(&self, min_count: usize) -> Vec<bool> {
+        let n = self.vertexes.len();
+        let mut kept = vec![true; n];
+        let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+        let mut versions = vec![0; n];
+        let is_removable = |i: usize| self.is_closed || (i != 0 && i != n - 1);
+        let triangle_area = |i: usize, prev: &[usize], next: &[usize]| {
+            let p0 = self.vertexes[prev[i]].pos();
+            let p1 = self.vertexes[i].pos();
+            let p2 = self.vertexes[next[i]].pos();
+            (p1 - p0).perp_dot(p2 - p0).abs() / T::two()
+        };
+
+        let mut heap = BinaryHeap::with_capacity(n);
+        for i in (0..n).filter(|&i| is_removable(i)) {
+            heap.push(AreaEntry {
+                area: triangle_area(i, &prev, &next),
+                index: i,
+                version: 0,
+            });
+        }
+
+        let mut count = n;
+        while let Some(entry) = heap.pop() {
+            if count <= min_count {
+                break;
+            }
+
+            let i = entry.index;
+            if !kept[i] || entry.version != versions[i] {
+                continue;
+            }
+
+            let p = prev[i];
+            let nx = next[i];
+            // range end is past the vertex count if wrapping around for closed polyline
+            let range_end = if nx > p { nx } else { nx + n };
+            if !self.within_tolerance(p, range_end) {
+                continue;
+            }
+
+            kept[i] = false;
+            next[p] = nx;
+            prev[nx] = p;
+            count -= 1;
+            for k in [p, nx] {
+                if is_removable(k) {
+                    versions[k] += 1;
+                    heap.push(AreaEntry {
+                        area: triangle_area(k, &prev, &next),
+                        index: k,
+                        version: versions[k],
+                    });
+                }
+            }
+        }
+
+        kept
+    }
+
+    fn create_pline<O>(&self, kept: &[bool]) -> O
+    where
+        O: PlineCreation<Num = T>,
+    {
+        let mut result = O::with_capacity(kept.iter().filter(|&&k| k).count(), self.is_closed);
+        for (a, b) in self.kept_ranges(kept) {
+            let v = self.vertexes[a];
+            // segment is kept as is (including arc) if no vertexes were removed
+            let bulge = if b - a == 1 { v.bulge } else { T::zero() };
+            result.add_vertex(v.with_bulge(bulge));
+        }
+
+        if !self.is_closed {
+            result.add_vertex(self.vertexes[self.vertexes.len() - 1]);
+        }
+
+        result
+    }
+}
+
+/// Heap entry to get the vertex with the smallest triangle area (min heap).
+struct AreaEntry<T> {
+    area: T,
+    index: usize,
+    version: usize,
+}
+
+impl<T: Real> Ord for AreaEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<T: Real> PartialOrd for AreaEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Real> PartialEq for AreaEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Real> Eq for AreaEntry<T> {}
@@ -0,0 +1,409 @@
+//! Polyline validation used by [PlineSource::validate] and the input checks used by the `try_`
+//! variants of the polyline operations.
+use crate::{
+    core::{
+        math::{Vector2, dist_squared},
+        traits::Real,
+    },
+    polyline::{
+        CavcError, PlineIntersect, PlineOffsetOptions, PlineSelfIntersectOptions, PlineSource,
+        PlineValidateIssue, PlineValidateOptions, PlineValidateResult, PlineVertex,
+        seg_arc_radius_and_center, seg_bounding_box, seg_closest_point,
+    },
+};
+use static_aabb2d_index::StaticAABB2DIndex;
+
+/// Returns true if `value` is not NaN or infinite.
+#[inline]
+pub fn is_finite<T>(value: T) -> bool
+where
+    T: Real,
+{
+    // NaN fails all comparisons and infinity is greater than the max finite value
+    value.abs() <= <T as Real>::max_value()
+}
+
+/// Returns an iterator over the invalid vertex issues of the polyline in index order, a vertex
+/// position is invalid if not finite and a bulge is invalid if not finite or its magnitude is
+/// greater than 1 (fuzzy compared).
+fn invalid_vertex_issues<P, T>(polyline: &P) -> impl Iterator<Item = PlineValidateIssue<T>>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    polyline.iter_vertexes().enumerate().flat_map(|(index, v)| {
+        let pos_issue = (!is_finite(v.x) || !is_finite(v.y))
+            .then_some(PlineValidateIssue::NonFiniteVertex { index });
+        // also fails for NaN and infinite bulge values
+        let bulge_issue = (!v.bulge.abs().fuzzy_lt(T::one()))
+            .then_some(PlineValidateIssue::InvalidBulge { index });
+        pos_issue.into_iter().chain(bulge_issue)
+    })
+}
+
+/// Check all vertex positions are finite and all bulges are finite with magnitude no greater than
+/// 1 (fuzzy compared).
+pub fn check_vertexes<P, T>(polyline: &P) -> Result<(), CavcError>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    match invalid_vertex_issues(polyline).next() {
+        None => Ok(()),
+        Some(PlineValidateIssue::NonFiniteVertex { index }) => {
+            Err(CavcError::NonFiniteVertex { index })
+        }
+        Some(PlineValidateIssue::InvalidBulge { index }) => Err(CavcError::InvalidBulge { index }),
+        Some(_) => unreachable!("only vertex issues are returned"),
+    }
+}
+
+/// Check the polyline has at least `required` vertexes.
+#[inline]
+pub fn check_vertex_count<P, T>(polyline: &P, required: usize) -> Result<(), CavcError>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let count = polyline.vertex_count();
+    if count < required {
+        return Err(CavcError::TooFewVertexes { count, required });
+    }
+
+    Ok(())
+}
+
+/// Check the polyline is closed, has at least 2 vertexes, has valid vertexes, and does not self
+/// intersect. Repeat position vertexes (within `pos_equal_eps`) are removed before checking the
+/// vertex count and scanning for self intersects. `aabb_index` is used for the self intersect scan
+/// if given and no vertexes were removed.
+pub fn check_closed_simple<P, T>(
+    polyline: &P,
+    aabb_index: Option<&StaticAABB2DIndex<T>>,
+    pos_equal_eps: T,
+) -> Result<(), CavcError>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if !polyline.is_closed() {
+        return Err(CavcError::NotClosed);
+    }
+
+    check_vertexes(polyline)?;
+    match polyline.remove_repeat_pos(pos_equal_eps) {
+        None => check_simple(polyline, aabb_index, pos_equal_eps),
+        // spatial index given is for the polyline before removing vertexes
+        Some(cleaned) => check_simple(&cleaned, None, pos_equal_eps),
+    }
+}
+
+/// Check the polyline has at least 2 vertexes and does not self intersect.
+fn check_simple<P, T>(
+    polyline: &P,
+    aabb_index: Option<&StaticAABB2DIndex<T>>,
+    pos_equal_eps: T,
+) -> Result<(), CavcError>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    check_vertex_count(polyline, 2)?;
+
+    let options = PlineSelfIntersectOptions {
+        aabb_index,
+        pos_equal_eps,
+        ..Default::default()
+    };
+
+    if polyline.scan_for_self_intersect_opt(&options) {
+        return Err(CavcError::SelfIntersecting);
+    }
+
+    Ok(())
+}
+
+/// Check the polyline (with repeat position vertexes already removed) can be offset with the
+/// `options` given. Closed polylines must not self intersect unless
+/// [PlineOffsetOptions::handle_self_intersects] is true.
+pub fn check_offset_input<P, T>(
+    polyline: &P,
+    options: &PlineOffsetOptions<T>,
+) -> Result<(), CavcError>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    check_vertex_count(polyline, 2)?;
+    if polyline.is_closed() && !options.handle_self_intersects {
+        let self_intersect_options = PlineSelfIntersectOptions {
+            aabb_index: options.aabb_index,
+            pos_equal_eps: options.pos_equal_eps,
+            ..Default::default()
+        };
+
+        if polyline.scan_for_self_intersect_opt(&self_intersect_options) {
+            return Err(CavcError::SelfIntersecting);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the polyline and report all issues found.
+///
+/// See [PlineSource::validate] for more information.
+pub fn validate<P, T>(polyline: &P, options: &PlineValidateOptions<T>) -> PlineValidateResult<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut issues = Vec::new();
+    let vc = polyline.vertex_count();
+    if vc < 2 {
+        issues.push(PlineValidateIssue::TooFewVertexes { count: vc });
+    }
+
+    issues.extend(invalid_vertex_issues(polyline));
+    if !issues.is_empty() {
+        return PlineValidateResult { issues };
+    }
+
+    let eps = options.pos_equal_eps;
+
+    // zero length segments, indexed by segment start (always false for the last vertex of an
+    // open polyline)
+    let mut zero_length = vec![false; vc];
+    for i in polyline.iter_segment_indexes().map(|(i, _)| i) {
+        let j = polyline.next_wrapping_index(i);
+        if polyline.at(i).pos().fuzzy_eq_eps(polyline.at(j).pos(), eps) {
+            zero_length[i] = true;
+            issues.push(PlineValidateIssue::ZeroLengthSegment { seg_start_index: i });
+        }
+    }
+
+    let links = ZeroLengthLinks {
+        zero_length: &zero_length,
+        is_closed: polyline.is_closed(),
+    };
+
+    // non-adjacent repeat positions, sweep over vertexes sorted by x
+    let mut sorted: Vec<usize> = (0..vc).collect();
+    sorted.sort_unstable_by(|&a, &b| polyline.at(a).x.total_cmp(&polyline.at(b).x));
+    let mut repeats = Vec::new();
+    for (k, &a) in sorted.iter().enumerate() {
+        let pos_a = polyline.at(a).pos();
+        for &b in sorted[k + 1..].iter() {
+            let pos_b = polyline.at(b).pos();
+            if pos_b.x - pos_a.x > eps {
+                break;
+            }
+
+            if pos_a.fuzzy_eq_eps(pos_b, eps) && !links.vertexes_linked(a, b) {
+                repeats.push((a.min(b), a.max(b)));
+            }
+        }
+    }
+    repeats.sort_unstable();
+    issues.extend(
+        repeats
+            .into_iter()
+            .map(|(index1, index2)| PlineValidateIssue::RepeatPosition { index1, index2 }),
+    );
+
+    if polyline.is_closed() && polyline.area().abs() <= eps {
+        issues.push(PlineValidateIssue::ZeroArea);
+    }
+
+    let constructed_index;
+    let aabb_index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    let self_intersect_options = PlineSelfIntersectOptions {
+        aabb_index: Some(aabb_index),
+        pos_equal_eps: eps,
+        ..Default::default()
+    };
+
+    polyline.visit_self_intersects_opt(
+        &mut |intr: PlineIntersect<T>| match intr {
+            PlineIntersect::Basic(intr) => {
+                let (i, j) = (intr.start_index1, intr.start_index2);
+                if zero_length[i] || zero_length[j] {
+                    return;
+                }
+
+                // segments joined by zero length segments intersect at the joining position
+                let at_joint = links.segments_linked(polyline, i, j)
+                    && (intr.point.fuzzy_eq_eps(polyline.at(i).pos(), eps)
+                        || intr
+                            .point
+                            .fuzzy_eq_eps(polyline.at(polyline.next_wrapping_index(i)).pos(), eps));
+                if !at_joint {
+                    issues.push(PlineValidateIssue::SelfIntersect {
+                        seg_start_index1: i,
+                        seg_start_index2: j,
+                        point: intr.point,
+                    });
+                }
+            }
+            PlineIntersect::Overlapping(intr) => {
+                let (i, j) = (intr.start_index1, intr.start_index2);
+                if !zero_length[i] && !zero_length[j] {
+                    issues.push(PlineValidateIssue::SelfOverlap {
+                        seg_start_index1: i,
+                        seg_start_index2: j,
+                        point1: intr.point1,
+                        point2: intr.point2,
+                    });
+                }
+            }
+        },
+        &self_intersect_options,
+    );
+
+    let touch_dist = options.near_touch_distance;
+    if touch_dist > eps {
+        let mut query_stack = Vec::with_capacity(8);
+        for (i, j) in polyline.iter_segment_indexes() {
+            if zero_length[i] {
+                continue;
+            }
+
+            let v1 = polyline.at(i);
+            let v2 = polyline.at(j);
+            let bb = seg_bounding_box(v1, v2);
+            let mut hits: Vec<usize> = aabb_index
+                .query_with_stack(
+                    bb.min_x - touch_dist,
+                    bb.min_y - touch_dist,
+                    bb.max_x + touch_dist,
+                    bb.max_y + touch_dist,
+                    &mut query_stack,
+                )
+                .into_iter()
+                .filter(|&k| k > i && !zero_length[k] && !links.segments_linked(polyline, i, k))
+                .collect();
+            hits.sort_unstable();
+
+            for k in hits {
+                let u1 = polyline.at(k);
+                let u2 = polyline.at(polyline.next_wrapping_index(k));
+                let distance = seg_distance(v1, v2, u1, u2, eps);
+                if distance > eps && distance < touch_dist {
+                    issues.push(PlineValidateIssue::NearTouch {
+                        seg_start_index1: i,
+                        seg_start_index2: k,
+                        distance,
+                    });
+                }
+            }
+        }
+    }
+
+    PlineValidateResult { issues }
+}
+
+/// Helper to determine if vertexes or segments are adjacent, treating vertexes joined by zero
+/// length segments as a single vertex.
+struct ZeroLengthLinks<'a> {
+    zero_length: &'a [bool],
+    is_closed: bool,
+}
+
+impl ZeroLengthLinks<'_> {
+    /// Returns true if vertex `to` is reached from vertex `from` by moving forward only across
+    /// zero length segments.
+    fn reaches(&self, from: usize, to: usize) -> bool {
+        let n = self.zero_length.len();
+        let mut k = from;
+        for _ in 0..n {
+            if k == to {
+                return true;
+            }
+
+            if !self.zero_length[k] {
+                return false;
+            }
+
+            k = if k + 1 == n && self.is_closed {
+                0
+            } else {
+                k + 1
+            };
+        }
+
+        false
+    }
+
+    /// Returns true if vertexes `a` and `b` are adjacent or joined by zero length segments.
+    fn vertexes_linked(&self, a: usize, b: usize) -> bool {
+        let n = self.zero_length.len();
+        let next = |k: usize| if k + 1 == n { 0 } else { k + 1 };
+        let adjacent = if self.is_closed {
+            next(a) == b || next(b) == a
+        } else {
+            a.abs_diff(b) == 1
+        };
+
+        adjacent || self.reaches(a, b) || self.reaches(b, a)
+    }
+
+    /// Returns true if segments starting at `i` and `j` share a vertex (possibly through zero
+    /// length segments).
+    fn segments_linked<P>(&self, polyline: &P, i: usize, j: usize) -> bool
+    where
+        P: PlineSource + ?Sized,
+    {
+        i == j
+            || self.reaches(polyline.next_wrapping_index(i), j)
+            || self.reaches(polyline.next_wrapping_index(j), i)
+    }
+}
+
+/// Minimum distance between the segment `v1->v2` and the segment `u1->u2` (assumes the segments
+/// do not intersect).
+fn seg_distance<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    u1: PlineVertex<T>,
+    u2: PlineVertex<T>,
+    eps: T,
+) -> T
+where
+    T: Real,
+{
+    let dist_to_u = |p: Vector2<T>| dist_squared(p, seg_closest_point(u1, u2, p, eps));
+    let dist_to_v = |p: Vector2<T>| dist_squared(p, seg_closest_point(v1, v2, p, eps));
+
+    let mut min_dist = dist_to_u(v1.pos());
+    let mut update = |d: T| {
+        if d < min_dist {
+            min_dist = d;
+        }
+    };
+
+    update(dist_to_u(v2.pos()));
+    update(dist_to_v(u1.pos()));
+    update(dist_to_v(u2.pos()));
+
+    // closest points may lie inside of arc segments, for an arc the closest point is in the
+    // direction of the other segment point closest to the arc center
+    if !v1.bulge_is_zero() {
+        let (_, center) = seg_arc_radius_and_center(v1, v2);
+        let toward = seg_closest_point(u1, u2, center, eps);
+        update(dist_to_u(seg_closest_point(v1, v2, toward, eps)));
+    }
+
+    if !u1.bulge_is_zero() {
+        let (_, center) = seg_arc_radius_and_center(u1, u2);
+        let toward = seg_closest_point(v1, v2, center, eps);
+        update(dist_to_v(seg_closest_point(u1, u2, toward, eps)));
+    }
+
+    min_dist.sqrt()
+}
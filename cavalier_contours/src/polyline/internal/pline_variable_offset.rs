@@ -0,0 +1,735 @@
+//! Parallel offset where the offset distance varies along the polyline.
+use std::cell::Cell;
+
+use static_aabb2d_index::{Control, StaticAABB2DIndex};
+
+use crate::{
+    core::{
+        math::{
+            Vector2, angle, angle_from_bulge, bulge_from_angle, delta_angle_signed, dist_squared,
+            line_seg_closest_point, normalize_radians, point_on_circle,
+        },
+        traits::Real,
+    },
+    polyline::{
+        FindIntersectsOptions, OffsetDistance, PlineCreation, PlineOffsetOptions, PlineSegIntr,
+        PlineSource, PlineSourceMut, PlineVariableOffsetOptions, PlineVertex, PlineViewData,
+        Polyline, pline_seg_intr, seg_arc_radius_and_center, seg_closest_point,
+        seg_fast_approx_bounding_box, seg_length, seg_midpoint, seg_tangent_vector,
+    },
+};
+
+use super::{
+    pline_fit_arcs::fit_arcs,
+    pline_intersects::{all_self_intersects_as_basic, find_intersects},
+    pline_offset::{ScratchBuffers, stitch_slices_together_with, visit_circle_intersects},
+};
+
+/// Maximum number of times a sample interval is halved while sampling the offset curve of a
+/// segment.
+const MAX_SUBDIVIDE_DEPTH: u32 = 16;
+
+/// Offset distance evaluated at any point along a polyline.
+struct DistanceAlongPline<'a, T> {
+    distance: OffsetDistance<'a, T>,
+    /// Path length at the start of each segment.
+    seg_start_lengths: Vec<T>,
+    /// Length of each segment.
+    seg_lengths: Vec<T>,
+    /// If true then distances are negated (used to create the dual raw offset).
+    negate: bool,
+    /// Maximum absolute distance of all the distances evaluated.
+    max_abs_evaluated: Cell<T>,
+}
+
+impl<'a, T> DistanceAlongPline<'a, T>
+where
+    T: Real,
+{
+    fn new<P>(polyline: &P, distance: OffsetDistance<'a, T>) -> Self
+    where
+        P: PlineSource<Num = T> + ?Sized,
+    {
+        let mut seg_start_lengths = Vec::with_capacity(polyline.segment_count());
+        let mut seg_lengths = Vec::with_capacity(polyline.segment_count());
+        let mut acc_length = T::zero();
+        for (v1, v2) in polyline.iter_segments() {
+            let seg_len = seg_length(v1, v2);
+            seg_start_lengths.push(acc_length);
+            seg_lengths.push(seg_len);
+            acc_length = acc_length + seg_len;
+        }
+
+        Self {
+            distance,
+            seg_start_lengths,
+            seg_lengths,
+            negate: false,
+            max_abs_evaluated: Cell::new(T::zero()),
+        }
+    }
+
+    /// Offset distance at parametric value `t` (fraction of path length from 0 to 1) along the
+    /// segment that starts at `seg_index`.
+    fn at(&self, seg_index: usize, t: T) -> T {
+        let d = match self.distance {
+            OffsetDistance::PerVertex(distances) => {
+                let d1 = distances[seg_index];
+                let d2 = distances[(seg_index + 1) % distances.len()];
+                d1 + (d2 - d1) * t
+            }
+            OffsetDistance::PathLength(f) => {
+                f(self.seg_start_lengths[seg_index] + self.seg_lengths[seg_index] * t)
+            }
+        };
+
+        if d.abs() > self.max_abs_evaluated.get() {
+            self.max_abs_evaluated.set(d.abs());
+        }
+        if self.negate { -d } else { d }
+    }
+}
+
+/// Point and unit left normal at parametric value `t` (fraction of path length from 0 to 1) along
+/// the segment from `v1` to `v2`.
+fn seg_point_and_normal<T>(v1: PlineVertex<T>, v2: PlineVertex<T>, t: T) -> (Vector2<T>, Vector2<T>)
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        let dir = v2.pos() - v1.pos();
+        return (v1.pos() + dir.scale(t), dir.unit_perp());
+    }
+
+    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+    let start_angle = angle(center, v1.pos());
+    let sweep_angle = angle_from_bulge(v1.bulge);
+    let point = point_on_circle(radius, center, start_angle + sweep_angle * t);
+    let radial = (point - center).scale(radius.recip());
+    // left side of counter clockwise arc is toward the center
+    let normal = if v1.bulge_is_pos() { -radial } else { radial };
+    (point, normal)
+}
+
+/// Parametric value (fraction of path length from 0 to 1) of `point` along the segment from `v1`
+/// to `v2`, `point` must lie on the segment.
+fn seg_parametric_at_point<T>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    point: Vector2<T>,
+    pos_equal_eps: T,
+) -> T
+where
+    T: Real,
+{
+    if point.fuzzy_eq_eps(v1.pos(), pos_equal_eps) {
+        return T::zero();
+    }
+
+    if v1.bulge_is_zero() {
+        let dir = v2.pos() - v1.pos();
+        let t = (point - v1.pos()).dot(dir) / dir.length_squared();
+        return if t < T::zero() {
+            T::zero()
+        } else if t > T::one() {
+            T::one()
+        } else {
+            t
+        };
+    }
+
+    let (_, center) = seg_arc_radius_and_center(v1, v2);
+    let sweep_angle = angle_from_bulge(v1.bulge).abs();
+    let start_angle = angle(center, v1.pos());
+    let point_angle = angle(center, point);
+    let from_start = if v1.bulge_is_pos() {
+        normalize_radians(point_angle - start_angle)
+    } else {
+        normalize_radians(start_angle - point_angle)
+    };
+
+    if from_start <= sweep_angle {
+        from_start / sweep_angle
+    } else if from_start - sweep_angle < T::tau() - from_start {
+        T::one()
+    } else {
+        T::zero()
+    }
+}
+
+/// Sample the offset curve of the segment that starts at `seg_index` between parametric values
+/// `t1` and `t2` adding the sample points after `p1` (up to and including `p2`) to `result`.
+/// Intervals are halved until the curve midpoint and quarter points are within `tolerance` of the
+/// line between the interval end points (quarter points are also tested to avoid stopping early
+/// when the midpoint happens to lie on the line, e.g. symmetric distance functions).
+#[allow(clippy::too_many_arguments)]
+fn add_offset_samples<T, O>(
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    seg_index: usize,
+    distance: &DistanceAlongPline<T>,
+    (t1, p1): (T, Vector2<T>),
+    (t2, p2): (T, Vector2<T>),
+    tolerance: T,
+    depth: u32,
+    result: &mut O,
+    pos_equal_eps: T,
+) where
+    T: Real,
+    O: PlineSourceMut<Num = T>,
+{
+    let curve_at = |t: T| {
+        let (point, normal) = seg_point_and_normal(v1, v2, t);
+        point + normal.scale(distance.at(seg_index, t))
+    };
+    let within_tolerance = |point: Vector2<T>| {
+        dist_squared(line_seg_closest_point(p1, p2, point), point) <= tolerance * tolerance
+    };
+
+    if depth < MAX_SUBDIVIDE_DEPTH {
+        let tm = (t1 + t2) / T::two();
+        let pm = curve_at(tm);
+        let quarter = (t2 - t1) / T::four();
+        if !within_tolerance(pm)
+            || !within_tolerance(curve_at(t1 + quarter))
+            || !within_tolerance(curve_at(t2 - quarter))
+        {
+            let first = (t1, p1);
+            let mid = (tm, pm);
+            let last = (t2, p2);
+            add_offset_samples(
+                v1,
+                v2,
+                seg_index,
+                distance,
+                first,
+                mid,
+                tolerance,
+                depth + 1,
+                result,
+                pos_equal_eps,
+            );
+            add_offset_samples(
+                v1,
+                v2,
+                seg_index,
+                distance,
+                mid,
+                last,
+                tolerance,
+                depth + 1,
+                result,
+                pos_equal_eps,
+            );
+            return;
+        }
+    }
+
+    result.add_or_replace(p2.x, p2.y, T::zero(), pos_equal_eps);
+}
+
+/// Create the raw (untrimmed) variable distance offset polyline, the offset curve of each segment
+/// is sampled and approximated by line segments within `tolerance`. Outward corners are joined
+/// with arcs centered on the original vertex and inward corners are joined with lines (to be
+/// trimmed).
+fn create_raw_offset<P, T>(
+    polyline: &P,
+    distance: &DistanceAlongPline<T>,
+    tolerance: T,
+    pos_equal_eps: T,
+) -> Polyline<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let mut result = Polyline::with_capacity(polyline.vertex_count(), polyline.is_closed());
+    if polyline.vertex_count() < 2 {
+        return result;
+    }
+
+    // join the last point added (end of the previous segment offset) to the start of the offset of
+    // the segment at `seg_index`, `end_tangent` is the tangent at the end of the previous segment
+    let add_join = |result: &mut Polyline<T>, end_tangent: Vector2<T>, seg_index: usize| {
+        let vertex = polyline.at(seg_index).pos();
+        let d = distance.at(seg_index, T::zero());
+        let last = result.last().unwrap();
+        let start_point = offset_point(polyline, distance, seg_index, T::zero());
+        if last.pos().fuzzy_eq_eps(start_point, pos_equal_eps) {
+            return;
+        }
+
+        let turn = end_tangent.perp_dot(start_tangent_of(polyline, seg_index));
+        let is_outward = (turn > T::zero()) != (d > T::zero());
+        if is_outward {
+            let a1 = angle(vertex, last.pos());
+            let a2 = angle(vertex, start_point);
+            let bulge = bulge_from_angle(delta_angle_signed(a1, a2, turn < T::zero()));
+            let last_index = result.vertex_count() - 1;
+            result.set_vertex(last_index, last.with_bulge(bulge));
+        }
+    };
+
+    let tolerance = tolerance / T::two();
+    for (seg_index, (v1, v2)) in polyline.iter_segments().enumerate() {
+        if seg_index != 0 {
+            let end_tangent = seg_tangent_vector(polyline.at(seg_index - 1), v1, v1.pos());
+            add_join(&mut result, end_tangent, seg_index);
+        }
+
+        let p1 = offset_point(polyline, distance, seg_index, T::zero());
+        result.add_or_replace(p1.x, p1.y, T::zero(), pos_equal_eps);
+
+        // arcs are sampled at least enough to keep the chord error of the offset arc within
+        // tolerance (distance function may also vary along the arc)
+        let sample_count = if v1.bulge_is_zero() {
+            1
+        } else {
+            let (arc_radius, _) = seg_arc_radius_and_center(v1, v2);
+            // upper bound of the offset arc radius
+            let radius = arc_radius
+                + distance.at(seg_index, T::zero()).abs()
+                + distance.at(seg_index, T::one()).abs();
+            let sweep_angle = angle_from_bulge(v1.bulge).abs();
+            if tolerance < radius {
+                let sub_angle = T::two() * (T::one() - tolerance / radius).acos();
+                (sweep_angle / sub_angle)
+                    .ceil()
+                    .to_usize()
+                    .unwrap_or(1)
+                    .max(1)
+            } else {
+                1
+            }
+        };
+
+        let mut prev = (T::zero(), p1);
+        for i in 1..=sample_count {
+            let t = T::from(i).unwrap() / T::from(sample_count).unwrap();
+            let p = offset_point(polyline, distance, seg_index, t);
+            add_offset_samples(
+                v1,
+                v2,
+                seg_index,
+                distance,
+                prev,
+                (t, p),
+                tolerance,
+                0,
+                &mut result,
+                pos_equal_eps,
+            );
+            prev = (t, p);
+        }
+
+        if polyline.is_closed() && seg_index + 1 == polyline.segment_count() {
+            // join back to start of the first segment
+            let end_tangent = seg_tangent_vector(v1, v2, v2.pos());
+            add_join(&mut result, end_tangent, 0);
+        }
+    }
+
+    if polyline.is_closed()
+        && result.vertex_count() > 1
+        && result
+            .at(0)
+            .pos()
+            .fuzzy_eq_eps(result.last().unwrap().pos(), pos_equal_eps)
+    {
+        result.remove_last();
+    }
+
+    if result.vertex_count() == 1 {
+        result.clear();
+    }
+
+    result
+}
+
+/// Offset point at parametric value `t` along the segment that starts at `seg_index`.
+fn offset_point<P, T>(
+    polyline: &P,
+    distance: &DistanceAlongPline<T>,
+    seg_index: usize,
+    t: T,
+) -> Vector2<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let v1 = polyline.at(seg_index);
+    let v2 = polyline.at(polyline.next_wrapping_index(seg_index));
+    let (point, normal) = seg_point_and_normal(v1, v2, t);
+    point + normal.scale(distance.at(seg_index, t))
+}
+
+/// Tangent at the start of the segment that starts at `seg_index`.
+fn start_tangent_of<P, T>(polyline: &P, seg_index: usize) -> Vector2<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let v1 = polyline.at(seg_index);
+    let v2 = polyline.at(polyline.next_wrapping_index(seg_index));
+    seg_tangent_vector(v1, v2, v1.pos())
+}
+
+/// Returns true if `point` is at least the offset distance (at the closest point on each nearby
+/// segment) away from the original polyline.
+///
+/// If the closest point on a segment is a vertex shared with an adjacent segment then the point is
+/// only tested against the vertex if it is also the closest point on the adjacent segment (point
+/// lies in the region of an outward corner). Otherwise the adjacent segment determines validity,
+/// this is required since the offset curve passes within the offset distance of the vertex when
+/// the distance decreases along the segment after the vertex.
+#[allow(clippy::too_many_arguments)]
+fn point_valid_for_offset<P, T>(
+    polyline: &P,
+    distance: &DistanceAlongPline<T>,
+    max_dist: T,
+    aabb_index: &StaticAABB2DIndex<T>,
+    point: Vector2<T>,
+    query_stack: &mut Vec<usize>,
+    pos_equal_eps: T,
+    offset_tol: T,
+) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let vc = polyline.vertex_count();
+    let is_closed = polyline.is_closed();
+    let closest_point_is_vertex = |seg_index: usize, vertex: Vector2<T>| {
+        let v1 = polyline.at(seg_index);
+        let v2 = polyline.at(polyline.next_wrapping_index(seg_index));
+        seg_closest_point(v1, v2, point, pos_equal_eps).fuzzy_eq_eps(vertex, pos_equal_eps)
+    };
+
+    let mut point_valid = true;
+    let mut visitor = |i: usize| {
+        let j = polyline.next_wrapping_index(i);
+        let v1 = polyline.at(i);
+        let v2 = polyline.at(j);
+        let closest_point = seg_closest_point(v1, v2, point, pos_equal_eps);
+        let at_start = closest_point.fuzzy_eq_eps(v1.pos(), pos_equal_eps);
+        let at_end = closest_point.fuzzy_eq_eps(v2.pos(), pos_equal_eps);
+        if at_start && (is_closed || i != 0) {
+            let prev_seg = polyline.prev_wrapping_index(i);
+            if !closest_point_is_vertex(prev_seg, v1.pos()) {
+                return Control::Continue;
+            }
+        } else if at_end && (is_closed || j != vc - 1) && !closest_point_is_vertex(j, v2.pos()) {
+            return Control::Continue;
+        }
+
+        let t = seg_parametric_at_point(v1, v2, closest_point, pos_equal_eps);
+        let min_dist = distance.at(i, t).abs() - offset_tol;
+        point_valid =
+            min_dist <= T::zero() || dist_squared(closest_point, point) > min_dist * min_dist;
+        if point_valid {
+            Control::Continue
+        } else {
+            Control::Break(())
+        }
+    };
+
+    aabb_index.visit_query_with_stack(
+        point.x - max_dist,
+        point.y - max_dist,
+        point.x + max_dist,
+        point.y + max_dist,
+        &mut visitor,
+        query_stack,
+    );
+    point_valid
+}
+
+/// Returns true if the segment from `v1` to `v2` intersects the original polyline at a point
+/// where the offset distance is not zero.
+fn intersects_original_pline<P, T>(
+    polyline: &P,
+    distance: &DistanceAlongPline<T>,
+    aabb_index: &StaticAABB2DIndex<T>,
+    v1: PlineVertex<T>,
+    v2: PlineVertex<T>,
+    query_stack: &mut Vec<usize>,
+    options: &PlineVariableOffsetOptions<T>,
+) -> bool
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    let approx_bb = seg_fast_approx_bounding_box(v1, v2);
+    let mut has_intersect = false;
+    let mut visitor = |i: usize| {
+        let u1 = polyline.at(i);
+        let u2 = polyline.at(polyline.next_wrapping_index(i));
+        let nonzero_dist_at = |point: Vector2<T>| {
+            let t = seg_parametric_at_point(u1, u2, point, pos_equal_eps);
+            distance.at(i, t).abs() > options.offset_dist_eps
+        };
+
+        has_intersect = match pline_seg_intr(v1, v2, u1, u2, pos_equal_eps) {
+            PlineSegIntr::NoIntersect => false,
+            PlineSegIntr::TangentIntersect { point } | PlineSegIntr::OneIntersect { point } => {
+                nonzero_dist_at(point)
+            }
+            PlineSegIntr::TwoIntersects { point1, point2 }
+            | PlineSegIntr::OverlappingLines { point1, point2 }
+            | PlineSegIntr::OverlappingArcs { point1, point2 } => {
+                nonzero_dist_at(point1) || nonzero_dist_at(point2)
+            }
+        };
+
+        if has_intersect {
+            Control::Break(())
+        } else {
+            Control::Continue
+        }
+    };
+
+    let fuzz = T::fuzzy_epsilon();
+    aabb_index.visit_query_with_stack(
+        approx_bb.min_x - fuzz,
+        approx_bb.min_y - fuzz,
+        approx_bb.max_x + fuzz,
+        approx_bb.max_y + fuzz,
+        &mut visitor,
+        query_stack,
+    );
+    has_intersect
+}
+
+/// Compute the variable distance parallel offset polylines of the polyline.
+///
+/// See [PlineSource::variable_offset_opt] for more information.
+///
+/// # Panics
+///
+/// Panics if [OffsetDistance::PerVertex] is given and the number of distances does not equal the
+/// vertex count.
+pub fn variable_offset<P, T, O>(
+    polyline: &P,
+    distance: OffsetDistance<T>,
+    options: &PlineVariableOffsetOptions<T>,
+) -> Vec<O>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+    O: PlineCreation<Num = T>,
+{
+    if let OffsetDistance::PerVertex(distances) = distance {
+        assert_eq!(
+            distances.len(),
+            polyline.vertex_count(),
+            "must have one offset distance per vertex"
+        );
+    }
+
+    if polyline.vertex_count() < 2 {
+        return Vec::new();
+    }
+    debug_assert!(
+        polyline.remove_repeat_pos(options.pos_equal_eps).is_none(),
+        "bug: input assumed to not have repeat position vertexes"
+    );
+
+    let pos_equal_eps = options.pos_equal_eps;
+    let tolerance = if options.tolerance > pos_equal_eps {
+        options.tolerance
+    } else {
+        pos_equal_eps
+    };
+
+    let constructed_index;
+    let index = if let Some(x) = options.aabb_index {
+        x
+    } else {
+        constructed_index = polyline.create_approx_aabb_index();
+        &constructed_index
+    };
+
+    let mut distance = DistanceAlongPline::new(polyline, distance);
+
+    // half the tolerance is used for sampling the offset curve, the other half for fitting arcs
+    let raw_offset = create_raw_offset(polyline, &distance, tolerance, pos_equal_eps);
+    if raw_offset.vertex_count() < 2 {
+        return Vec::new();
+    }
+
+    // bounds the query used to test points for validity (distances are evaluated at every sample
+    // while creating the raw offset)
+    let max_dist = distance.max_abs_evaluated.get() + options.offset_dist_eps;
+
+    let raw_offset_index = raw_offset.create_approx_aabb_index();
+    let offset_options = PlineOffsetOptions {
+        pos_equal_eps,
+        slice_join_eps: options.slice_join_eps,
+        offset_dist_eps: options.offset_dist_eps,
+        ..Default::default()
+    };
+
+    let mut intr_points = Vec::new();
+    for si in all_self_intersects_as_basic(&raw_offset, &raw_offset_index, false, pos_equal_eps) {
+        intr_points.push((si.start_index1, si.point));
+        intr_points.push((si.start_index2, si.point));
+    }
+
+    let is_closed = polyline.is_closed();
+    let use_dual = !is_closed || options.handle_self_intersects;
+    if use_dual {
+        distance.negate = true;
+        let dual_raw_offset = create_raw_offset(polyline, &distance, tolerance, pos_equal_eps);
+        distance.negate = false;
+        let dual_intrs = find_intersects(
+            &raw_offset,
+            &dual_raw_offset,
+            &FindIntersectsOptions {
+                pline1_aabb_index: Some(&raw_offset_index),
+                pos_equal_eps,
+            },
+        );
+        intr_points.extend(
+            dual_intrs
+                .basic_intersects
+                .iter()
+                .map(|intr| (intr.start_index1, intr.point)),
+        );
+    }
+
+    if !is_closed {
+        // intersects with circles at the end points of the original polyline
+        let mut add_intr = |start_index: usize, intr: Vector2<T>| {
+            intr_points.push((start_index, intr));
+        };
+        let last_seg_index = polyline.segment_count() - 1;
+        let end_circles = [
+            (polyline.at(0).pos(), distance.at(0, T::zero()).abs()),
+            (
+                polyline.last().unwrap().pos(),
+                distance.at(last_seg_index, T::one()).abs(),
+            ),
+        ];
+        for (center, radius) in end_circles {
+            if radius > options.offset_dist_eps {
+                visit_circle_intersects(
+                    &raw_offset,
+                    center,
+                    radius,
+                    &raw_offset_index,
+                    &mut add_intr,
+                    &offset_options,
+                );
+            }
+        }
+    }
+
+    // sort by segment index then by distance from segment start vertex
+    intr_points.sort_unstable_by(|&(i1, p1), &(i2, p2)| {
+        i1.cmp(&i2).then_with(|| {
+            let start_pos = raw_offset.at(i1).pos();
+            dist_squared(p1, start_pos).total_cmp(&dist_squared(p2, start_pos))
+        })
+    });
+    intr_points.dedup_by(|a, b| a.0 == b.0 && a.1.fuzzy_eq_eps(b.1, pos_equal_eps));
+
+    let mut query_stack = Vec::new();
+    // raw offset deviates from the exact offset curve by up to the sampling tolerance
+    let offset_tol = options.offset_dist_eps + tolerance;
+    let point_valid = |point: Vector2<T>, query_stack: &mut Vec<usize>| {
+        point_valid_for_offset(
+            polyline,
+            &distance,
+            max_dist,
+            index,
+            point,
+            query_stack,
+            pos_equal_eps,
+            offset_tol,
+        )
+    };
+
+    let slice_is_valid = |slice: &PlineViewData<T>, query_stack: &mut Vec<usize>| {
+        for (v1, v2) in slice.view(&raw_offset).iter_segments() {
+            if !point_valid(v1.pos(), query_stack)
+                || !point_valid(seg_midpoint(v1, v2), query_stack)
+                || intersects_original_pline(
+                    polyline,
+                    &distance,
+                    index,
+                    v1,
+                    v2,
+                    query_stack,
+                    options,
+                )
+            {
+                return false;
+            }
+        }
+
+        point_valid(slice.end_point, query_stack)
+    };
+
+    let mut slices = Vec::new();
+    let mut add_slice = |start: (usize, Vector2<T>), end: (usize, Vector2<T>)| {
+        if let Some(s) = PlineViewData::from_slice_points(
+            &raw_offset,
+            start.1,
+            start.0,
+            end.1,
+            end.0,
+            pos_equal_eps,
+        ) && slice_is_valid(&s, &mut query_stack)
+        {
+            slices.push(s);
+        }
+    };
+
+    if intr_points.is_empty() {
+        let slice = PlineViewData::from_entire_pline(&raw_offset);
+        if slice_is_valid(&slice, &mut query_stack) {
+            slices.push(slice);
+        }
+    } else {
+        let first = intr_points[0];
+        let last = *intr_points.last().unwrap();
+        if !is_closed {
+            add_slice((0, raw_offset.at(0).pos()), first);
+        }
+
+        for w in intr_points.windows(2) {
+            add_slice(w[0], w[1]);
+        }
+
+        if is_closed {
+            add_slice(last, first);
+        } else {
+            let end_index = raw_offset.vertex_count() - 1;
+            add_slice(last, (end_index, raw_offset.at(end_index).pos()));
+        }
+    }
+
+    let orig_max_index = if is_closed && !use_dual {
+        raw_offset.vertex_count() - 1
+    } else {
+        raw_offset.vertex_count()
+    };
+
+    let stitched: Vec<Polyline<T>> = stitch_slices_together_with(
+        &raw_offset,
+        &slices,
+        is_closed,
+        orig_max_index,
+        &offset_options,
+        &mut ScratchBuffers::new(),
+    );
+
+    stitched
+        .iter()
+        .map(|pline| {
+            let mut result: O = fit_arcs(pline, tolerance / T::two(), pos_equal_eps);
+            result.set_userdata_values(polyline.get_userdata_values());
+            result
+        })
+        .collect()
+}
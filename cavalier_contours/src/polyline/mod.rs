@@ -9,6 +9,7 @@ mod pline_vertex;
 mod pline_view;
 mod traits;
 
+pub use internal::pline_convex_hull::plines_convex_hull;
 pub use pline::*;
 pub use pline_seg::*;
 pub use pline_seg_intersect::*;
@@ -1,6 +1,9 @@
 //! Supporting public types used in the core polyline trait methods.
 
-use super::{PlineVertex, PlineView, PlineViewData, internal::pline_intersects::OverlappingSlice};
+use super::{
+    PlineVertex, PlineView, PlineViewData,
+    internal::{pline_intersects::OverlappingSlice, pline_offset::OffsetBuffers},
+};
 use crate::{
     core::{
         math::Vector2,
@@ -9,6 +12,7 @@ use crate::{
     polyline::{PlineCreation, PlineSegIntr, PlineSource, ViewDataValidation},
 };
 use static_aabb2d_index::StaticAABB2DIndex;
+use std::sync::{Mutex, TryLockError};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -38,6 +42,75 @@ where
     pub distance: T,
 }
 
+/// Result from calling [PlineSource::min_feature_width].
+#[derive(Debug, Copy, Clone)]
+pub struct MinFeatureWidthResult<T = f64>
+where
+    T: Real,
+{
+    /// The narrowest width (distance between `point1` and `point2`).
+    pub width: T,
+    /// The start vertex index of the segment `point1` lies on.
+    pub seg_start_index1: usize,
+    /// Point on one side of the narrowest width.
+    pub point1: Vector2<T>,
+    /// The start vertex index of the segment `point2` lies on.
+    pub seg_start_index2: usize,
+    /// Point on the other side of the narrowest width.
+    pub point2: Vector2<T>,
+}
+
+/// Struct to hold options parameters when calling [PlineSource::min_feature_width_opt].
+#[derive(Debug, Clone)]
+pub struct PlineFeatureWidthOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineFeatureWidthOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineFeatureWidthOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Style used to join adjacent segments at outward corners when performing a parallel offset.
+///
+/// Inward corners are always trimmed at the intersect of the offset segments, the join style only
+/// affects corners where the offset segments must be extended to be connected.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JoinStyle<T = f64> {
+    /// Join using an arc centered on the original vertex (rounded corner).
+    Round,
+    /// Join by extending the offset segments along their tangents until they meet (sharp corner).
+    /// If the distance from the original vertex to the miter point divided by the offset distance
+    /// exceeds `limit` then the corner is joined using [JoinStyle::Bevel] instead.
+    Miter {
+        /// Maximum ratio of miter point distance to offset distance.
+        limit: T,
+    },
+    /// Join using a line segment between the ends of the offset segments (flat corner).
+    Bevel,
+}
+
 /// Struct to hold options parameters when performing polyline offset.
 #[derive(Debug, Clone)]
 pub struct PlineOffsetOptions<'a, T = f64>
@@ -63,6 +136,11 @@ where
     /// Fuzzy comparison epsilon used when testing distance of slices to original polyline for
     /// validity.
     pub offset_dist_eps: T,
+    /// Style used to join segments at outward corners.
+    pub join_style: JoinStyle<T>,
+    /// Workspace to reuse buffers across calls, if `None` is given then buffers are allocated for
+    /// each call. See [OffsetContext].
+    pub context: Option<&'a OffsetContext<T>>,
 }
 
 impl<T> PlineOffsetOptions<'_, T>
@@ -77,6 +155,8 @@ where
             pos_equal_eps: T::from(1e-5).unwrap(),
             slice_join_eps: T::from(1e-4).unwrap(),
             offset_dist_eps: T::from(1e-4).unwrap(),
+            join_style: JoinStyle::Round,
+            context: None,
         }
     }
 }
@@ -91,6 +171,583 @@ where
     }
 }
 
+/// Workspace that holds buffers which can be reused across calls to
+/// [PlineSource::parallel_offset_opt] and [PlineSource::boolean_opt].
+///
+/// Offset and boolean operations allocate temporary buffers on every call (raw offset segments and
+/// polylines, intersects, slices, and buffers used while stitching slices together). Passing the
+/// same context in the options of repeated calls reuses the buffers so after the first call few
+/// allocations are required beyond the result polylines. Results are the same with or without a
+/// context.
+///
+/// Spatial indexes are still built on each call, the spatial index of the input polyline may be
+/// reused by also passing it in the options (e.g. [PlineOffsetOptions::aabb_index]).
+///
+/// The context may be shared between threads, a call made while the context is already in use
+/// allocates its own buffers (one context per thread is required to get the benefit).
+///
+/// # Examples
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_closed;
+/// let pline = pline_closed![
+///     (0.0, 0.0, 0.0),
+///     (10.0, 0.0, 0.0),
+///     (10.0, 10.0, 0.0),
+///     (0.0, 10.0, 0.0),
+/// ];
+/// let context = OffsetContext::new();
+/// let options = PlineOffsetOptions {
+///     context: Some(&context),
+///     ..Default::default()
+/// };
+/// for i in 1..10 {
+///     let offset_plines = pline.parallel_offset_opt(0.5 * i as f64, &options);
+///     assert_eq!(offset_plines.len(), 1);
+/// }
+/// ```
+pub struct OffsetContext<T = f64>
+where
+    T: Real,
+{
+    buffers: Mutex<OffsetBuffers<T>>,
+}
+
+impl<T> OffsetContext<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            buffers: Mutex::new(OffsetBuffers::new()),
+        }
+    }
+
+    /// Call `f` with the buffers held by `context`, if `context` is `None` or already in use then
+    /// `f` is called with newly allocated buffers.
+    pub(crate) fn with_buffers<F, R>(context: Option<&Self>, f: F) -> R
+    where
+        F: FnOnce(&mut OffsetBuffers<T>) -> R,
+    {
+        let guard = context.and_then(|c| match c.buffers.try_lock() {
+            Ok(guard) => Some(guard),
+            // buffers are always cleared before use so they are still valid after a panic
+            Err(TryLockError::Poisoned(e)) => Some(e.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        });
+
+        match guard {
+            Some(mut buffers) => f(&mut buffers),
+            None => f(&mut OffsetBuffers::new()),
+        }
+    }
+}
+
+impl<T> Default for OffsetContext<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for OffsetContext<T>
+where
+    T: Real,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OffsetContext").finish_non_exhaustive()
+    }
+}
+
+/// Style used to cap the ends of an open polyline when creating a buffer (stroke) around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndCapStyle {
+    /// Half circle centered on the end point.
+    Round,
+    /// Square end that extends past the end point by the buffer distance.
+    Square,
+    /// Flat end that passes through the end point.
+    Butt,
+}
+
+/// Struct to hold options parameters when creating a buffer (stroke) around a polyline.
+#[derive(Debug, Clone)]
+pub struct PlineBufferOptions<T = f64>
+where
+    T: Real,
+{
+    /// Style used to cap the ends of open polylines (not used for closed polylines).
+    pub end_cap: EndCapStyle,
+    /// Style used to join segments at outward corners.
+    pub join_style: JoinStyle<T>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together.
+    pub slice_join_eps: T,
+    /// Fuzzy comparison epsilon used when testing distance of slices to original polyline for
+    /// validity.
+    pub offset_dist_eps: T,
+}
+
+impl<T> PlineBufferOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            end_cap: EndCapStyle::Round,
+            join_style: JoinStyle::Round,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+            offset_dist_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineBufferOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Offset distance along a polyline used when performing a variable distance offset, see
+/// [PlineSource::variable_offset].
+///
+/// Positive distances offset to the left of the polyline segment tangent vectors and negative
+/// distances offset to the right (same as [PlineSource::parallel_offset]), all distances should
+/// have the same sign.
+#[derive(Clone, Copy)]
+pub enum OffsetDistance<'a, T = f64> {
+    /// Distance at each vertex (length must equal the polyline vertex count), linearly
+    /// interpolated by path length along each segment. For closed polylines the last segment
+    /// interpolates between the last and first distance.
+    PerVertex(&'a [T]),
+    /// Distance as a function of path length from the start of the polyline (from zero to the
+    /// total path length, see [PlineSource::find_point_at_path_length]).
+    PathLength(&'a dyn Fn(T) -> T),
+}
+
+impl<T> std::fmt::Debug for OffsetDistance<'_, T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PerVertex(distances) => f.debug_tuple("PerVertex").field(distances).finish(),
+            Self::PathLength(_) => f.debug_tuple("PathLength").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Struct to hold options parameters when performing a variable distance polyline offset.
+#[derive(Debug, Clone)]
+pub struct PlineVariableOffsetOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index of all the polyline segment bounding boxes (or boxes no smaller, e.g. using
+    /// [PlineSource::create_approx_aabb_index] is valid). If `None` is given then it will be
+    /// computed internally.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// If true then self intersects will be properly handled by the offset algorithm, if false then
+    /// self intersecting polylines may not offset correctly.
+    pub handle_self_intersects: bool,
+    /// Maximum distance between the resulting polylines and the exact variable distance offset
+    /// curve. The offset curve is sampled and then approximated by line and arc segments.
+    pub tolerance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together.
+    pub slice_join_eps: T,
+    /// Fuzzy comparison epsilon used when testing distance of slices to original polyline for
+    /// validity.
+    pub offset_dist_eps: T,
+}
+
+impl<T> PlineVariableOffsetOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            handle_self_intersects: false,
+            tolerance: T::from(1e-3).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+            offset_dist_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineVariableOffsetOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Trait used to select which polyline vertexes (corners) are modified when performing a fillet
+/// or chamfer, implemented for closures of the form `FnMut(usize) -> bool` (argument is the vertex
+/// index).
+pub trait CornerSelector {
+    /// Returns true if the corner at `vertex_index` should be modified.
+    fn select(&mut self, vertex_index: usize) -> bool;
+}
+
+impl<F> CornerSelector for F
+where
+    F: FnMut(usize) -> bool,
+{
+    #[inline]
+    fn select(&mut self, vertex_index: usize) -> bool {
+        self(vertex_index)
+    }
+}
+
+/// [CornerSelector] that selects all corners.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllCorners;
+
+impl CornerSelector for AllCorners {
+    #[inline]
+    fn select(&mut self, _vertex_index: usize) -> bool {
+        true
+    }
+}
+
+/// Result from calling [PlineSource::fillet] or [PlineSource::chamfer].
+#[derive(Debug, Clone)]
+pub struct CornerModifyResult<P> {
+    /// Resulting polyline with corners modified.
+    pub pline: P,
+    /// Indexes (of the input polyline) of the selected vertexes that could not be modified, e.g.
+    /// because the adjacent segments are too short to fit the fillet or chamfer.
+    pub skipped_vertexes: Vec<usize>,
+}
+
+/// Struct to hold options parameters when calling [PlineSource::fillet_opt] or
+/// [PlineSource::chamfer_opt].
+#[derive(Debug, Clone)]
+pub struct PlineCornerOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineCornerOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineCornerOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when calling [PlineSource::fit_arcs_opt].
+#[derive(Debug, Clone)]
+pub struct PlineFitArcsOptions<T = f64>
+where
+    T: Real,
+{
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineFitArcsOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineFitArcsOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Method used to select which vertexes are removed when calling [PlineSource::simplify].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimplifyMethod {
+    /// Ramer–Douglas–Peucker, recursively keeps the vertex furthest from the segment joining the
+    /// kept vertexes until all removed vertexes are within tolerance.
+    DouglasPeucker,
+    /// Visvalingam–Whyatt, repeatedly removes the vertex forming the smallest area triangle with
+    /// its neighbors while the path remains within tolerance.
+    Visvalingam,
+}
+
+/// Error returned when a transform cannot be applied, see [PlineSourceMut::transform_mut].
+///
+/// [PlineSourceMut::transform_mut]: crate::polyline::PlineSourceMut::transform_mut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformError {
+    /// Transform is not a similarity (non-uniform scale, skew, or degenerate) and the polyline has
+    /// arc segments which would not remain circular. Arcs can be converted to line segments first,
+    /// e.g. with [PlineSource::arcs_to_approx_lines].
+    NonUniformScaleWithArcs,
+}
+
+impl std::fmt::Display for TransformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransformError::NonUniformScaleWithArcs => write!(
+                f,
+                "transform does not preserve circles and polyline has arc segments"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TransformError {}
+
+/// Error returned by the `try_` variants of polyline operations (e.g.
+/// [PlineSource::try_parallel_offset]) describing why the input is not valid for the operation.
+///
+/// For operations involving two polylines `self` is checked first then `other`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CavcError {
+    /// Polyline has fewer vertexes than required by the operation (after removing repeat position
+    /// vertexes if the operation requires it).
+    TooFewVertexes {
+        /// Number of vertexes the polyline has.
+        count: usize,
+        /// Minimum number of vertexes required.
+        required: usize,
+    },
+    /// Vertex at `index` has a NaN or infinite coordinate.
+    NonFiniteVertex { index: usize },
+    /// Vertex at `index` has a bulge that is NaN, infinite, or has a magnitude greater than 1 (arc
+    /// segments must not sweep more than a half circle).
+    InvalidBulge { index: usize },
+    /// Polyline self intersects and the operation requires it does not.
+    SelfIntersecting,
+    /// Operation requires a closed polyline but an open polyline was given.
+    NotClosed,
+}
+
+impl std::fmt::Display for CavcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CavcError::TooFewVertexes { count, required } => write!(
+                f,
+                "polyline has {count} vertexes but at least {required} are required"
+            ),
+            CavcError::NonFiniteVertex { index } => {
+                write!(f, "vertex {index} has a NaN or infinite coordinate")
+            }
+            CavcError::InvalidBulge { index } => {
+                write!(f, "vertex {index} has an invalid bulge value")
+            }
+            CavcError::SelfIntersecting => write!(f, "polyline self intersects"),
+            CavcError::NotClosed => write!(f, "operation requires a closed polyline"),
+        }
+    }
+}
+
+impl std::error::Error for CavcError {}
+
+/// Struct to hold options parameters when calling [PlineSource::validate].
+#[derive(Debug, Clone)]
+pub struct PlineValidateOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index of all the polyline segment bounding boxes. If `None` is given then it will
+    /// be computed internally.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Non-adjacent segments that do not intersect but are closer than this distance are reported
+    /// as [PlineValidateIssue::NearTouch]. If not greater than `pos_equal_eps` then near touching
+    /// segments are not checked.
+    pub near_touch_distance: T,
+}
+
+impl<T> PlineValidateOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            near_touch_distance: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineValidateOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Issue found when calling [PlineSource::validate], segments are identified by their start vertex
+/// index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlineValidateIssue<T = f64> {
+    /// Polyline has `count` vertexes, at least 2 are required to form a segment.
+    TooFewVertexes { count: usize },
+    /// Vertex at `index` has a NaN or infinite coordinate.
+    NonFiniteVertex { index: usize },
+    /// Vertex at `index` has a bulge that is NaN, infinite, or has a magnitude greater than 1.
+    InvalidBulge { index: usize },
+    /// Segment starting at `seg_start_index` has zero length (vertex position repeats on the next
+    /// vertex), these are removed by [PlineSource::remove_repeat_pos].
+    ZeroLengthSegment { seg_start_index: usize },
+    /// Vertexes at `index1` and `index2` (`index1 < index2`) are not adjacent but have the same
+    /// position.
+    RepeatPosition { index1: usize, index2: usize },
+    /// Segments intersect at `point`.
+    SelfIntersect {
+        seg_start_index1: usize,
+        seg_start_index2: usize,
+        point: Vector2<T>,
+    },
+    /// Segments overlap from `point1` to `point2`.
+    SelfOverlap {
+        seg_start_index1: usize,
+        seg_start_index2: usize,
+        point1: Vector2<T>,
+        point2: Vector2<T>,
+    },
+    /// Non-adjacent segments do not intersect but are within
+    /// [PlineValidateOptions::near_touch_distance] of each other.
+    NearTouch {
+        seg_start_index1: usize,
+        seg_start_index2: usize,
+        distance: T,
+    },
+    /// Polyline is closed but has zero area.
+    ZeroArea,
+}
+
+/// Result of calling [PlineSource::validate].
+#[derive(Debug, Clone, Default)]
+pub struct PlineValidateResult<T = f64> {
+    /// All issues found, grouped by kind in the order: too few vertexes, invalid vertexes, zero
+    /// length segments, repeat positions, zero area, self intersects/overlaps, and near touches.
+    /// If there are too few vertexes or any vertex is not valid (non-finite or invalid bulge) then
+    /// no other checks are performed.
+    pub issues: Vec<PlineValidateIssue<T>>,
+}
+
+impl<T> PlineValidateResult<T> {
+    /// Returns true if no issues were found.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Rule used to determine which regions are filled (inside) according to their winding number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// Region is filled where the winding number is not zero.
+    NonZero,
+    /// Region is filled where the winding number is odd.
+    EvenOdd,
+    /// Region is filled where the winding number is greater than zero.
+    Positive,
+    /// Region is filled where the winding number is less than zero.
+    Negative,
+}
+
+impl FillRule {
+    /// Returns true if a region with the `winding_number` given is filled.
+    #[inline]
+    pub fn is_filled(&self, winding_number: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding_number != 0,
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::Positive => winding_number > 0,
+            FillRule::Negative => winding_number < 0,
+        }
+    }
+}
+
+/// Struct to hold options parameters when calling [PlineSource::resolve_self_intersects_opt].
+#[derive(Debug, Clone)]
+pub struct PlineResolveOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index of all the polyline segment bounding boxes. If `None` is given then it will
+    /// be computed internally.
+    pub aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineResolveOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineResolveOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // The containment functions use the same underlying mechinsims as the boolean functions.
 /// Information about what happened during the boolean operation.
 #[derive(Debug, Clone, PartialEq)]
@@ -271,6 +928,9 @@ where
     pub pline1_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
     /// Fuzzy comparison epsilon used for determining if two positions are equal.
     pub pos_equal_eps: T,
+    /// Workspace to reuse buffers across calls, if `None` is given then buffers are allocated for
+    /// each call. See [OffsetContext].
+    pub context: Option<&'a OffsetContext<T>>,
 }
 
 impl<T> PlineBooleanOptions<'_, T>
@@ -282,6 +942,7 @@ where
         Self {
             pline1_aabb_index: None,
             pos_equal_eps: T::from(1e-5).unwrap(),
+            context: None,
         }
     }
 }
@@ -296,6 +957,142 @@ where
     }
 }
 
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+/// Location of a slice from clipping a polyline relative to the region it was clipped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClipLocation {
+    /// Slice is inside the region.
+    Inside,
+    /// Slice is outside the region.
+    Outside,
+    /// Slice lies on the boundary of the region.
+    OnBoundary,
+}
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase"),
+    serde(bound(
+        serialize = "P: Serialize, P::Num: Serialize",
+        deserialize = "P: Deserialize<'de>, P::Num: Deserialize<'de>",
+    ))
+)]
+/// Represents one of the polyline slices from clipping a polyline by a region.
+#[derive(Debug, Clone)]
+pub struct ClipResultPline<P>
+where
+    P: PlineCreation,
+{
+    /// Resultant open polyline.
+    pub pline: P,
+    /// View data for the slice, can be used with the polyline that was clipped to form a view of
+    /// the vertexes for the slice (indexes refer to the polyline that was clipped).
+    pub view_data: PlineViewData<P::Num>,
+}
+
+impl<P> ClipResultPline<P>
+where
+    P: PlineCreation,
+{
+    #[inline]
+    pub fn new(pline: P, view_data: PlineViewData<P::Num>) -> Self {
+        Self { pline, view_data }
+    }
+}
+
+/// Result of clipping a polyline by a region, each collection holds the slices in the order they
+/// appear along the polyline that was clipped.
+#[derive(Debug, Clone)]
+pub struct PlineClipResult<P>
+where
+    P: PlineCreation,
+{
+    /// Slices inside of the region.
+    pub inside: Vec<ClipResultPline<P>>,
+    /// Slices outside of the region.
+    pub outside: Vec<ClipResultPline<P>>,
+    /// Slices that lie on the boundary of the region.
+    pub on_boundary: Vec<ClipResultPline<P>>,
+}
+
+impl<P> PlineClipResult<P>
+where
+    P: PlineCreation,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inside: Vec::new(),
+            outside: Vec::new(),
+            on_boundary: Vec::new(),
+        }
+    }
+
+    /// Add a slice to the collection for the `location` given.
+    #[inline]
+    pub fn push(&mut self, location: ClipLocation, slice: ClipResultPline<P>) {
+        match location {
+            ClipLocation::Inside => self.inside.push(slice),
+            ClipLocation::Outside => self.outside.push(slice),
+            ClipLocation::OnBoundary => self.on_boundary.push(slice),
+        }
+    }
+}
+
+impl<P> Default for PlineClipResult<P>
+where
+    P: PlineCreation,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when calling [PlineSource::clip_opt].
+#[derive(Debug)]
+pub struct PlineClipOptions<'a, T = f64>
+where
+    T: Real,
+{
+    /// Spatial index for `self` (the polyline being clipped).
+    pub pline1_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Spatial index for the region polyline.
+    pub region_aabb_index: Option<&'a StaticAABB2DIndex<T>>,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal, also used to
+    /// determine if a slice lies on the boundary of the region.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PlineClipOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pline1_aabb_index: None,
+            region_aabb_index: None,
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PlineClipOptions<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Enum to control which self intersects to include.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SelfIntersectsInclude {
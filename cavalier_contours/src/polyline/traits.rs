@@ -6,8 +6,8 @@ use crate::{
     core::{
         Control,
         math::{
-            Vector2, angle, angle_from_bulge, bulge_from_angle, delta_angle, dist_squared, is_left,
-            is_left_or_equal, point_on_circle,
+            Affine2, Vector2, angle, angle_from_bulge, bulge_from_angle, delta_angle, dist_squared,
+            is_left, is_left_or_equal, point_on_circle,
         },
         traits::{ControlFlow, FuzzyEq, FuzzyOrd, Real},
     },
@@ -18,17 +18,32 @@ use crate::{
 };
 
 use super::{
-    BooleanOp, BooleanResult, ClosestPointResult, FindIntersectsOptions, PlineBooleanOptions,
-    PlineIntersectVisitor, PlineIntersectsCollection, PlineOffsetOptions, PlineOrientation,
-    PlineSelfIntersectOptions, PlineVertex, arc_seg_bounding_box,
+    BooleanOp, BooleanResult, CavcError, ClosestPointResult, CornerModifyResult, CornerSelector,
+    FillRule, FindIntersectsOptions, MinFeatureWidthResult, OffsetDistance, PlineBooleanOptions,
+    PlineBufferOptions, PlineClipOptions, PlineClipResult, PlineCornerOptions,
+    PlineFeatureWidthOptions, PlineFitArcsOptions, PlineIntersectVisitor,
+    PlineIntersectsCollection, PlineOffsetOptions, PlineOrientation, PlineResolveOptions,
+    PlineSelfIntersectOptions, PlineValidateOptions, PlineValidateResult,
+    PlineVariableOffsetOptions, PlineVertex, SimplifyMethod, TransformError, arc_seg_bounding_box,
     internal::{
         pline_boolean::polyline_boolean,
+        pline_clip::clip,
         pline_contains::polyline_contains,
+        pline_convex_hull::convex_hull,
+        pline_corners::{chamfer, fillet},
+        pline_feature_width::min_feature_width,
+        pline_fit_arcs::fit_arcs,
         pline_intersects::{
             find_intersects, visit_global_self_intersects, visit_intersects,
             visit_local_self_intersects,
         },
-        pline_offset::parallel_offset,
+        pline_offset::{buffer, parallel_offset},
+        pline_resolve::resolve_self_intersects,
+        pline_simplify::simplify,
+        pline_validate::{
+            check_closed_simple, check_offset_input, check_vertex_count, check_vertexes, validate,
+        },
+        pline_variable_offset::variable_offset,
     },
     seg_bounding_box, seg_closest_point, seg_fast_approx_bounding_box, seg_length,
     seg_split_at_point,
@@ -297,6 +312,42 @@ pub trait PlineSource {
         Some(result)
     }
 
+    /// Compute the convex hull of the polyline.
+    ///
+    /// Returns a closed counter clockwise polyline, arc segments (or the portions of arc segments)
+    /// that lie on the hull are kept as arcs rather than approximated by lines. Userdata values are
+    /// copied to the result. See [plines_convex_hull](crate::polyline::plines_convex_hull) to
+    /// compute the convex hull of many polylines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // rectangle with a notch cut into the bottom and a half circle on the right side
+    /// let polyline: Polyline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.0, 0.0, 0.0),
+    ///     (5.0, 2.0, 0.0),
+    ///     (6.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 1.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let hull = polyline.convex_hull();
+    /// assert!(hull.is_closed());
+    /// // notch vertex removed, half circle kept
+    /// assert_eq!(hull.vertex_count(), 4);
+    /// assert!(hull[1].bulge.fuzzy_eq(1.0));
+    /// assert!(hull.area().fuzzy_eq(40.0 + std::f64::consts::PI * 2.0));
+    /// ```
+    fn convex_hull(&self) -> Self::OutputPolyline {
+        let mut result: Self::OutputPolyline = convex_hull(std::iter::once(self));
+        result.set_userdata_values(self.get_userdata_values());
+        result
+    }
+
     /// Returns the total path length of the polyline.
     ///
     /// # Examples
@@ -477,6 +528,40 @@ pub trait PlineSource {
         result
     }
 
+    /// Same as [PlineSource::remove_repeat_pos] but returns an error describing why the polyline
+    /// is not valid instead of `None`.
+    ///
+    /// Returns `Ok(None)` if no vertexes were removed, returns [CavcError::TooFewVertexes] if the
+    /// polyline has less than 2 vertexes, and returns [CavcError::NonFiniteVertex] or
+    /// [CavcError::InvalidBulge] if any vertex is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// let mut polyline = Polyline::new();
+    /// polyline.add(2.0, 2.0, 0.0);
+    /// assert_eq!(
+    ///     polyline.try_remove_repeat_pos(1e-5).unwrap_err(),
+    ///     CavcError::TooFewVertexes { count: 1, required: 2 }
+    /// );
+    /// polyline.add(f64::NAN, 2.0, 0.0);
+    /// assert_eq!(
+    ///     polyline.try_remove_repeat_pos(1e-5).unwrap_err(),
+    ///     CavcError::NonFiniteVertex { index: 1 }
+    /// );
+    /// polyline.set(1, 3.0, 3.0, 0.0);
+    /// assert!(polyline.try_remove_repeat_pos(1e-5).unwrap().is_none());
+    /// ```
+    fn try_remove_repeat_pos(
+        &self,
+        pos_equal_eps: Self::Num,
+    ) -> Result<Option<Self::OutputPolyline>, CavcError> {
+        check_vertex_count(self, 2)?;
+        check_vertexes(self)?;
+        Ok(self.remove_repeat_pos(pos_equal_eps))
+    }
+
     /// Remove all redundant vertexes from the polyline.
     ///
     /// Redundant vertexes can arise with multiple vertexes on top of each other, along a straight
@@ -821,6 +906,36 @@ pub trait PlineSource {
         result
     }
 
+    /// Same as [PlineSource::remove_redundant] but returns an error describing why the polyline
+    /// is not valid instead of `None`.
+    ///
+    /// Returns `Ok(None)` if no vertexes were removed, returns [CavcError::TooFewVertexes] if the
+    /// polyline has less than 2 vertexes, and returns [CavcError::NonFiniteVertex] or
+    /// [CavcError::InvalidBulge] if any vertex is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    /// let result = polyline.try_remove_redundant(1e-5).unwrap().unwrap();
+    /// assert_eq!(result.vertex_count(), 2);
+    /// let polyline = pline_open![(0.0, 0.0, -1.5), (1.0, 0.0, 0.0)];
+    /// assert_eq!(
+    ///     polyline.try_remove_redundant(1e-5).unwrap_err(),
+    ///     CavcError::InvalidBulge { index: 0 }
+    /// );
+    /// ```
+    fn try_remove_redundant(
+        &self,
+        pos_equal_eps: Self::Num,
+    ) -> Result<Option<Self::OutputPolyline>, CavcError> {
+        check_vertex_count(self, 2)?;
+        check_vertexes(self)?;
+        Ok(self.remove_redundant(pos_equal_eps))
+    }
+
     /// Rotates the vertexes in a closed polyline such that the first vertex's position is at
     /// `point`. `start_index` indicates which segment `point` lies on before rotation. This does
     /// not change the shape of the polyline curve. `pos_equal_eps` is epsilon value used for
@@ -1028,6 +1143,83 @@ pub trait PlineSource {
         Some(result)
     }
 
+    /// Find the narrowest width of the region enclosed by a closed polyline (the narrowest neck or
+    /// channel a tool must pass through).
+    ///
+    /// The width is measured between two boundary points whose boundary normals face each other
+    /// across the region (e.g. the two sides of a channel, or the diameter of a circle) where the
+    /// circle having the two points as its diameter lies inside the region. Widths closing in to a
+    /// convex corner are not counted. The polyline may be either orientation.
+    ///
+    /// Returns `None` if the polyline is open, has no area, or has no such width (e.g. a triangle
+    /// only narrows into its corners).
+    ///
+    /// Caution: Polylines with self-intersections may generate unexpected results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // two 10 x 10 squares joined by a 2 wide neck
+    /// let polyline: Polyline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (14.0, 4.0, 0.0),
+    ///     (14.0, 0.0, 0.0),
+    ///     (24.0, 0.0, 0.0),
+    ///     (24.0, 10.0, 0.0),
+    ///     (14.0, 10.0, 0.0),
+    ///     (14.0, 6.0, 0.0),
+    ///     (10.0, 6.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let result = polyline.min_feature_width().unwrap();
+    /// assert!(result.width.fuzzy_eq(2.0));
+    /// assert!(result.point1.y.fuzzy_eq(4.0) || result.point1.y.fuzzy_eq(6.0));
+    /// ```
+    fn min_feature_width(&self) -> Option<MinFeatureWidthResult<Self::Num>> {
+        self.min_feature_width_opt(&Default::default())
+    }
+
+    /// Find the narrowest width of the region enclosed by a closed polyline using the options
+    /// given.
+    ///
+    /// See [PlineSource::min_feature_width] for more information.
+    ///
+    /// `options` is a struct that holds optional parameters. See
+    /// [PlineFeatureWidthOptions](crate::polyline::PlineFeatureWidthOptions) for specific
+    /// parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let rectangle: Polyline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ];
+    /// let options = PlineFeatureWidthOptions {
+    ///     pos_equal_eps: 1e-8,
+    ///     ..Default::default()
+    /// };
+    /// let result = rectangle.min_feature_width_opt(&options).unwrap();
+    /// assert!(result.width.fuzzy_eq(4.0));
+    /// ```
+    fn min_feature_width_opt(
+        &self,
+        options: &PlineFeatureWidthOptions<Self::Num>,
+    ) -> Option<MinFeatureWidthResult<Self::Num>> {
+        min_feature_width(self, options.pos_equal_eps)
+    }
+
     /// Calculate the winding number for a `point` relative to the polyline.
     ///
     /// The winding number calculates the number of turns/windings around a point that the polyline
@@ -1289,6 +1481,106 @@ pub trait PlineSource {
         Some(result)
     }
 
+    /// Returns a new polyline with runs of line segments merged into single line or arc segments
+    /// where the merged segment deviates from the original path by at most `tolerance`.
+    ///
+    /// This is the reverse of [PlineSource::arcs_to_approx_lines] and is useful for reducing the
+    /// segment count of polylines made up of many small line segments (e.g. from mesh slices)
+    /// before performing other operations. Arc segments already in the polyline are kept as is,
+    /// vertexes at the start and end of open polylines are kept, and repeat position vertexes are
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::traits::*;
+    /// let mut circle = Polyline::new_closed();
+    /// circle.add(0.0, 0.0, 1.0);
+    /// circle.add(10.0, 0.0, 1.0);
+    /// let lines = circle.arcs_to_approx_lines(1e-4).unwrap();
+    /// assert!(lines.vertex_count() > 100);
+    /// let fitted = lines.fit_arcs(1e-3);
+    /// assert!(fitted.vertex_count() < 4);
+    /// assert!(fitted.area().fuzzy_eq_eps(circle.area(), 1e-2));
+    /// ```
+    fn fit_arcs(&self, tolerance: Self::Num) -> Self::OutputPolyline {
+        self.fit_arcs_opt(tolerance, &Default::default())
+    }
+
+    /// Returns a new polyline with runs of line segments merged into single line or arc segments
+    /// using the options given.
+    ///
+    /// See [PlineSource::fit_arcs] for more information.
+    ///
+    /// `options` is a struct that holds optional parameters. See
+    /// [PlineFitArcsOptions](crate::polyline::PlineFitArcsOptions) for specific parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// // vertexes closer than pos_equal_eps are removed as repeat positions
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (0.001, 0.0, 0.0), (5.0, 0.0, 0.0)];
+    /// let options = PlineFitArcsOptions {
+    ///     pos_equal_eps: 0.01,
+    ///     ..Default::default()
+    /// };
+    /// let fitted = pline.fit_arcs_opt(1e-3, &options);
+    /// assert_eq!(fitted.vertex_count(), 2);
+    /// ```
+    fn fit_arcs_opt(
+        &self,
+        tolerance: Self::Num,
+        options: &PlineFitArcsOptions<Self::Num>,
+    ) -> Self::OutputPolyline {
+        fit_arcs(self, tolerance, options.pos_equal_eps)
+    }
+
+    /// Returns a new polyline with vertexes removed such that the resulting path deviates from
+    /// the original path by at most `tolerance`, `method` selects the algorithm used to choose
+    /// which vertexes are removed (see [SimplifyMethod]).
+    ///
+    /// Unlike [PlineSource::remove_redundant] (which only removes collinear/concentric vertexes)
+    /// this removes any vertexes whose removal keeps the path within tolerance. Deviation of arc
+    /// segments is measured along the arc (not the arc chord), arc segments are kept as is if
+    /// neither of their vertexes are removed. The result is closed if the polyline is closed
+    /// (keeping at least 3 vertexes), the first and last vertex of open polylines are kept, and
+    /// vertexes are restored as needed so that the result does not have any new self intersects.
+    /// Self intersects already in the original polyline do not prevent simplification, simplified
+    /// segments may cross where their original paths crossed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// let polyline = pline_open![
+    ///     (0.0, 0.0, 0.0),
+    ///     (1.0, 0.05, 0.0),
+    ///     (2.0, -0.05, 0.0),
+    ///     (3.0, 0.0, 0.0),
+    ///     (3.0, 5.0, 0.0),
+    /// ];
+    /// let result = polyline.simplify(0.1, SimplifyMethod::DouglasPeucker);
+    /// assert_eq!(result.vertex_count(), 3);
+    /// let result = polyline.simplify(0.01, SimplifyMethod::Visvalingam);
+    /// assert_eq!(result.vertex_count(), 5);
+    /// ```
+    fn simplify(&self, tolerance: Self::Num, method: SimplifyMethod) -> Self::OutputPolyline {
+        simplify(
+            self,
+            tolerance,
+            method,
+            <Self::Num as NumCast>::from(1e-5).unwrap(),
+        )
+    }
+
     /// Visit self intersects of the polyline using default options.
     ///
     /// # Panics
@@ -1470,6 +1762,134 @@ pub trait PlineSource {
         found_intersects
     }
 
+    /// Validate the polyline and return a report of all the issues found, each issue is tied to
+    /// the vertex or segment start indexes involved.
+    ///
+    /// Checks for too few vertexes (less than 2), non-finite vertex positions, invalid bulge values
+    /// (NaN, infinite, or magnitude greater than 1), zero length segments, repeat positions
+    /// between non-adjacent vertexes, zero area (closed polylines), self intersects and overlaps,
+    /// and non-adjacent segments within [PlineValidateOptions::near_touch_distance] of each
+    /// other. If there are too few vertexes or any vertex is not valid then only the vertex count
+    /// and vertex issues are reported. See [PlineValidateIssue] for all the
+    /// issues reported.
+    ///
+    /// [PlineValidateIssue]: crate::polyline::PlineValidateIssue
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    /// ];
+    /// let result = pline.validate(&Default::default());
+    /// assert!(!result.is_valid());
+    /// assert_eq!(
+    ///     result.issues,
+    ///     vec![PlineValidateIssue::ZeroLengthSegment { seg_start_index: 1 }]
+    /// );
+    ///
+    /// let result = pline.remove_repeat_pos(1e-5).unwrap().validate(&Default::default());
+    /// assert!(result.is_valid());
+    /// ```
+    fn validate(
+        &self,
+        options: &PlineValidateOptions<Self::Num>,
+    ) -> PlineValidateResult<Self::Num> {
+        validate(self, options)
+    }
+
+    /// Resolve the self intersects of a closed polyline using default options, returning simple
+    /// closed polylines representing the region filled according to `fill_rule`.
+    ///
+    /// See [PlineSource::resolve_self_intersects_opt] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// // figure eight made of two triangles with opposite orientation
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    /// ];
+    /// let result = pline.resolve_self_intersects(FillRule::NonZero);
+    /// assert_eq!(result.len(), 2);
+    /// for p in result.iter() {
+    ///     assert_eq!(p.orientation(), PlineOrientation::CounterClockwise);
+    ///     assert!(p.area().fuzzy_eq(1.0));
+    /// }
+    /// ```
+    fn resolve_self_intersects(&self, fill_rule: FillRule) -> Vec<Self::OutputPolyline> {
+        self.resolve_self_intersects_opt(fill_rule, &Default::default())
+    }
+
+    /// Resolve the self intersects of a closed polyline using the options given, returning simple
+    /// closed polylines representing the region filled according to `fill_rule`.
+    ///
+    /// The polyline is split at all of its self intersects (including the ends of overlapping
+    /// segments) and the slices which separate a filled region from an unfilled region are
+    /// stitched back together. Outer boundaries are returned counter clockwise and holes are
+    /// returned clockwise, polylines which touch at a point are returned separately. Returns an
+    /// empty `Vec` if the polyline is open, has less than 2 vertexes, or has no filled region.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// // five pointed star drawn as a single closed polyline, the center pentagon has winding
+    /// // number 2 and the points have winding number 1
+    /// let mut star = Polyline::new_closed();
+    /// for i in 0..5 {
+    ///     let angle = std::f64::consts::FRAC_PI_2 + i as f64 * 4.0 * std::f64::consts::PI / 5.0;
+    ///     star.add(10.0 * angle.cos(), 10.0 * angle.sin(), 0.0);
+    /// }
+    /// let aabb_index = star.create_approx_aabb_index();
+    /// let options = PlineResolveOptions {
+    ///     aabb_index: Some(&aabb_index),
+    ///     ..Default::default()
+    /// };
+    /// // outline of the star
+    /// let non_zero = star.resolve_self_intersects_opt(FillRule::NonZero, &options);
+    /// assert_eq!(non_zero.len(), 1);
+    /// assert_eq!(non_zero[0].vertex_count(), 10);
+    /// // outline of the star with the center pentagon as a clockwise hole
+    /// let even_odd = star.resolve_self_intersects_opt(FillRule::EvenOdd, &options);
+    /// assert_eq!(even_odd.len(), 2);
+    /// let hole = even_odd.iter().find(|p| p.vertex_count() == 5).unwrap();
+    /// assert_eq!(hole.orientation(), PlineOrientation::Clockwise);
+    /// ```
+    fn resolve_self_intersects_opt(
+        &self,
+        fill_rule: FillRule,
+        options: &PlineResolveOptions<Self::Num>,
+    ) -> Vec<Self::OutputPolyline> {
+        resolve_self_intersects(self, fill_rule, options)
+    }
+
     /// Find all intersects between two polylines using default options.
     ///
     /// # Panics
@@ -1567,6 +1987,389 @@ pub trait PlineSource {
         parallel_offset(self, offset, options)
     }
 
+    /// Same as [PlineSource::parallel_offset] but returns an error if the polyline is not valid to
+    /// offset, see [PlineSource::try_parallel_offset_opt] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// let pline = pline_closed![(0.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+    /// let offset_plines = pline.try_parallel_offset(0.2).unwrap();
+    /// assert_eq!(offset_plines.len(), 1);
+    ///
+    /// // bulge greater than 1 (arc sweeps more than a half circle)
+    /// let invalid = pline_closed![(0.0, 0.0, 2.0), (1.0, 0.0, 1.0)];
+    /// assert_eq!(
+    ///     invalid.try_parallel_offset(0.2).unwrap_err(),
+    ///     CavcError::InvalidBulge { index: 0 }
+    /// );
+    /// ```
+    fn try_parallel_offset(
+        &self,
+        offset: Self::Num,
+    ) -> Result<Vec<Self::OutputPolyline>, CavcError> {
+        self.try_parallel_offset_opt(offset, &Default::default())
+    }
+
+    /// Same as [PlineSource::parallel_offset_opt] but the polyline is checked first and an error
+    /// is returned if it is not valid to offset.
+    ///
+    /// Repeat position vertexes are removed before offsetting (if any are removed then
+    /// [PlineOffsetOptions::aabb_index] is not used). Errors returned:
+    ///
+    /// * [CavcError::NonFiniteVertex] or [CavcError::InvalidBulge] if any vertex is not valid.
+    /// * [CavcError::TooFewVertexes] if less than 2 vertexes remain after removing repeat position
+    ///   vertexes.
+    /// * [CavcError::SelfIntersecting] if the polyline is closed, self intersects, and
+    ///   [PlineOffsetOptions::handle_self_intersects] is false.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// // figure eight (self intersecting)
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (2.0, 2.0, 0.0),
+    ///     (2.0, 0.0, 0.0),
+    ///     (0.0, 2.0, 0.0),
+    /// ];
+    /// assert_eq!(
+    ///     pline.try_parallel_offset(0.1).unwrap_err(),
+    ///     CavcError::SelfIntersecting
+    /// );
+    /// let options = PlineOffsetOptions {
+    ///     handle_self_intersects: true,
+    ///     ..Default::default()
+    /// };
+    /// assert!(pline.try_parallel_offset_opt(0.1, &options).is_ok());
+    /// ```
+    fn try_parallel_offset_opt(
+        &self,
+        offset: Self::Num,
+        options: &PlineOffsetOptions<Self::Num>,
+    ) -> Result<Vec<Self::OutputPolyline>, CavcError> {
+        check_vertexes(self)?;
+        match self.remove_repeat_pos(options.pos_equal_eps) {
+            None => {
+                check_offset_input(self, options)?;
+                Ok(parallel_offset(self, offset, options))
+            }
+            Some(cleaned) => {
+                // spatial index given is for the polyline before removing vertexes
+                let options = PlineOffsetOptions {
+                    aabb_index: None,
+                    ..options.clone()
+                };
+                check_offset_input(&cleaned, &options)?;
+                Ok(parallel_offset(&cleaned, offset, &options))
+            }
+        }
+    }
+
+    /// Compute the buffer (stroke) of the polyline using default options.
+    ///
+    /// Returns closed polylines that enclose the area within `distance` of the polyline, see
+    /// [PlineSource::buffer_opt] for more information.
+    ///
+    /// Algorithm will use [PlineBufferOptions::default] for algorithm options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// # use cavalier_contours::core::traits::*;
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// let buffer_plines = pline.buffer(1.0);
+    /// assert_eq!(buffer_plines.len(), 1);
+    /// let buffer_pline = &buffer_plines[0];
+    /// assert!(buffer_pline.is_closed());
+    /// // rectangle with half circle caps
+    /// assert!(buffer_pline.area().fuzzy_eq(20.0 + std::f64::consts::PI));
+    /// ```
+    fn buffer(&self, distance: Self::Num) -> Vec<Self::OutputPolyline> {
+        self.buffer_opt(distance, &Default::default())
+    }
+
+    /// Compute the buffer (stroke) of the polyline with options given.
+    ///
+    /// Returns closed polylines that enclose the area within `distance` of the polyline (sign of
+    /// `distance` is ignored). Outer polylines are oriented counter clockwise and inner polylines
+    /// (holes) are oriented clockwise.
+    ///
+    /// If the polyline is open then the ends are capped according to
+    /// [PlineBufferOptions::end_cap], if the polyline is closed then the result is the parallel
+    /// offsets on both sides of the polyline.
+    ///
+    /// `options` is a struct that holds optional parameters. See
+    /// [PlineBufferOptions](crate::polyline::PlineBufferOptions) for specific parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// # use cavalier_contours::core::traits::*;
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// let options = PlineBufferOptions {
+    ///     end_cap: EndCapStyle::Square,
+    ///     ..Default::default()
+    /// };
+    /// let buffer_plines = pline.buffer_opt(1.0, &options);
+    /// assert_eq!(buffer_plines.len(), 1);
+    /// let extents = buffer_plines[0].extents().unwrap();
+    /// assert!(extents.min_x.fuzzy_eq(-1.0));
+    /// assert!(extents.max_x.fuzzy_eq(11.0));
+    /// assert!(buffer_plines[0].area().fuzzy_eq(24.0));
+    /// ```
+    fn buffer_opt(
+        &self,
+        distance: Self::Num,
+        options: &PlineBufferOptions<Self::Num>,
+    ) -> Vec<Self::OutputPolyline> {
+        buffer(self, distance, options)
+    }
+
+    /// Compute the variable distance parallel offset polylines of the polyline using default
+    /// options.
+    ///
+    /// See [PlineSource::variable_offset_opt] for more information.
+    ///
+    /// Algorithm will use [PlineVariableOffsetOptions::default] for algorithm options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [OffsetDistance::PerVertex] is given and the number of distances does not equal
+    /// the vertex count, or if `Self::Num` type fails to cast to/from a `u16` (required for spatial
+    /// index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// # use cavalier_contours::core::traits::*;
+    /// // tapered offset of a line, distance goes from 1 to 2
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    /// let offset_plines = pline.variable_offset(OffsetDistance::PerVertex(&[1.0, 2.0]));
+    /// assert_eq!(offset_plines.len(), 1);
+    /// let offset_pline = &offset_plines[0];
+    /// assert!(offset_pline[0].fuzzy_eq(PlineVertex::new(0.0, 1.0, 0.0)));
+    /// assert!(offset_pline.last().unwrap().fuzzy_eq(PlineVertex::new(10.0, 2.0, 0.0)));
+    /// ```
+    fn variable_offset(&self, distance: OffsetDistance<Self::Num>) -> Vec<Self::OutputPolyline> {
+        self.variable_offset_opt(distance, &Default::default())
+    }
+
+    /// Compute the variable distance parallel offset polylines of the polyline with options given.
+    ///
+    /// The offset distance at each point along the polyline is given by `distance`, either per
+    /// vertex (linearly interpolated along each segment) or as a function of path length.
+    /// Positive distances offset to the left of the polyline segment tangent vectors and negative
+    /// distances offset to the right, all distances should have the same sign. For closed
+    /// polylines a [OffsetDistance::PathLength] function should return the same distance at zero
+    /// and at the total path length.
+    ///
+    /// The offset curve is sampled and approximated by line and arc segments within
+    /// [PlineVariableOffsetOptions::tolerance]. Outward corners are joined with arcs and parts of
+    /// the offset that are closer to the polyline than the offset distance (at the closest point)
+    /// are trimmed away, same as [PlineSource::parallel_offset_opt].
+    ///
+    /// `options` is a struct that holds optional parameters. See
+    /// [PlineVariableOffsetOptions](crate::polyline::PlineVariableOffsetOptions) for specific
+    /// parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [OffsetDistance::PerVertex] is given and the number of distances does not equal
+    /// the vertex count, or if `Self::Num` type fails to cast to/from a `u16` (required for spatial
+    /// index).
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::{math::Vector2, traits::*};
+    /// let pline = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// // distance varies between 0.5 (at the corners) and 1.5 (at the middle of each side), total
+    /// // path length is 40
+    /// let distance_fn = |path_length: f64| 1.0 - 0.5 * (path_length * std::f64::consts::PI / 5.0).cos();
+    /// let options = PlineVariableOffsetOptions {
+    ///     tolerance: 1e-4,
+    ///     ..Default::default()
+    /// };
+    /// let offset_plines =
+    ///     pline.variable_offset_opt(OffsetDistance::PathLength(&distance_fn), &options);
+    /// assert_eq!(offset_plines.len(), 1);
+    /// // offset to the left (inward for counter clockwise polyline)
+    /// let offset_pline = &offset_plines[0];
+    /// let closest = offset_pline.closest_point(Vector2::new(5.0, 0.0), 1e-5).unwrap();
+    /// assert!(closest.seg_point.fuzzy_eq_eps(Vector2::new(5.0, 1.5), 1e-3));
+    /// ```
+    fn variable_offset_opt(
+        &self,
+        distance: OffsetDistance<Self::Num>,
+        options: &PlineVariableOffsetOptions<Self::Num>,
+    ) -> Vec<Self::OutputPolyline> {
+        variable_offset(self, distance, options)
+    }
+
+    /// Replace selected corners of the polyline with tangent arcs of the given `radius`.
+    ///
+    /// `selector` is called with the index of each corner vertex (all vertexes for closed
+    /// polylines, all but the first and last vertex for open polylines) and only corners for which
+    /// it returns true are filleted. Use [AllCorners](crate::polyline::AllCorners) to fillet every
+    /// corner. Corners may be line-line, line-arc, or arc-arc, vertexes where the adjacent
+    /// segments are already tangent are left unchanged.
+    ///
+    /// Corners that cannot be filleted (e.g. the adjacent segments are too short to fit the
+    /// fillet or the segments fold back on each other) are left unchanged and their vertex index
+    /// is returned in [CornerModifyResult::skipped_vertexes]. If `radius` is not positive then the
+    /// polyline is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// let rectangle = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let result = rectangle.fillet(1.0, AllCorners);
+    /// assert!(result.skipped_vertexes.is_empty());
+    /// assert_eq!(result.pline.vertex_count(), 8);
+    /// let expected_area = 100.0 - (4.0 - std::f64::consts::PI);
+    /// assert!(result.pline.area().fuzzy_eq(expected_area));
+    ///
+    /// // only fillet the first corner
+    /// let result = rectangle.fillet(1.0, |i| i == 0);
+    /// assert_eq!(result.pline.vertex_count(), 5);
+    /// ```
+    fn fillet<S>(&self, radius: Self::Num, selector: S) -> CornerModifyResult<Self::OutputPolyline>
+    where
+        S: CornerSelector,
+    {
+        self.fillet_opt(radius, selector, &Default::default())
+    }
+
+    /// Replace selected corners of the polyline with tangent arcs of the given `radius` using the
+    /// options given.
+    ///
+    /// See [PlineSource::fillet] for more information.
+    ///
+    /// `options` is a struct that holds optional parameters. See
+    /// [PlineCornerOptions](crate::polyline::PlineCornerOptions) for specific parameters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// # use cavalier_contours::core::traits::*;
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    /// let options = PlineCornerOptions {
+    ///     pos_equal_eps: 1e-8,
+    ///     ..Default::default()
+    /// };
+    /// let result = pline.fillet_opt(1.0, AllCorners, &options);
+    /// assert_eq!(result.pline.vertex_count(), 4);
+    /// // quarter circle arc
+    /// assert!(result.pline[1].bulge.fuzzy_eq((std::f64::consts::PI / 8.0).tan()));
+    /// ```
+    fn fillet_opt<S>(
+        &self,
+        radius: Self::Num,
+        mut selector: S,
+        options: &PlineCornerOptions<Self::Num>,
+    ) -> CornerModifyResult<Self::OutputPolyline>
+    where
+        S: CornerSelector,
+    {
+        fillet(self, radius, &mut selector, options.pos_equal_eps)
+    }
+
+    /// Replace selected corners of the polyline with straight line cuts (chamfers) which start and
+    /// end at the path `distance` from the corner along the adjacent segments.
+    ///
+    /// Corners are selected the same as in [PlineSource::fillet]. Corners where the adjacent
+    /// segments are shorter than `distance` (or shorter than the space left by a neighboring
+    /// chamfer) are left unchanged and their vertex index is returned in
+    /// [CornerModifyResult::skipped_vertexes]. If `distance` is not positive then the polyline is
+    /// returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// let rectangle = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 10.0, 0.0),
+    ///     (0.0, 10.0, 0.0),
+    /// ];
+    /// let result = rectangle.chamfer(1.0, AllCorners);
+    /// assert!(result.skipped_vertexes.is_empty());
+    /// assert_eq!(result.pline.vertex_count(), 8);
+    /// assert!(result.pline.area().fuzzy_eq(98.0));
+    /// ```
+    fn chamfer<S>(
+        &self,
+        distance: Self::Num,
+        selector: S,
+    ) -> CornerModifyResult<Self::OutputPolyline>
+    where
+        S: CornerSelector,
+    {
+        self.chamfer_opt(distance, selector, &Default::default())
+    }
+
+    /// Replace selected corners of the polyline with straight line cuts (chamfers) using the
+    /// options given.
+    ///
+    /// See [PlineSource::chamfer] for more information.
+    ///
+    /// `options` is a struct that holds optional parameters. See
+    /// [PlineCornerOptions](crate::polyline::PlineCornerOptions) for specific parameters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_open;
+    /// # use cavalier_contours::core::traits::*;
+    /// let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    /// let options = PlineCornerOptions::new();
+    /// let result = pline.chamfer_opt(2.0, AllCorners, &options);
+    /// assert_eq!(result.pline.vertex_count(), 4);
+    /// assert!(result.pline[1].fuzzy_eq(PlineVertex::new(8.0, 0.0, 0.0)));
+    /// assert!(result.pline[2].fuzzy_eq(PlineVertex::new(10.0, 2.0, 0.0)));
+    /// ```
+    fn chamfer_opt<S>(
+        &self,
+        distance: Self::Num,
+        mut selector: S,
+        options: &PlineCornerOptions<Self::Num>,
+    ) -> CornerModifyResult<Self::OutputPolyline>
+    where
+        S: CornerSelector,
+    {
+        chamfer(self, distance, &mut selector, options.pos_equal_eps)
+    }
+
     /// Perform a boolean `operation` between this polyline and another using default options.
     ///
     /// See [PlineSource::boolean_opt] for more information.
@@ -1654,6 +2457,136 @@ pub trait PlineSource {
         polyline_boolean(self, other, operation, options)
     }
 
+    /// Same as [PlineSource::boolean] but returns an error if either polyline is not valid for a
+    /// boolean operation, see [PlineSource::try_boolean_opt] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::pline_open;
+    /// let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let line = pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    /// assert_eq!(
+    ///     circle.try_boolean(&line, BooleanOp::Or).unwrap_err(),
+    ///     CavcError::NotClosed
+    /// );
+    /// let result = circle.try_boolean(&circle, BooleanOp::Or).unwrap();
+    /// assert!(matches!(result.result_info, BooleanResultInfo::Overlapping));
+    /// ```
+    fn try_boolean<P>(
+        &self,
+        other: &P,
+        operation: BooleanOp,
+    ) -> Result<BooleanResult<Self::OutputPolyline>, CavcError>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.try_boolean_opt(other, operation, &Default::default())
+    }
+
+    /// Same as [PlineSource::boolean_opt] but both polylines are checked first and an error is
+    /// returned if either is not valid for a boolean operation (`self` is checked first).
+    ///
+    /// Errors returned:
+    ///
+    /// * [CavcError::NotClosed] if either polyline is open.
+    /// * [CavcError::TooFewVertexes] if less than 2 vertexes remain in either polyline after
+    ///   removing repeat position vertexes.
+    /// * [CavcError::NonFiniteVertex] or [CavcError::InvalidBulge] if any vertex is not valid.
+    /// * [CavcError::SelfIntersecting] if either polyline self intersects.
+    fn try_boolean_opt<P>(
+        &self,
+        other: &P,
+        operation: BooleanOp,
+        options: &PlineBooleanOptions<Self::Num>,
+    ) -> Result<BooleanResult<Self::OutputPolyline>, CavcError>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        check_closed_simple(self, options.pline1_aabb_index, options.pos_equal_eps)?;
+        check_closed_simple(other, None, options.pos_equal_eps)?;
+        Ok(polyline_boolean(self, other, operation, options))
+    }
+
+    /// Clip this polyline by the area of the closed polyline `region` using default options.
+    ///
+    /// See [PlineSource::clip_opt] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// # use cavalier_contours::core::traits::*;
+    /// let region = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// // path crossing through the region
+    /// let path = pline_open![(-2.0, 2.0, 0.0), (6.0, 2.0, 0.0)];
+    /// let result = path.clip(&region);
+    /// assert_eq!(result.inside.len(), 1);
+    /// assert_eq!(result.outside.len(), 2);
+    /// assert!(result.on_boundary.is_empty());
+    /// assert!(result.inside[0].pline.path_length().fuzzy_eq(4.0));
+    /// ```
+    fn clip<P>(&self, region: &P) -> PlineClipResult<Self::OutputPolyline>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        self.clip_opt(region, &Default::default())
+    }
+
+    /// Clip this polyline by the area of the closed polyline `region` with options provided.
+    ///
+    /// This polyline is sliced at all of its intersects with `region` and each slice is returned
+    /// as inside of the region, outside of the region, or on the boundary of the region (overlapping
+    /// `region` segments). Each slice holds the
+    /// [PlineViewData](crate::polyline::PlineViewData) for forming the slice from this polyline so
+    /// results can be tied back to the source vertex indexes. This polyline is typically open
+    /// (e.g. trimming a tool path) but closed polylines are also sliced. If `region` is open then
+    /// it has no area and all slices are either outside or on the boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::Num` type fails to cast to/from a `u16` (required for spatial index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// # use cavalier_contours::core::traits::*;
+    /// let region = pline_closed![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// // path running along the bottom edge of the region then up into the region
+    /// let path = pline_open![(-2.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 2.0, 0.0)];
+    /// let region_index = region.create_approx_aabb_index();
+    /// let options = PlineClipOptions {
+    ///     region_aabb_index: Some(&region_index),
+    ///     ..Default::default()
+    /// };
+    /// let result = path.clip_opt(&region, &options);
+    /// assert_eq!(result.outside.len(), 1);
+    /// assert_eq!(result.on_boundary.len(), 1);
+    /// assert_eq!(result.inside.len(), 1);
+    /// // view data ties the slice back to the path vertexes
+    /// let inside = &result.inside[0];
+    /// assert_eq!(inside.view_data.start_index, 1);
+    /// assert!(inside.view_data.view(&path).path_length().fuzzy_eq(2.0));
+    /// ```
+    fn clip_opt<P>(
+        &self,
+        region: &P,
+        options: &PlineClipOptions<Self::Num>,
+    ) -> PlineClipResult<Self::OutputPolyline>
+    where
+        P: PlineSource<Num = Self::Num> + ?Sized,
+    {
+        clip(self, region, options)
+    }
+
     /// Determine if this polyline fully contains another using default options.
     ///
     /// Caution: Polylines with self-intersections may generate unexpected results.
@@ -2044,6 +2977,108 @@ pub trait PlineSourceMut: PlineSource {
         }
     }
 
+    /// Rotate the polyline (mutably) counter clockwise about `origin` by `angle` in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// let mut polyline = Polyline::new();
+    /// polyline.add(2.0, 0.0, 0.5);
+    /// polyline.add(4.0, 0.0, 1.0);
+    /// polyline.rotate_mut(std::f64::consts::FRAC_PI_2, Vector2::new(1.0, 0.0));
+    /// let mut expected = Polyline::new();
+    /// expected.add(1.0, 1.0, 0.5);
+    /// expected.add(1.0, 3.0, 1.0);
+    /// assert!(polyline.fuzzy_eq(&expected));
+    /// ```
+    fn rotate_mut(&mut self, angle: Self::Num, origin: Vector2<Self::Num>) {
+        for i in 0..self.vertex_count() {
+            let v = self.at(i);
+            let p = v.pos().rotate_about(origin, angle);
+            self.set(i, p.x, p.y, v.bulge);
+        }
+    }
+
+    /// Mirror the polyline (mutably) across the line passing through `point` in the `direction`
+    /// given.
+    ///
+    /// Bulge values are negated since mirroring reverses the direction of arcs, for closed
+    /// polylines this means the orientation is inverted (clockwise becomes counter clockwise and
+    /// vice versa).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// let mut polyline = Polyline::new();
+    /// polyline.add(1.0, 1.0, 0.5);
+    /// polyline.add(3.0, 2.0, 0.0);
+    /// // mirror across the y axis
+    /// polyline.mirror_mut(Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0));
+    /// let mut expected = Polyline::new();
+    /// expected.add(-1.0, 1.0, -0.5);
+    /// expected.add(-3.0, 2.0, 0.0);
+    /// assert!(polyline.fuzzy_eq(&expected));
+    /// ```
+    fn mirror_mut(&mut self, point: Vector2<Self::Num>, direction: Vector2<Self::Num>) {
+        let transform = Affine2::mirror(point, direction);
+        for i in 0..self.vertex_count() {
+            let v = self.at(i);
+            let p = transform.transform_point(v.pos());
+            self.set(i, p.x, p.y, -v.bulge);
+        }
+    }
+
+    /// Apply an affine `transform` to the polyline (mutably).
+    ///
+    /// Arcs remain circular only under similarity transforms (uniform scale, rotation,
+    /// reflection, and translation), if the transform is not a similarity (see
+    /// [Affine2::is_similarity]) and the polyline has arc segments then
+    /// [TransformError::NonUniformScaleWithArcs] is returned and the polyline is left unchanged.
+    /// Arcs can be converted to line segments first using [PlineSource::arcs_to_approx_lines].
+    /// Bulge values are negated if the transform reflects (negative determinant).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Affine2;
+    /// # use cavalier_contours::pline_closed;
+    /// let mut polyline: Polyline = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    /// let transform = Affine2::uniform_scaling(2.0).then(&Affine2::translation(1.0, 1.0));
+    /// assert!(polyline.transform_mut(&transform).is_ok());
+    /// assert!(polyline.fuzzy_eq(&pline_closed![(1.0, 1.0, 1.0), (5.0, 1.0, 1.0)]));
+    ///
+    /// // non-uniform scaling fails with arcs
+    /// let stretch = Affine2::scaling(1.0, 2.0);
+    /// assert_eq!(
+    ///     polyline.transform_mut(&stretch),
+    ///     Err(TransformError::NonUniformScaleWithArcs)
+    /// );
+    ///
+    /// // but succeeds after converting arcs to lines
+    /// let mut lines = polyline.arcs_to_approx_lines(1e-3).unwrap();
+    /// assert!(lines.transform_mut(&stretch).is_ok());
+    /// ```
+    fn transform_mut(&mut self, transform: &Affine2<Self::Num>) -> Result<(), TransformError> {
+        if !transform.is_similarity() && self.iter_vertexes().any(|v| !v.bulge_is_zero()) {
+            return Err(TransformError::NonUniformScaleWithArcs);
+        }
+
+        let reflects = transform.determinant() < Self::Num::zero();
+        for i in 0..self.vertex_count() {
+            let v = self.at(i);
+            let p = transform.transform_point(v.pos());
+            let bulge = if reflects { -v.bulge } else { v.bulge };
+            self.set(i, p.x, p.y, bulge);
+        }
+
+        Ok(())
+    }
+
     /// Invert/reverse the direction of the polyline in place (mutably).
     ///
     /// This method works by simply reversing the order of the vertexes, shifting by 1 position all
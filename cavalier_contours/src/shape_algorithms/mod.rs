@@ -1,4 +1,12 @@
-use std::collections::{BTreeMap, BTreeSet};
+mod shape_boolean;
+pub use shape_boolean::*;
+mod shape_nesting;
+pub use shape_nesting::*;
+mod shape_queries;
+pub use shape_queries::*;
+mod shape_transform;
+
+use std::collections::BTreeMap;
 
 use static_aabb2d_index::{StaticAABB2DIndex, StaticAABB2DIndexBuilder};
 
@@ -8,8 +16,8 @@ use crate::{
         traits::Real,
     },
     polyline::{
-        FindIntersectsOptions, PlineBasicIntersect, PlineOffsetOptions, PlineOrientation,
-        PlineSource, PlineSourceMut, PlineViewData, Polyline,
+        FindIntersectsOptions, JoinStyle, PlineBasicIntersect, PlineOffsetOptions,
+        PlineOrientation, PlineSource, PlineSourceMut, PlineViewData, Polyline,
         internal::pline_offset::point_valid_for_offset, seg_midpoint,
     },
 };
@@ -73,6 +81,8 @@ where
             pos_equal_eps: options.pos_equal_eps,
             slice_join_eps: options.slice_join_eps,
             offset_dist_eps: options.offset_dist_eps,
+            join_style: options.join_style,
+            context: None,
         };
 
         self.polyline.parallel_offset_opt(offset, &opts)
@@ -94,6 +104,17 @@ pub struct Shape<T: Real> {
     pub plines_index: StaticAABB2DIndex<T>,
 }
 
+/// Key used to select loops of a shape for a per loop offset distance, see
+/// [ShapeOffsetOptions::loop_offsets].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeLoopKey {
+    /// Index position of the loop in the [Shape::plines_index] (all counter clockwise polylines
+    /// followed by all clockwise polylines).
+    Index(usize),
+    /// All loops which have the userdata value.
+    Userdata(u64),
+}
+
 /// Struct to hold options parameters when performing shape offset.
 #[derive(Debug, Clone)]
 pub struct ShapeOffsetOptions<T> {
@@ -105,6 +126,15 @@ pub struct ShapeOffsetOptions<T> {
     /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
     /// polyline slices together.
     pub slice_join_eps: T,
+    /// Style used to join segments at outward corners.
+    pub join_style: JoinStyle<T>,
+    /// Offset distances which override the offset given to [Shape::parallel_offset] for the
+    /// loops matching the key (e.g. a different allowance for the outer boundary than for the
+    /// holes). If more than one entry matches a loop then the first entry is used.
+    ///
+    /// Offset loops are trimmed where they come closer to another input loop than that loop's
+    /// offset distance.
+    pub loop_offsets: Vec<(ShapeLoopKey, T)>,
 }
 
 impl<T> ShapeOffsetOptions<T>
@@ -117,6 +147,8 @@ where
             pos_equal_eps: T::from(1e-5).unwrap(),
             offset_dist_eps: T::from(1e-4).unwrap(),
             slice_join_eps: T::from(1e-4).unwrap(),
+            join_style: JoinStyle::Round,
+            loop_offsets: Vec::new(),
         }
     }
 }
@@ -187,6 +219,14 @@ where
         }
     }
 
+    /// Parallel offset the shape by `offset` (positive offsets shrink counter clockwise polylines
+    /// and grow clockwise/hole polylines).
+    ///
+    /// The offset for individual loops may be overridden by [ShapeOffsetOptions::loop_offsets].
+    ///
+    /// If the `rayon` feature is enabled then the offset of each loop, the intersects between
+    /// offset loops, and the slice validation are computed in parallel, the result is the same as
+    /// when computed serially.
     pub fn parallel_offset(&self, offset: T, options: ShapeOffsetOptions<T>) -> Self {
         let (ccw_offset_loops, cw_offset_loops, offset_loops_index) =
             self.create_offset_loops_with_index(offset, &options);
@@ -242,54 +282,46 @@ where
         offset: T,
         options: &ShapeOffsetOptions<T>,
     ) -> (Vec<OffsetLoop<T>>, Vec<OffsetLoop<T>>, StaticAABB2DIndex<T>) {
-        let mut ccw_offset_loops = Vec::new();
-        let mut cw_offset_loops = Vec::new();
-        let mut parent_idx = 0;
-
-        for pline in self.ccw_plines.iter() {
-            for offset_pline in pline.parallel_offset_for_shape(offset, options) {
-                let area = offset_pline.area();
-                // check if orientation inverted (due to collapse of very narrow or small input)
-                // skip if inversion happened (ccw became cw while offsetting inward)
-                if offset > T::zero() && area < T::zero() {
-                    continue;
-                }
-
-                let offset_loop = OffsetLoop {
-                    parent_loop_idx: parent_idx,
-                    indexed_pline: IndexedPolyline::new(offset_pline),
-                };
-
-                if area < T::zero() {
-                    cw_offset_loops.push(offset_loop);
-                } else {
-                    ccw_offset_loops.push(offset_loop);
-                }
-            }
-            parent_idx += 1;
-        }
+        let ccw_count = self.ccw_plines.len();
+        let input_offsets = map_indexes(
+            ccw_count + self.cw_plines.len(),
+            || (),
+            |_, parent_idx| {
+                let is_ccw = parent_idx < ccw_count;
+                let offset = self.loop_offset(parent_idx, offset, options);
+                self.get_pline(parent_idx)
+                    .parallel_offset_for_shape(offset, options)
+                    .into_iter()
+                    .filter_map(|offset_pline| {
+                        let area = offset_pline.area();
+                        // check if orientation inverted (due to collapse of very narrow or small
+                        // input), skip if inversion happened (ccw became cw while offsetting
+                        // inward or cw became ccw while offsetting inward)
+                        if (is_ccw && offset > T::zero() && area < T::zero())
+                            || (!is_ccw && offset < T::zero() && area > T::zero())
+                        {
+                            return None;
+                        }
 
-        for pline in self.cw_plines.iter() {
-            for offset_pline in pline.parallel_offset_for_shape(offset, options) {
-                let area = offset_pline.area();
-                // check if orientation inverted (due to collapse of very narrow or small input)
-                // skip if inversion happened (cw became ccw while offsetting inward)
-                if offset < T::zero() && area > T::zero() {
-                    continue;
-                }
+                        let offset_loop = OffsetLoop {
+                            parent_loop_idx: parent_idx,
+                            indexed_pline: IndexedPolyline::new(offset_pline),
+                        };
 
-                let offset_loop = OffsetLoop {
-                    parent_loop_idx: parent_idx,
-                    indexed_pline: IndexedPolyline::new(offset_pline),
-                };
+                        Some((area < T::zero(), offset_loop))
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
 
-                if area < T::zero() {
-                    cw_offset_loops.push(offset_loop);
-                } else {
-                    ccw_offset_loops.push(offset_loop);
-                }
+        let mut ccw_offset_loops = Vec::new();
+        let mut cw_offset_loops = Vec::new();
+        for (is_cw, offset_loop) in input_offsets.into_iter().flatten() {
+            if is_cw {
+                cw_offset_loops.push(offset_loop);
+            } else {
+                ccw_offset_loops.push(offset_loop);
             }
-            parent_idx += 1;
         }
 
         let offset_loops_index = {
@@ -324,7 +356,8 @@ where
     /// the offset polylines generated in Step 1. It performs pairwise intersection tests only
     /// on polylines whose bounding boxes overlap, avoiding expensive computations on non-intersecting
     /// pairs. Both basic intersections and overlapping segments are detected and converted into
-    /// slice points for further processing.
+    /// slice points for further processing. Loops offset by different distances (see
+    /// [ShapeOffsetOptions::loop_offsets]) are intersected the same way.
     ///
     /// # Arguments
     ///
@@ -350,87 +383,77 @@ where
         pos_equal_eps: T,
     ) -> Vec<SlicePointSet<T>> {
         let offset_loop_count = ccw_offset_loops.len() + cw_offset_loops.len();
-        let mut slice_point_sets = Vec::new();
-        let mut visited_loop_pairs = BTreeSet::<(usize, usize)>::new();
-        let mut query_stack = Vec::new();
 
-        for i in 0..offset_loop_count {
-            let loop1 = Self::get_loop(i, ccw_offset_loops, cw_offset_loops);
-            let spatial_idx1 = &loop1.indexed_pline.spatial_index;
-            let bounds = spatial_idx1.bounds().expect("expect non-empty polyline");
-            let query_results = offset_loops_index.query_with_stack(
-                bounds.min_x,
-                bounds.min_y,
-                bounds.max_x,
-                bounds.max_y,
-                &mut query_stack,
-            );
-
-            for j in query_results {
-                if i == j {
-                    // skip same index (no self intersects among the offset loops)
-                    continue;
-                }
+        let slice_point_sets_per_loop =
+            map_indexes(offset_loop_count, Vec::new, |query_stack, i| {
+                let loop1 = Self::get_loop(i, ccw_offset_loops, cw_offset_loops);
+                let spatial_idx1 = &loop1.indexed_pline.spatial_index;
+                let bounds = spatial_idx1.bounds().expect("expect non-empty polyline");
+                let query_results = offset_loops_index.query_with_stack(
+                    bounds.min_x,
+                    bounds.min_y,
+                    bounds.max_x,
+                    bounds.max_y,
+                    query_stack,
+                );
 
-                if visited_loop_pairs.contains(&(j, i)) {
-                    // skip reversed index order (would end up comparing the same loops in another
-                    // iteration)
-                    continue;
-                }
+                let mut slice_point_sets = Vec::new();
+                // only test loops with greater index, skips same index (no self intersects among the
+                // offset loops) and reversed index order (same loops compared in another iteration)
+                for j in query_results.into_iter().filter(|&j| j > i) {
+                    let loop2 = Self::get_loop(j, ccw_offset_loops, cw_offset_loops);
 
-                visited_loop_pairs.insert((i, j));
+                    let intrs_opts = FindIntersectsOptions {
+                        pline1_aabb_index: Some(spatial_idx1),
+                        pos_equal_eps,
+                    };
 
-                let loop2 = Self::get_loop(j, ccw_offset_loops, cw_offset_loops);
+                    let intersects = loop1
+                        .indexed_pline
+                        .polyline
+                        .find_intersects_opt(&loop2.indexed_pline.polyline, &intrs_opts);
 
-                let intrs_opts = FindIntersectsOptions {
-                    pline1_aabb_index: Some(spatial_idx1),
-                    pos_equal_eps,
-                };
+                    if intersects.basic_intersects.is_empty()
+                        && intersects.overlapping_intersects.is_empty()
+                    {
+                        continue;
+                    }
 
-                let intersects = loop1
-                    .indexed_pline
-                    .polyline
-                    .find_intersects_opt(&loop2.indexed_pline.polyline, &intrs_opts);
+                    let mut slice_points = Vec::new();
 
-                if intersects.basic_intersects.is_empty()
-                    && intersects.overlapping_intersects.is_empty()
-                {
-                    continue;
-                }
+                    for intr in intersects.basic_intersects {
+                        slice_points.push(intr);
+                    }
 
-                let mut slice_points = Vec::new();
+                    // add overlapping start and end points
+                    for overlap_intr in intersects.overlapping_intersects {
+                        let start_index1 = overlap_intr.start_index1;
+                        let start_index2 = overlap_intr.start_index2;
+                        slice_points.push(PlineBasicIntersect {
+                            start_index1,
+                            start_index2,
+                            point: overlap_intr.point1,
+                        });
+                        slice_points.push(PlineBasicIntersect {
+                            start_index1,
+                            start_index2,
+                            point: overlap_intr.point2,
+                        });
+                    }
 
-                for intr in intersects.basic_intersects {
-                    slice_points.push(intr);
-                }
+                    let slice_point_set = SlicePointSet {
+                        loop_idx1: i,
+                        loop_idx2: j,
+                        slice_points,
+                    };
 
-                // add overlapping start and end points
-                for overlap_intr in intersects.overlapping_intersects {
-                    let start_index1 = overlap_intr.start_index1;
-                    let start_index2 = overlap_intr.start_index2;
-                    slice_points.push(PlineBasicIntersect {
-                        start_index1,
-                        start_index2,
-                        point: overlap_intr.point1,
-                    });
-                    slice_points.push(PlineBasicIntersect {
-                        start_index1,
-                        start_index2,
-                        point: overlap_intr.point2,
-                    });
+                    slice_point_sets.push(slice_point_set);
                 }
 
-                let slice_point_set = SlicePointSet {
-                    loop_idx1: i,
-                    loop_idx2: j,
-                    slice_points,
-                };
+                slice_point_sets
+            });
 
-                slice_point_sets.push(slice_point_set);
-            }
-        }
-
-        slice_point_sets
+        slice_point_sets_per_loop.into_iter().flatten().collect()
     }
 
     /// **Step 3** of the multipolyline offset algorithm: Creates valid slices from intersection points.
@@ -449,7 +472,8 @@ where
     /// * `ccw_offset_loops` - Counter-clockwise offset loops from Step 1
     /// * `cw_offset_loops` - Clockwise offset loops from Step 1
     /// * `slice_point_sets` - Intersection data from Step 2
-    /// * `offset` - The offset distance used for validation
+    /// * `offset` - The offset distance used for validation (unless overridden for a loop by
+    ///   [ShapeOffsetOptions::loop_offsets])
     /// * `options` - Offset options containing validation epsilons
     ///
     /// # Returns
@@ -473,6 +497,9 @@ where
         let offset_loop_count = ccw_offset_loops.len() + cw_offset_loops.len();
         let pos_equal_eps = options.pos_equal_eps;
         let offset_dist_eps = options.offset_dist_eps;
+        let input_loop_offsets = (0..(self.ccw_plines.len() + self.cw_plines.len()))
+            .map(|i| self.loop_offset(i, offset, options))
+            .collect::<Vec<_>>();
 
         let mut slice_points_lookup = BTreeMap::<usize, Vec<usize>>::new();
         for (set_idx, set) in slice_point_sets.iter().enumerate() {
@@ -486,23 +513,6 @@ where
                 .push(set_idx);
         }
 
-        let mut sorted_intrs = Vec::new();
-        let mut slices_data = Vec::new();
-        let mut query_stack = Vec::new();
-
-        /// A point where an offset loop should be divided during slice creation.
-        ///
-        /// This structure represents a specific location on a polyline where an intersection
-        /// occurs, defined by both the segment index and the exact position. These points
-        /// are used to divide offset loops into valid slices.
-        #[derive(Debug, Clone, Copy)]
-        struct DissectionPoint<T> {
-            /// Index of the polyline segment containing this point
-            seg_idx: usize,
-            /// Exact 2D position of the dissection point
-            pos: Vector2<T>,
-        }
-
         let create_slice = |pt1: &DissectionPoint<T>,
                             pt2: &DissectionPoint<T>,
                             offset_loop: &Polyline<T>|
@@ -537,7 +547,7 @@ where
 
                 if !point_valid_for_offset(
                     &parent_loop.polyline,
-                    offset,
+                    input_loop_offsets[input_loop_idx],
                     &parent_loop.spatial_index,
                     midpoint,
                     query_stack,
@@ -550,68 +560,91 @@ where
             true
         };
 
-        for loop_idx in 0..offset_loop_count {
-            sorted_intrs.clear();
-            let curr_loop = Self::get_loop(loop_idx, ccw_offset_loops, cw_offset_loops);
-
-            if let Some(slice_point_set_idxs) = slice_points_lookup.get(&loop_idx) {
-                // gather all the intersects for the current loop
-                sorted_intrs.extend(slice_point_set_idxs.iter().flat_map(|set_idx| {
-                    let set = &slice_point_sets[*set_idx];
-                    debug_assert!(set.loop_idx1 == loop_idx || set.loop_idx2 == loop_idx);
-                    let loop_is_first_index = set.loop_idx1 == loop_idx;
-                    set.slice_points.iter().map(move |intr_pt| {
-                        let seg_idx = if loop_is_first_index {
-                            intr_pt.start_index1
-                        } else {
-                            intr_pt.start_index2
-                        };
-                        DissectionPoint {
-                            seg_idx,
-                            pos: intr_pt.point,
+        let slices_per_loop = map_indexes(
+            offset_loop_count,
+            || (Vec::new(), Vec::new()),
+            |(sorted_intrs, query_stack), loop_idx| {
+                sorted_intrs.clear();
+                let mut slices_data = Vec::new();
+                let curr_loop = Self::get_loop(loop_idx, ccw_offset_loops, cw_offset_loops);
+
+                if let Some(slice_point_set_idxs) = slice_points_lookup.get(&loop_idx) {
+                    // gather all the intersects for the current loop
+                    sorted_intrs.extend(slice_point_set_idxs.iter().flat_map(|set_idx| {
+                        let set = &slice_point_sets[*set_idx];
+                        debug_assert!(set.loop_idx1 == loop_idx || set.loop_idx2 == loop_idx);
+                        let loop_is_first_index = set.loop_idx1 == loop_idx;
+                        set.slice_points.iter().map(move |intr_pt| {
+                            let seg_idx = if loop_is_first_index {
+                                intr_pt.start_index1
+                            } else {
+                                intr_pt.start_index2
+                            };
+                            DissectionPoint {
+                                seg_idx,
+                                pos: intr_pt.point,
+                            }
+                        })
+                    }));
+
+                    // sort the intersect points along direction of polyline
+                    sorted_intrs.sort_unstable_by(|a, b| {
+                        // sort by the segment index, then if both intersects on the same segment sort
+                        // by distance from start of segment
+                        a.seg_idx.cmp(&b.seg_idx).then_with(|| {
+                            let seg_start = curr_loop.indexed_pline.polyline.at(a.seg_idx).pos();
+                            let dist1 = dist_squared(a.pos, seg_start);
+                            let dist2 = dist_squared(b.pos, seg_start);
+                            dist1.total_cmp(&dist2)
+                        })
+                    });
+
+                    // construct valid slices to later be stitched together
+                    if sorted_intrs.len() == 1 {
+                        // treat whole loop as slice
+                        let v_data =
+                            PlineViewData::from_entire_pline(&curr_loop.indexed_pline.polyline);
+                        if is_slice_valid(
+                            &v_data,
+                            &curr_loop.indexed_pline.polyline,
+                            curr_loop.parent_loop_idx,
+                            query_stack,
+                        ) {
+                            slices_data.push(DissectedSlice {
+                                source_idx: loop_idx,
+                                v_data,
+                            });
+                        }
+                    } else {
+                        // create slices from adjacent points
+                        let mut windows = sorted_intrs.windows(2);
+                        while let Some([pt1, pt2]) = windows.next() {
+                            if let Some(v_data) =
+                                create_slice(pt1, pt2, &curr_loop.indexed_pline.polyline)
+                                && is_slice_valid(
+                                    &v_data,
+                                    &curr_loop.indexed_pline.polyline,
+                                    curr_loop.parent_loop_idx,
+                                    query_stack,
+                                )
+                            {
+                                slices_data.push(DissectedSlice {
+                                    source_idx: loop_idx,
+                                    v_data,
+                                });
+                            }
                         }
-                    })
-                }));
-
-                // sort the intersect points along direction of polyline
-                sorted_intrs.sort_unstable_by(|a, b| {
-                    // sort by the segment index, then if both intersects on the same segment sort
-                    // by distance from start of segment
-                    a.seg_idx.cmp(&b.seg_idx).then_with(|| {
-                        let seg_start = curr_loop.indexed_pline.polyline.at(a.seg_idx).pos();
-                        let dist1 = dist_squared(a.pos, seg_start);
-                        let dist2 = dist_squared(b.pos, seg_start);
-                        dist1.total_cmp(&dist2)
-                    })
-                });
 
-                // construct valid slices to later be stitched together
-                if sorted_intrs.len() == 1 {
-                    // treat whole loop as slice
-                    let v_data =
-                        PlineViewData::from_entire_pline(&curr_loop.indexed_pline.polyline);
-                    if is_slice_valid(
-                        &v_data,
-                        &curr_loop.indexed_pline.polyline,
-                        curr_loop.parent_loop_idx,
-                        &mut query_stack,
-                    ) {
-                        slices_data.push(DissectedSlice {
-                            source_idx: loop_idx,
-                            v_data,
-                        });
-                    }
-                } else {
-                    // create slices from adjacent points
-                    let mut windows = sorted_intrs.windows(2);
-                    while let Some([pt1, pt2]) = windows.next() {
+                        // collect slice from last to start
+                        let pt1 = sorted_intrs.last().unwrap();
+                        let pt2 = &sorted_intrs[0];
                         if let Some(v_data) =
                             create_slice(pt1, pt2, &curr_loop.indexed_pline.polyline)
                             && is_slice_valid(
                                 &v_data,
                                 &curr_loop.indexed_pline.polyline,
                                 curr_loop.parent_loop_idx,
-                                &mut query_stack,
+                                query_stack,
                             )
                         {
                             slices_data.push(DissectedSlice {
@@ -620,43 +653,29 @@ where
                             });
                         }
                     }
-
-                    // collect slice from last to start
-                    let pt1 = sorted_intrs.last().unwrap();
-                    let pt2 = &sorted_intrs[0];
-                    if let Some(v_data) = create_slice(pt1, pt2, &curr_loop.indexed_pline.polyline)
-                        && is_slice_valid(
-                            &v_data,
-                            &curr_loop.indexed_pline.polyline,
-                            curr_loop.parent_loop_idx,
-                            &mut query_stack,
-                        )
-                    {
+                } else {
+                    // no intersects but still must test distance of one vertex position since it may be
+                    // inside another offset (completely eclipsed by island offset)
+                    let v_data =
+                        PlineViewData::from_entire_pline(&curr_loop.indexed_pline.polyline);
+                    if is_slice_valid(
+                        &v_data,
+                        &curr_loop.indexed_pline.polyline,
+                        curr_loop.parent_loop_idx,
+                        query_stack,
+                    ) {
                         slices_data.push(DissectedSlice {
                             source_idx: loop_idx,
                             v_data,
                         });
                     }
                 }
-            } else {
-                // no intersects but still must test distance of one vertex position since it may be
-                // inside another offset (completely eclipsed by island offset)
-                let v_data = PlineViewData::from_entire_pline(&curr_loop.indexed_pline.polyline);
-                if is_slice_valid(
-                    &v_data,
-                    &curr_loop.indexed_pline.polyline,
-                    curr_loop.parent_loop_idx,
-                    &mut query_stack,
-                ) {
-                    slices_data.push(DissectedSlice {
-                        source_idx: loop_idx,
-                        v_data,
-                    });
-                }
-            }
-        }
 
-        slices_data
+                slices_data
+            },
+        );
+
+        slices_per_loop.into_iter().flatten().collect()
     }
 
     /// **Step 4** of the multipolyline offset algorithm: Stitches slices together into final shapes.
@@ -694,6 +713,34 @@ where
         pos_equal_eps: T,
         slice_join_eps: T,
     ) -> Self {
+        Self::stitch_slices_into_shape(
+            &slices_data,
+            |i| {
+                &Self::get_loop(i, ccw_offset_loops, cw_offset_loops)
+                    .indexed_pline
+                    .polyline
+            },
+            pos_equal_eps,
+            slice_join_eps,
+        )
+    }
+
+    /// Stitch slices end to end into closed polylines and return them as a shape.
+    ///
+    /// `get_source` is used to look up the source polyline of a slice from its
+    /// [DissectedSlice::source_idx]. Slices with inverted direction are stitched from their end
+    /// point to their start point. When multiple slices start at the end of the current slice then
+    /// the slice from the same source polyline is preferred.
+    fn stitch_slices_into_shape<'a, F>(
+        slices_data: &[DissectedSlice<T>],
+        get_source: F,
+        pos_equal_eps: T,
+        slice_join_eps: T,
+    ) -> Self
+    where
+        F: Fn(usize) -> &'a Polyline<T>,
+        T: 'a,
+    {
         if slices_data.is_empty() {
             return Self::empty();
         }
@@ -704,7 +751,7 @@ where
         let slice_starts_aabb_index = {
             let mut builder = StaticAABB2DIndexBuilder::new(slices_data.len());
             for slice in slices_data.iter() {
-                let start_point = slice.v_data.updated_start.pos();
+                let start_point = slice.start_point();
                 builder.add(
                     start_point.x - slice_join_eps,
                     start_point.y - slice_join_eps,
@@ -740,15 +787,13 @@ where
                 loop_count += 1;
 
                 let curr_slice = &slices_data[current_index];
-                let source_loop =
-                    Self::get_loop(curr_slice.source_idx, ccw_offset_loops, cw_offset_loops);
-                let slice_view = curr_slice.v_data.view(&source_loop.indexed_pline.polyline);
+                let slice_view = curr_slice.v_data.view(get_source(curr_slice.source_idx));
                 let slice_userdata_values = slice_view.get_userdata_values();
                 current_pline.extend_remove_repeat(&slice_view, pos_equal_eps);
                 current_pline.add_userdata_values(slice_userdata_values);
 
                 query_results.clear();
-                let slice_end_point = curr_slice.v_data.end_point;
+                let slice_end_point = curr_slice.end_point();
                 let mut aabb_index_visitor = |i: usize| {
                     if !visited_slices_idxs[i] {
                         query_results.push(i);
@@ -821,6 +866,33 @@ where
         }
     }
 
+    /// Get the indexed polyline at index position `i` of the [Shape::plines_index] (all counter
+    /// clockwise polylines followed by all clockwise polylines).
+    pub(crate) fn get_pline(&self, i: usize) -> &IndexedPolyline<T> {
+        if i < self.ccw_plines.len() {
+            &self.ccw_plines[i]
+        } else {
+            &self.cw_plines[i - self.ccw_plines.len()]
+        }
+    }
+
+    /// Get the offset distance for the loop at index position `i` of the [Shape::plines_index],
+    /// the first matching entry of [ShapeOffsetOptions::loop_offsets] or `offset` if none match.
+    fn loop_offset(&self, i: usize, offset: T, options: &ShapeOffsetOptions<T>) -> T {
+        options
+            .loop_offsets
+            .iter()
+            .find(|(key, _)| match *key {
+                ShapeLoopKey::Index(idx) => idx == i,
+                ShapeLoopKey::Userdata(value) => self
+                    .get_pline(i)
+                    .polyline
+                    .get_userdata_values()
+                    .any(|u| u == value),
+            })
+            .map_or(offset, |&(_, loop_offset)| loop_offset)
+    }
+
     fn get_loop<'a>(
         i: usize,
         s1: &'a [OffsetLoop<T>],
@@ -834,6 +906,19 @@ where
     }
 }
 
+/// A point where a loop should be divided during slice creation.
+///
+/// This structure represents a specific location on a polyline where an intersection
+/// occurs, defined by both the segment index and the exact position. These points
+/// are used to divide loops into slices.
+#[derive(Debug, Clone, Copy)]
+struct DissectionPoint<T> {
+    /// Index of the polyline segment containing this point
+    seg_idx: usize,
+    /// Exact 2D position of the dissection point
+    pos: Vector2<T>,
+}
+
 /// Intersection data between two offset loops.
 ///
 /// This structure contains all intersection points found between a pair of offset loops,
@@ -871,3 +956,55 @@ pub struct DissectedSlice<T: Real> {
     /// View data defining the slice boundaries within the source polyline
     pub v_data: PlineViewData<T>,
 }
+
+impl<T> DissectedSlice<T>
+where
+    T: Real,
+{
+    /// Start point of the slice (accounting for inverted direction).
+    #[inline]
+    pub fn start_point(&self) -> Vector2<T> {
+        if self.v_data.inverted_direction {
+            self.v_data.end_point
+        } else {
+            self.v_data.updated_start.pos()
+        }
+    }
+
+    /// End point of the slice (accounting for inverted direction).
+    #[inline]
+    pub fn end_point(&self) -> Vector2<T> {
+        if self.v_data.inverted_direction {
+            self.v_data.updated_start.pos()
+        } else {
+            self.v_data.end_point
+        }
+    }
+}
+
+/// Map `f` over the indexes `0..count` and collect the results in index order. If the `rayon`
+/// feature is enabled then `f` is run in parallel (output order is unchanged), `init` is used to
+/// create the scratch state given to `f` (e.g. query stacks), which may be created more than once.
+#[cfg(feature = "rayon")]
+fn map_indexes<S, R, I, F>(count: usize, init: I, f: F) -> Vec<R>
+where
+    R: Send,
+    I: Fn() -> S + Send + Sync,
+    F: Fn(&mut S, usize) -> R + Send + Sync,
+{
+    use rayon::prelude::*;
+    (0..count).into_par_iter().map_init(init, f).collect()
+}
+
+/// Map `f` over the indexes `0..count` and collect the results in index order. If the `rayon`
+/// feature is enabled then `f` is run in parallel (output order is unchanged), `init` is used to
+/// create the scratch state given to `f` (e.g. query stacks), which may be created more than once.
+#[cfg(not(feature = "rayon"))]
+fn map_indexes<S, R, I, F>(count: usize, init: I, f: F) -> Vec<R>
+where
+    I: Fn() -> S,
+    F: Fn(&mut S, usize) -> R,
+{
+    let mut state = init();
+    (0..count).map(|i| f(&mut state, i)).collect()
+}
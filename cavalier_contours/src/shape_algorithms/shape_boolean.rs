@@ -0,0 +1,738 @@
+use crate::{
+    core::{
+        math::{Vector2, dist_squared},
+        traits::Real,
+    },
+    polyline::{
+        BooleanOp, ClipLocation, FillRule, FindIntersectsOptions, PlineClipResult, PlineSource,
+        PlineViewData, Polyline,
+        internal::{
+            pline_boolean::remove_repeat_slices, pline_clip::clip_at_points,
+            pline_intersects::all_self_intersects_as_basic,
+        },
+        seg_closest_point, seg_midpoint, seg_tangent_vector,
+    },
+};
+
+use super::{DissectedSlice, DissectionPoint, Shape};
+
+/// Struct to hold options parameters when performing a boolean operation between shapes.
+#[derive(Debug, Clone)]
+pub struct ShapeBooleanOptions<T> {
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal when stitching
+    /// polyline slices together, also used to determine if a slice lies on the boundary of the
+    /// other shape (coincident boundaries).
+    pub slice_join_eps: T,
+}
+
+impl<T> ShapeBooleanOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+            slice_join_eps: T::from(1e-4).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for ShapeBooleanOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Location of a slice relative to the area of the other shape in a boolean operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SliceLocation {
+    /// Slice is inside the other shape's area.
+    Inside,
+    /// Slice is outside the other shape's area.
+    Outside,
+    /// Slice lies on the boundary of the other shape and has the same direction.
+    CoincidentSameDirection,
+    /// Slice lies on the boundary of the other shape and has the opposite direction.
+    CoincidentOppositeDirection,
+}
+
+/// Determine if a slice should be kept for the boolean `operation`, returns `None` if the slice
+/// is discarded, otherwise returns whether the slice direction should be inverted.
+fn keep_slice(operation: BooleanOp, from_self: bool, location: SliceLocation) -> Option<bool> {
+    use SliceLocation::*;
+    match (operation, from_self, location) {
+        (BooleanOp::Or, _, Outside) => Some(false),
+        (BooleanOp::And, _, Inside) => Some(false),
+        // coincident boundaries with the same direction are only kept once (from self)
+        (BooleanOp::Or | BooleanOp::And, true, CoincidentSameDirection) => Some(false),
+        (BooleanOp::Not, true, Outside) => Some(false),
+        (BooleanOp::Not, true, CoincidentOppositeDirection) => Some(false),
+        (BooleanOp::Not, false, Inside) => Some(true),
+        (BooleanOp::Xor, _, Outside) => Some(false),
+        (BooleanOp::Xor, _, Inside) => Some(true),
+        (BooleanOp::Xor, _, CoincidentOppositeDirection) => Some(false),
+        _ => None,
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Perform a boolean `operation` between this shape and another using default options.
+    ///
+    /// Both shapes are treated as whole regions (counter clockwise polylines add area and clockwise
+    /// polylines remove area), each shape is expected to not have overlapping polylines. For
+    /// [BooleanOp::Not] the result is `self` minus `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// // 10x10 square with 4x4 hole
+    /// let square_with_hole = Shape::from_plines([
+    ///     pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)],
+    ///     pline_closed![(3.0, 3.0, 0.0), (3.0, 7.0, 0.0), (7.0, 7.0, 0.0), (7.0, 3.0, 0.0)],
+    /// ]);
+    /// // 2x2 square inside of the hole
+    /// let island = Shape::from_plines([pline_closed![
+    ///     (4.0, 4.0, 0.0),
+    ///     (6.0, 4.0, 0.0),
+    ///     (6.0, 6.0, 0.0),
+    ///     (4.0, 6.0, 0.0),
+    /// ]]);
+    ///
+    /// let result = square_with_hole.boolean(&island, BooleanOp::Or);
+    /// assert_eq!(result.ccw_plines.len(), 2);
+    /// assert_eq!(result.cw_plines.len(), 1);
+    ///
+    /// let result = square_with_hole.boolean(&island, BooleanOp::And);
+    /// assert!(result.ccw_plines.is_empty());
+    /// assert!(result.cw_plines.is_empty());
+    /// ```
+    pub fn boolean(&self, other: &Self, operation: BooleanOp) -> Self {
+        self.boolean_opt(other, operation, &Default::default())
+    }
+
+    /// Perform a boolean `operation` between this shape and another with options provided.
+    ///
+    /// All the polylines of both shapes are sliced at their intersects with the polylines of the
+    /// other shape (using [Shape::plines_index] to only test polylines with overlapping bounding
+    /// boxes), slices are then kept or discarded based on their location relative to the other
+    /// shape's area and stitched together to form the resulting shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::{Shape, ShapeBooleanOptions};
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// let square1 = Shape::from_plines([pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (4.0, 0.0, 0.0),
+    ///     (4.0, 4.0, 0.0),
+    ///     (0.0, 4.0, 0.0),
+    /// ]]);
+    /// let square2 = Shape::from_plines([pline_closed![
+    ///     (2.0, 2.0, 0.0),
+    ///     (6.0, 2.0, 0.0),
+    ///     (6.0, 6.0, 0.0),
+    ///     (2.0, 6.0, 0.0),
+    /// ]]);
+    /// let options = ShapeBooleanOptions {
+    ///     pos_equal_eps: 1e-6,
+    ///     ..Default::default()
+    /// };
+    /// let result = square1.boolean_opt(&square2, BooleanOp::Not, &options);
+    /// assert_eq!(result.ccw_plines.len(), 1);
+    /// assert!(result.cw_plines.is_empty());
+    /// assert!(result.ccw_plines[0].polyline.area().fuzzy_eq(12.0));
+    /// ```
+    pub fn boolean_opt(
+        &self,
+        other: &Self,
+        operation: BooleanOp,
+        options: &ShapeBooleanOptions<T>,
+    ) -> Self {
+        let pos_equal_eps = options.pos_equal_eps;
+        let self_count = self.ccw_plines.len() + self.cw_plines.len();
+        let other_count = other.ccw_plines.len() + other.cw_plines.len();
+
+        // dissection points for all the polylines of self followed by all the polylines of other
+        let mut dissection_points = vec![Vec::new(); self_count + other_count];
+        let mut query_stack = Vec::new();
+
+        for i in 0..self_count {
+            let pline1 = self.get_pline(i);
+            let Some(bounds) = pline1.spatial_index.bounds() else {
+                continue;
+            };
+
+            let query_results = other.plines_index.query_with_stack(
+                bounds.min_x,
+                bounds.min_y,
+                bounds.max_x,
+                bounds.max_y,
+                &mut query_stack,
+            );
+
+            for j in query_results {
+                let pline2 = other.get_pline(j);
+                let intrs_opts = FindIntersectsOptions {
+                    pline1_aabb_index: Some(&pline1.spatial_index),
+                    pos_equal_eps,
+                };
+
+                let intersects = pline1
+                    .polyline
+                    .find_intersects_opt(&pline2.polyline, &intrs_opts);
+
+                let mut add_point = |seg_idx1: usize, seg_idx2: usize, pos: Vector2<T>| {
+                    dissection_points[i].push(DissectionPoint {
+                        seg_idx: seg_idx1,
+                        pos,
+                    });
+                    dissection_points[self_count + j].push(DissectionPoint {
+                        seg_idx: seg_idx2,
+                        pos,
+                    });
+                };
+
+                for intr in intersects.basic_intersects {
+                    add_point(intr.start_index1, intr.start_index2, intr.point);
+                }
+
+                // add overlapping start and end points
+                for overlap_intr in intersects.overlapping_intersects {
+                    let start_index1 = overlap_intr.start_index1;
+                    let start_index2 = overlap_intr.start_index2;
+                    add_point(start_index1, start_index2, overlap_intr.point1);
+                    add_point(start_index1, start_index2, overlap_intr.point2);
+                }
+            }
+        }
+
+        let get_source = |i: usize| {
+            if i < self_count {
+                &self.get_pline(i).polyline
+            } else {
+                &other.get_pline(i - self_count).polyline
+            }
+        };
+
+        let mut slices_data = Vec::new();
+        // for xor the slices forming `other` minus `self` are stitched separately from the slices
+        // forming `self` minus `other` so the two regions are not joined where they touch
+        let mut xor_slices_data = Vec::new();
+        for (source_idx, points) in dissection_points.iter_mut().enumerate() {
+            let from_self = source_idx < self_count;
+            let source = get_source(source_idx);
+            let other_shape = if from_self { other } else { self };
+            Self::visit_loop_slices(source, points, pos_equal_eps, |v_data| {
+                let location = other_shape.slice_location(
+                    &v_data,
+                    source,
+                    options.slice_join_eps,
+                    &mut query_stack,
+                );
+
+                if let Some(inverted_direction) = keep_slice(operation, from_self, location) {
+                    let slice = DissectedSlice {
+                        source_idx,
+                        v_data: PlineViewData {
+                            inverted_direction,
+                            ..v_data
+                        },
+                    };
+
+                    if operation == BooleanOp::Xor && from_self == inverted_direction {
+                        xor_slices_data.push(slice);
+                    } else {
+                        slices_data.push(slice);
+                    }
+                }
+            });
+        }
+
+        let result = Self::stitch_slices_into_shape(
+            &slices_data,
+            get_source,
+            pos_equal_eps,
+            options.slice_join_eps,
+        );
+
+        if xor_slices_data.is_empty() {
+            return result;
+        }
+
+        let xor_result = Self::stitch_slices_into_shape(
+            &xor_slices_data,
+            get_source,
+            pos_equal_eps,
+            options.slice_join_eps,
+        );
+
+        Self::from_plines(
+            result
+                .ccw_plines
+                .into_iter()
+                .chain(result.cw_plines)
+                .chain(xor_result.ccw_plines)
+                .chain(xor_result.cw_plines)
+                .map(|p| p.polyline),
+        )
+    }
+
+    /// Combine any number of closed polylines into a shape using default options, the resulting
+    /// shape covers the region filled according to `fill_rule`.
+    ///
+    /// See [Shape::boolean_many_opt] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// // row of overlapping squares
+    /// let squares = (0..10).map(|i| {
+    ///     let x = i as f64;
+    ///     pline_closed![(x, 0.0, 0.0), (x + 2.0, 0.0, 0.0), (x + 2.0, 2.0, 0.0), (x, 2.0, 0.0)]
+    /// });
+    /// let result = Shape::boolean_many(squares, FillRule::NonZero);
+    /// assert_eq!(result.ccw_plines.len(), 1);
+    /// assert!(result.cw_plines.is_empty());
+    /// assert!(result.area().fuzzy_eq(22.0));
+    /// ```
+    pub fn boolean_many<I>(plines: I, fill_rule: FillRule) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        Self::boolean_many_opt(plines, fill_rule, &Default::default())
+    }
+
+    /// Combine any number of closed polylines into a shape with options provided, the resulting
+    /// shape covers the region filled according to `fill_rule`.
+    ///
+    /// The winding number at a point is the sum of the winding numbers of all the polylines
+    /// (counter clockwise polylines add 1 and clockwise polylines subtract 1 where they enclose
+    /// the point). All polylines are sliced at their intersects with each other and themselves in
+    /// one pass, slices that separate a filled region from an unfilled region are kept (oriented
+    /// so the filled region is to the left) and stitched together to form the resulting shape.
+    /// This avoids the cost and accumulated error of repeated [Shape::boolean] calls when
+    /// combining many polylines. Open polylines are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::{Shape, ShapeBooleanOptions};
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::core::traits::*;
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let inner = pline_closed![(3.0, 3.0, 0.0), (7.0, 3.0, 0.0), (7.0, 7.0, 0.0), (3.0, 7.0, 0.0)];
+    /// let options = ShapeBooleanOptions::new();
+    /// // inner square region has winding number 2
+    /// let result = Shape::boolean_many_opt([outer.clone(), inner.clone()], FillRule::NonZero, &options);
+    /// assert_eq!(result.ccw_plines.len(), 1);
+    /// assert!(result.cw_plines.is_empty());
+    /// assert!(result.area().fuzzy_eq(100.0));
+    ///
+    /// let result = Shape::boolean_many_opt([outer.clone(), inner.clone()], FillRule::EvenOdd, &options);
+    /// assert_eq!(result.ccw_plines.len(), 1);
+    /// assert_eq!(result.cw_plines.len(), 1);
+    /// assert!(result.area().fuzzy_eq(84.0));
+    ///
+    /// // clockwise polylines subtract
+    /// let mut inner_cw = inner.clone();
+    /// inner_cw.invert_direction_mut();
+    /// let result = Shape::boolean_many_opt([outer, inner_cw], FillRule::Positive, &options);
+    /// assert_eq!(result.cw_plines.len(), 1);
+    /// assert!(result.area().fuzzy_eq(84.0));
+    /// ```
+    pub fn boolean_many_opt<I>(
+        plines: I,
+        fill_rule: FillRule,
+        options: &ShapeBooleanOptions<T>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        let pos_equal_eps = options.pos_equal_eps;
+        let input = Self::from_plines(plines.into_iter().filter(|p| p.is_closed()));
+        let count = input.ccw_plines.len() + input.cw_plines.len();
+
+        let mut dissection_points = vec![Vec::new(); count];
+        let mut query_stack = Vec::new();
+
+        for i in 0..count {
+            let pline1 = input.get_pline(i);
+            let Some(bounds) = pline1.spatial_index.bounds() else {
+                continue;
+            };
+
+            let self_intersects = all_self_intersects_as_basic(
+                &pline1.polyline,
+                &pline1.spatial_index,
+                true,
+                pos_equal_eps,
+            );
+
+            for intr in self_intersects {
+                for seg_idx in [intr.start_index1, intr.start_index2] {
+                    dissection_points[i].push(DissectionPoint {
+                        seg_idx,
+                        pos: intr.point,
+                    });
+                }
+            }
+
+            let query_results = input.plines_index.query_with_stack(
+                bounds.min_x,
+                bounds.min_y,
+                bounds.max_x,
+                bounds.max_y,
+                &mut query_stack,
+            );
+
+            // each pair is only tested once
+            for j in query_results.into_iter().filter(|&j| j > i) {
+                let pline2 = input.get_pline(j);
+                let intrs_opts = FindIntersectsOptions {
+                    pline1_aabb_index: Some(&pline1.spatial_index),
+                    pos_equal_eps,
+                };
+
+                let intersects = pline1
+                    .polyline
+                    .find_intersects_opt(&pline2.polyline, &intrs_opts);
+
+                let mut add_point = |seg_idx1: usize, seg_idx2: usize, pos: Vector2<T>| {
+                    dissection_points[i].push(DissectionPoint {
+                        seg_idx: seg_idx1,
+                        pos,
+                    });
+                    dissection_points[j].push(DissectionPoint {
+                        seg_idx: seg_idx2,
+                        pos,
+                    });
+                };
+
+                for intr in intersects.basic_intersects {
+                    add_point(intr.start_index1, intr.start_index2, intr.point);
+                }
+
+                for overlap_intr in intersects.overlapping_intersects {
+                    let start_index1 = overlap_intr.start_index1;
+                    let start_index2 = overlap_intr.start_index2;
+                    add_point(start_index1, start_index2, overlap_intr.point1);
+                    add_point(start_index1, start_index2, overlap_intr.point2);
+                }
+            }
+        }
+
+        let get_source = |i: usize| &input.get_pline(i).polyline;
+
+        // keep slices that separate filled and unfilled regions, test points are offset to either
+        // side of the slice so they do not lie on the slice
+        let test_offset = T::from(10.0).unwrap() * pos_equal_eps;
+        let mut slices_data = Vec::new();
+        let mut slice_midpoints = Vec::new();
+        for (source_idx, points) in dissection_points.iter_mut().enumerate() {
+            let source = get_source(source_idx);
+            // points at the end of a segment use the next segment index and repeat points are
+            // removed (same point found for many polylines)
+            for pt in points.iter_mut() {
+                let next_idx = source.next_wrapping_index(pt.seg_idx);
+                if pt
+                    .pos
+                    .fuzzy_eq_eps(source.at(next_idx).pos(), pos_equal_eps)
+                {
+                    pt.seg_idx = next_idx;
+                }
+            }
+            points.sort_unstable_by(|a, b| {
+                a.seg_idx.cmp(&b.seg_idx).then_with(|| {
+                    let seg_start = source.at(a.seg_idx).pos();
+                    let dist1 = dist_squared(a.pos, seg_start);
+                    let dist2 = dist_squared(b.pos, seg_start);
+                    dist1.total_cmp(&dist2)
+                })
+            });
+            points.dedup_by(|a, b| {
+                a.seg_idx == b.seg_idx && a.pos.fuzzy_eq_eps(b.pos, pos_equal_eps)
+            });
+            Self::visit_loop_slices(source, points, pos_equal_eps, |v_data| {
+                let slice_view = v_data.view(source);
+                let v1 = slice_view.at(0);
+                let v2 = slice_view.at(1);
+                let midpoint = seg_midpoint(v1, v2);
+                let left_normal = seg_tangent_vector(v1, v2, midpoint)
+                    .unit_perp()
+                    .scale(test_offset);
+
+                let mut is_filled = |pt: Vector2<T>| {
+                    fill_rule.is_filled(input.winding_number_at(pt, &mut query_stack))
+                };
+
+                let left_filled = is_filled(midpoint + left_normal);
+                let right_filled = is_filled(midpoint - left_normal);
+                if left_filled != right_filled {
+                    slices_data.push(DissectedSlice {
+                        source_idx,
+                        v_data: PlineViewData {
+                            inverted_direction: right_filled,
+                            ..v_data
+                        },
+                    });
+                    slice_midpoints.push(midpoint);
+                }
+            });
+        }
+
+        let slices_data =
+            remove_repeat_slices(slices_data, &slice_midpoints, options.slice_join_eps, |s| {
+                (s.start_point(), s.end_point())
+            });
+
+        Self::stitch_slices_into_shape(
+            &slices_data,
+            get_source,
+            pos_equal_eps,
+            options.slice_join_eps,
+        )
+    }
+
+    /// Clip `pline` by the area of this shape using default options.
+    ///
+    /// See [Shape::clip_pline_opt] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// # use cavalier_contours::{pline_closed, pline_open};
+    /// # use cavalier_contours::core::traits::*;
+    /// // 10x10 square with 4x4 hole
+    /// let shape = Shape::from_plines([
+    ///     pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)],
+    ///     pline_closed![(3.0, 3.0, 0.0), (3.0, 7.0, 0.0), (7.0, 7.0, 0.0), (7.0, 3.0, 0.0)],
+    /// ]);
+    /// let path = pline_open![(-1.0, 5.0, 0.0), (11.0, 5.0, 0.0)];
+    /// let result = shape.clip_pline(&path);
+    /// assert_eq!(result.inside.len(), 2);
+    /// assert_eq!(result.outside.len(), 3);
+    /// let inside_length: f64 = result.inside.iter().map(|s| s.pline.path_length()).sum();
+    /// assert!(inside_length.fuzzy_eq(6.0));
+    /// ```
+    pub fn clip_pline<P>(&self, pline: &P) -> PlineClipResult<P::OutputPolyline>
+    where
+        P: PlineSource<Num = T> + ?Sized,
+    {
+        self.clip_pline_opt(pline, &Default::default())
+    }
+
+    /// Clip `pline` by the area of this shape with options provided.
+    ///
+    /// `pline` is sliced at all of its intersects with the polylines of this shape and each slice
+    /// is returned as inside of the shape, outside of the shape, or on the boundary of the shape
+    /// (using [ShapeBooleanOptions::slice_join_eps] to determine if a slice lies on the boundary).
+    /// Each slice holds the [PlineViewData] for forming the slice from `pline`.
+    ///
+    /// See [PlineSource::clip_opt] for clipping by a single polyline.
+    pub fn clip_pline_opt<P>(
+        &self,
+        pline: &P,
+        options: &ShapeBooleanOptions<T>,
+    ) -> PlineClipResult<P::OutputPolyline>
+    where
+        P: PlineSource<Num = T> + ?Sized,
+    {
+        let pos_equal_eps = options.pos_equal_eps;
+        if pline.vertex_count() < 2 {
+            return PlineClipResult::new();
+        }
+
+        let pline_index = pline.create_approx_aabb_index();
+        let mut query_stack = Vec::new();
+        let mut points = Vec::new();
+        if let Some(bounds) = pline_index.bounds() {
+            let query_results = self.plines_index.query_with_stack(
+                bounds.min_x,
+                bounds.min_y,
+                bounds.max_x,
+                bounds.max_y,
+                &mut query_stack,
+            );
+
+            let intrs_opts = FindIntersectsOptions {
+                pline1_aabb_index: Some(&pline_index),
+                pos_equal_eps,
+            };
+
+            for i in query_results {
+                let intersects =
+                    pline.find_intersects_opt(&self.get_pline(i).polyline, &intrs_opts);
+                points.extend(
+                    intersects
+                        .basic_intersects
+                        .iter()
+                        .map(|intr| (intr.start_index1, intr.point)),
+                );
+
+                for intr in intersects.overlapping_intersects.iter() {
+                    points.push((intr.start_index1, intr.point1));
+                    points.push((intr.start_index1, intr.point2));
+                }
+            }
+        }
+
+        clip_at_points(pline, &mut points, pos_equal_eps, |midpoint| {
+            if self
+                .boundary_tangent_at(midpoint, options.slice_join_eps, &mut query_stack)
+                .is_some()
+            {
+                ClipLocation::OnBoundary
+            } else if self.winding_number_at(midpoint, &mut query_stack) != 0 {
+                ClipLocation::Inside
+            } else {
+                ClipLocation::Outside
+            }
+        })
+    }
+
+    /// Visit all the slices of the closed polyline `source` formed by dividing it at the
+    /// `points` given. If there are less than 2 points then the entire polyline is visited as a
+    /// single slice.
+    fn visit_loop_slices<F>(
+        source: &Polyline<T>,
+        points: &mut [DissectionPoint<T>],
+        pos_equal_eps: T,
+        mut visitor: F,
+    ) where
+        F: FnMut(PlineViewData<T>),
+    {
+        if points.len() < 2 {
+            visitor(PlineViewData::from_entire_pline(source));
+            return;
+        }
+
+        // sort by the segment index, then if both points on the same segment sort by distance
+        // from start of segment
+        points.sort_unstable_by(|a, b| {
+            a.seg_idx.cmp(&b.seg_idx).then_with(|| {
+                let seg_start = source.at(a.seg_idx).pos();
+                let dist1 = dist_squared(a.pos, seg_start);
+                let dist2 = dist_squared(b.pos, seg_start);
+                dist1.total_cmp(&dist2)
+            })
+        });
+
+        let create_slice = |pt1: &DissectionPoint<T>, pt2: &DissectionPoint<T>| {
+            PlineViewData::from_slice_points(
+                source,
+                pt1.pos,
+                pt1.seg_idx,
+                pt2.pos,
+                pt2.seg_idx,
+                pos_equal_eps,
+            )
+        };
+
+        for w in points.windows(2) {
+            if let Some(v_data) = create_slice(&w[0], &w[1]) {
+                visitor(v_data);
+            }
+        }
+
+        // slice from last point back around to first point
+        if let Some(v_data) = create_slice(points.last().unwrap(), &points[0]) {
+            visitor(v_data);
+        }
+    }
+
+    /// Determine the location of a slice relative to this shape's area by testing the midpoint of
+    /// the first segment of the slice.
+    fn slice_location(
+        &self,
+        v_data: &PlineViewData<T>,
+        source: &Polyline<T>,
+        coincident_eps: T,
+        query_stack: &mut Vec<usize>,
+    ) -> SliceLocation {
+        let slice_view = v_data.view(source);
+        let v1 = slice_view.at(0);
+        let v2 = slice_view.at(1);
+        let midpoint = seg_midpoint(v1, v2);
+
+        if let Some(boundary_tangent) =
+            self.boundary_tangent_at(midpoint, coincident_eps, query_stack)
+        {
+            let slice_tangent = seg_tangent_vector(v1, v2, midpoint);
+            return if slice_tangent.dot(boundary_tangent) > T::zero() {
+                SliceLocation::CoincidentSameDirection
+            } else {
+                SliceLocation::CoincidentOppositeDirection
+            };
+        }
+
+        if self.winding_number_at(midpoint, query_stack) != 0 {
+            SliceLocation::Inside
+        } else {
+            SliceLocation::Outside
+        }
+    }
+
+    /// Returns the tangent direction of the boundary of this shape at `point` if `point` lies on
+    /// the boundary (within `eps`), otherwise returns `None`.
+    fn boundary_tangent_at(
+        &self,
+        point: Vector2<T>,
+        eps: T,
+        query_stack: &mut Vec<usize>,
+    ) -> Option<Vector2<T>> {
+        let plines_query = self.plines_index.query_with_stack(
+            point.x - eps,
+            point.y - eps,
+            point.x + eps,
+            point.y + eps,
+            query_stack,
+        );
+
+        for i in plines_query {
+            let indexed_pline = self.get_pline(i);
+            let pline = &indexed_pline.polyline;
+            let segs_query = indexed_pline.spatial_index.query_with_stack(
+                point.x - eps,
+                point.y - eps,
+                point.x + eps,
+                point.y + eps,
+                query_stack,
+            );
+
+            for seg_idx in segs_query {
+                let v1 = pline.at(seg_idx);
+                let v2 = pline.at(pline.next_wrapping_index(seg_idx));
+                let closest_point = seg_closest_point(v1, v2, point, eps);
+                if dist_squared(closest_point, point) < eps * eps {
+                    return Some(seg_tangent_vector(v1, v2, closest_point));
+                }
+            }
+        }
+
+        None
+    }
+}
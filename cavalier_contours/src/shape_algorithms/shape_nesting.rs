@@ -0,0 +1,234 @@
+use static_aabb2d_index::AABB;
+
+use crate::{
+    core::traits::Real,
+    polyline::{
+        PlineContainsOptions, PlineContainsResult, PlineSource, PlineSourceMut, Polyline,
+        seg_midpoint,
+    },
+};
+
+use super::{IndexedPolyline, Shape};
+
+/// Nesting information for a polyline of a shape created by [Shape::from_plines_nested].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeLoopNesting {
+    /// Index of the polyline in the input given.
+    pub input_index: usize,
+    /// Nesting depth of the polyline, 0 for outer boundaries, 1 for holes, 2 for islands inside
+    /// holes, etc.
+    pub depth: usize,
+    /// Index of the smallest polyline containing this polyline (shape polyline index).
+    pub parent: Option<usize>,
+    /// Indexes of the polylines directly contained by this polyline (shape polyline indexes).
+    pub children: Vec<usize>,
+}
+
+/// Result of [Shape::from_plines_nested].
+#[derive(Debug, Clone)]
+pub struct NestedShape<T: Real> {
+    /// Shape with polylines oriented by nesting depth (even depths counter clockwise, odd depths
+    /// clockwise).
+    pub shape: Shape<T>,
+    /// Nesting of each shape polyline, index positions correspond to all the counter clockwise
+    /// polylines followed by all the clockwise polylines (same ordering as
+    /// [Shape::plines_index]).
+    pub loops: Vec<ShapeLoopNesting>,
+}
+
+impl<T> NestedShape<T>
+where
+    T: Real,
+{
+    /// Iterate over the shape polyline indexes of the outer boundaries (depth 0).
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.loops
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.parent.is_none())
+            .map(|(i, _)| i)
+    }
+
+    /// Get the polyline at shape polyline index `i`.
+    pub fn pline(&self, i: usize) -> &Polyline<T> {
+        &self.shape.get_pline(i).polyline
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Create a shape from closed polylines of any orientation, nesting is determined by
+    /// containment and orientation is normalized by nesting depth.
+    ///
+    /// Polylines not contained by any other polyline are outer boundaries (counter clockwise),
+    /// polylines directly inside an outer boundary are holes (clockwise), polylines inside a hole
+    /// are islands (counter clockwise), and so on. Open polylines and polylines with zero area are
+    /// skipped. Polylines are assumed to not cross each other (touching is allowed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// // all counter clockwise
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let hole = pline_closed![(2.0, 2.0, 0.0), (8.0, 2.0, 0.0), (8.0, 8.0, 0.0), (2.0, 8.0, 0.0)];
+    /// let island = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+    /// let nested = Shape::from_plines_nested([island, hole, outer]);
+    /// assert_eq!(nested.shape.ccw_plines.len(), 2);
+    /// assert_eq!(nested.shape.cw_plines.len(), 1);
+    /// // outer boundary is the second ccw polyline (input order is kept)
+    /// let outer_nesting = &nested.loops[1];
+    /// assert_eq!(outer_nesting.input_index, 2);
+    /// assert_eq!(outer_nesting.depth, 0);
+    /// // hole is index 2 (first cw polyline)
+    /// assert_eq!(outer_nesting.children, vec![2]);
+    /// assert_eq!(nested.loops[2].depth, 1);
+    /// assert_eq!(nested.loops[2].children, vec![0]);
+    /// assert_eq!(nested.loops[0].depth, 2);
+    /// ```
+    pub fn from_plines_nested<I>(plines: I) -> NestedShape<T>
+    where
+        I: IntoIterator<Item = Polyline<T>>,
+    {
+        let pos_equal_eps = T::from(1e-5).unwrap();
+        let inputs: Vec<(usize, IndexedPolyline<T>, T, AABB<T>)> = plines
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, pline)| {
+                if !pline.is_closed() || pline.vertex_count() < 2 {
+                    return None;
+                }
+
+                let area = pline.area().abs();
+                if area <= pos_equal_eps {
+                    return None;
+                }
+
+                let extents = pline.extents()?;
+                Some((i, IndexedPolyline::new(pline), area, extents))
+            })
+            .collect();
+
+        // process largest to smallest so containing polylines always come first
+        let mut order: Vec<usize> = (0..inputs.len()).collect();
+        order.sort_by(|&a, &b| inputs[b].2.total_cmp(&inputs[a].2));
+
+        let mut parents: Vec<Option<usize>> = vec![None; inputs.len()];
+        let mut depths = vec![0usize; inputs.len()];
+        for (k, &i) in order.iter().enumerate() {
+            let (_, inner, _, inner_extents) = &inputs[i];
+            // smallest containing polyline is the parent (searching back from the smallest)
+            let parent = order[..k].iter().rev().copied().find(|&j| {
+                let (_, outer, _, outer_extents) = &inputs[j];
+                extents_contain(outer_extents, inner_extents, pos_equal_eps)
+                    && is_inside(outer, &inner.polyline, pos_equal_eps)
+            });
+
+            if let Some(p) = parent {
+                parents[i] = Some(p);
+                depths[i] = depths[p] + 1;
+            }
+        }
+
+        // shape polyline index of each input (ccw polylines then cw polylines)
+        let ccw_count = depths.iter().filter(|&&d| d.is_multiple_of(2)).count();
+        let mut shape_indexes = vec![0; inputs.len()];
+        let mut next_ccw = 0;
+        let mut next_cw = ccw_count;
+        for (i, &d) in depths.iter().enumerate() {
+            if d.is_multiple_of(2) {
+                shape_indexes[i] = next_ccw;
+                next_ccw += 1;
+            } else {
+                shape_indexes[i] = next_cw;
+                next_cw += 1;
+            }
+        }
+
+        let mut loops = vec![
+            ShapeLoopNesting {
+                input_index: 0,
+                depth: 0,
+                parent: None,
+                children: Vec::new(),
+            };
+            inputs.len()
+        ];
+
+        for (i, (input_index, _, _, _)) in inputs.iter().enumerate() {
+            let l = &mut loops[shape_indexes[i]];
+            l.input_index = *input_index;
+            l.depth = depths[i];
+            l.parent = parents[i].map(|p| shape_indexes[p]);
+        }
+
+        for i in 0..inputs.len() {
+            if let Some(p) = loops[i].parent {
+                loops[p].children.push(i);
+            }
+        }
+
+        let mut ccw_plines = Vec::with_capacity(ccw_count);
+        let mut cw_plines = Vec::with_capacity(inputs.len() - ccw_count);
+        for (i, (_, indexed_pline, _, _)) in inputs.into_iter().enumerate() {
+            let mut pline = indexed_pline.polyline;
+            let is_ccw = depths[i].is_multiple_of(2);
+            if (pline.area() > T::zero()) != is_ccw {
+                pline.invert_direction_mut();
+            }
+
+            if is_ccw {
+                ccw_plines.push(pline);
+            } else {
+                cw_plines.push(pline);
+            }
+        }
+
+        NestedShape {
+            shape: Shape::from_plines(ccw_plines.into_iter().chain(cw_plines)),
+            loops,
+        }
+    }
+}
+
+/// Returns true if `outer` bounding box contains `inner` bounding box (fuzzy by `eps`).
+fn extents_contain<T>(outer: &AABB<T>, inner: &AABB<T>, eps: T) -> bool
+where
+    T: Real,
+{
+    outer.min_x <= inner.min_x + eps
+        && outer.min_y <= inner.min_y + eps
+        && outer.max_x + eps >= inner.max_x
+        && outer.max_y + eps >= inner.max_y
+}
+
+/// Returns true if `inner` lies inside of `outer`. Polylines that touch are tested using a point
+/// on `inner` that does not lie on `outer`.
+fn is_inside<T>(outer: &IndexedPolyline<T>, inner: &Polyline<T>, pos_equal_eps: T) -> bool
+where
+    T: Real,
+{
+    let options = PlineContainsOptions {
+        pline1_aabb_index: Some(&outer.spatial_index),
+        pos_equal_eps,
+    };
+
+    match outer.polyline.contains_opt(inner, &options) {
+        PlineContainsResult::Pline2InsidePline1 => true,
+        PlineContainsResult::Intersected => inner
+            .iter_segments()
+            .flat_map(|(v1, v2)| [v1.pos(), seg_midpoint(v1, v2)])
+            .find(|&pt| {
+                outer
+                    .polyline
+                    .closest_point(pt, pos_equal_eps)
+                    .is_some_and(|c| c.distance > pos_equal_eps)
+            })
+            .is_some_and(|pt| outer.polyline.winding_number(pt) != 0),
+        _ => false,
+    }
+}
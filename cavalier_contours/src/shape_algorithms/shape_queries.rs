@@ -0,0 +1,397 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use static_aabb2d_index::{AABB, Control};
+
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{PlineSource, internal::pline_closest_point::closest_point_with_index},
+};
+
+use super::Shape;
+
+/// Result from calling [Shape::closest_point].
+#[derive(Debug, Copy, Clone)]
+pub struct ShapeClosestPointResult<T = f64>
+where
+    T: Real,
+{
+    /// Index of the closest polyline in the shape, counter clockwise polylines followed by
+    /// clockwise polylines (same ordering as [Shape::plines_index]).
+    pub pline_index: usize,
+    /// The start vertex index of the closest segment.
+    pub seg_start_index: usize,
+    /// The closest point on the closest segment.
+    pub seg_point: Vector2<T>,
+    /// The distance between the points.
+    pub distance: T,
+}
+
+/// Result from calling [Shape::max_inscribed_circle].
+#[derive(Debug, Copy, Clone)]
+pub struct MaxInscribedCircleResult<T = f64>
+where
+    T: Real,
+{
+    /// Center of the circle.
+    pub center: Vector2<T>,
+    /// Radius of the circle (distance from the center to the closest polyline in the shape).
+    pub radius: T,
+}
+
+/// Struct to hold options parameters when calling [Shape::max_inscribed_circle_opt].
+#[derive(Debug, Clone)]
+pub struct ShapeInscribedCircleOptions<T> {
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> ShapeInscribedCircleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for ShapeInscribedCircleOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Square cell searched when finding the max inscribed circle, ordered by the largest possible
+/// distance to the boundary of any point inside the cell (max heap).
+#[derive(Clone, Copy)]
+struct InscribedCircleCell<T> {
+    center: Vector2<T>,
+    half_size: T,
+    /// Signed distance from the cell center to the boundary (negative if outside the shape).
+    dist: T,
+    /// Upper bound on the signed distance of any point in the cell.
+    max_dist: T,
+}
+
+impl<T: Real> Ord for InscribedCircleCell<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_dist.total_cmp(&other.max_dist)
+    }
+}
+
+impl<T: Real> PartialOrd for InscribedCircleCell<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Real> PartialEq for InscribedCircleCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Real> Eq for InscribedCircleCell<T> {}
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Total signed area of the shape (counter clockwise polyline areas minus clockwise polyline
+    /// hole areas).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let hole = pline_closed![(2.0, 2.0, 0.0), (2.0, 4.0, 0.0), (4.0, 4.0, 0.0), (4.0, 2.0, 0.0)];
+    /// let shape = Shape::from_plines([outer, hole]);
+    /// assert_eq!(shape.area(), 96.0);
+    /// ```
+    pub fn area(&self) -> T {
+        self.ccw_plines
+            .iter()
+            .chain(self.cw_plines.iter())
+            .fold(T::zero(), |acc, p| acc + p.polyline.area())
+    }
+
+    /// Compute the bounding box extents of the shape, returns `None` if the shape is empty.
+    ///
+    /// Unlike the bounds of [Shape::plines_index] (which may be larger than the actual extents for
+    /// arcs) this is the exact bounding box.
+    pub fn extents(&self) -> Option<AABB<T>> {
+        let mut all_extents = self
+            .ccw_plines
+            .iter()
+            .chain(self.cw_plines.iter())
+            .filter_map(|p| p.polyline.extents());
+
+        let mut result = all_extents.next()?;
+        for e in all_extents {
+            if e.min_x < result.min_x {
+                result.min_x = e.min_x;
+            }
+            if e.min_y < result.min_y {
+                result.min_y = e.min_y;
+            }
+            if e.max_x > result.max_x {
+                result.max_x = e.max_x;
+            }
+            if e.max_y > result.max_y {
+                result.max_y = e.max_y;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Total winding number of all the polylines in the shape around `point`.
+    ///
+    /// Only polylines whose bounding box contains `point` are tested. Counter clockwise polylines
+    /// add +1 and clockwise polylines add -1 for each winding, so points inside a hole have a
+    /// winding number of 0. If `point` lies on a polyline then the winding number is not well
+    /// defined.
+    pub fn winding_number(&self, point: Vector2<T>) -> i32 {
+        let mut query_stack = Vec::new();
+        self.winding_number_at(point, &mut query_stack)
+    }
+
+    /// Returns true if `point` is inside the shape area (non-zero [Shape::winding_number]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let hole = pline_closed![(2.0, 2.0, 0.0), (2.0, 4.0, 0.0), (4.0, 4.0, 0.0), (4.0, 2.0, 0.0)];
+    /// let shape = Shape::from_plines([outer, hole]);
+    /// assert!(shape.contains_point(Vector2::new(5.0, 5.0)));
+    /// // inside hole
+    /// assert!(!shape.contains_point(Vector2::new(3.0, 3.0)));
+    /// assert!(!shape.contains_point(Vector2::new(11.0, 5.0)));
+    /// ```
+    #[inline]
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        self.winding_number(point) != 0
+    }
+
+    /// Find the closest point on any polyline in the shape to `point`, returns `None` if the shape
+    /// is empty.
+    ///
+    /// Polylines (and the segments of each polyline) are visited in order of bounding box distance
+    /// to `point` and visiting stops once no remaining bounding box can contain a closer point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+    /// let hole = pline_closed![(2.0, 2.0, 0.0), (2.0, 4.0, 0.0), (4.0, 4.0, 0.0), (4.0, 2.0, 0.0)];
+    /// let shape = Shape::from_plines([outer, hole]);
+    /// let result = shape.closest_point(Vector2::new(5.0, 3.0), 1e-5).unwrap();
+    /// // closest to the hole polyline
+    /// assert_eq!(result.pline_index, 1);
+    /// assert_eq!(result.seg_point, Vector2::new(4.0, 3.0));
+    /// assert_eq!(result.distance, 1.0);
+    /// ```
+    pub fn closest_point(
+        &self,
+        point: Vector2<T>,
+        pos_equal_eps: T,
+    ) -> Option<ShapeClosestPointResult<T>> {
+        let mut result: Option<ShapeClosestPointResult<T>> = None;
+        let mut visitor = |i: usize, box_dist_squared: T| {
+            if let Some(r) = &result
+                && box_dist_squared > r.distance * r.distance
+            {
+                // all remaining polylines are further away
+                return Control::Break(());
+            }
+
+            let indexed_pline = self.get_pline(i);
+            if let Some(closest) = closest_point_with_index(
+                &indexed_pline.polyline,
+                &indexed_pline.spatial_index,
+                point,
+                pos_equal_eps,
+            ) && result
+                .as_ref()
+                .is_none_or(|r| closest.distance < r.distance)
+            {
+                result = Some(ShapeClosestPointResult {
+                    pline_index: i,
+                    seg_start_index: closest.seg_start_index,
+                    seg_point: closest.seg_point,
+                    distance: closest.distance,
+                });
+            }
+
+            Control::Continue
+        };
+
+        self.plines_index
+            .visit_neighbors(point.x, point.y, &mut visitor);
+
+        result
+    }
+
+    /// Find the largest circle that fits inside the shape area (not crossing any polyline and not
+    /// inside any hole), returns `None` if the shape has no area or `tolerance` is not positive.
+    ///
+    /// The radius found is within `tolerance` of the largest possible radius. The search
+    /// subdivides the shape extents into square cells visited in order of the largest distance any
+    /// point in the cell could have to the boundary, cells that cannot hold a circle larger than
+    /// the best found (plus `tolerance`) are discarded. Distances are found using the spatial
+    /// indexes of the shape (see [Shape::closest_point]).
+    ///
+    /// This is useful to check if a tool of some radius fits anywhere in a region without
+    /// repeatedly offsetting the shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// let rectangle = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// let shape = Shape::from_plines([rectangle]);
+    /// let circle = shape.max_inscribed_circle(1e-4).unwrap();
+    /// assert!(circle.radius.fuzzy_eq_eps(2.0, 1e-4));
+    /// assert!(circle.center.y.fuzzy_eq_eps(2.0, 1e-4));
+    /// ```
+    pub fn max_inscribed_circle(&self, tolerance: T) -> Option<MaxInscribedCircleResult<T>> {
+        self.max_inscribed_circle_opt(tolerance, &Default::default())
+    }
+
+    /// Find the largest circle that fits inside the shape area with options provided.
+    ///
+    /// See [Shape::max_inscribed_circle] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::core::traits::FuzzyEq;
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::shape_algorithms::{Shape, ShapeInscribedCircleOptions};
+    /// let rectangle = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+    /// let shape = Shape::from_plines([rectangle]);
+    /// let options = ShapeInscribedCircleOptions {
+    ///     pos_equal_eps: 1e-8,
+    ///     ..Default::default()
+    /// };
+    /// let circle = shape.max_inscribed_circle_opt(1e-4, &options).unwrap();
+    /// assert!(circle.radius.fuzzy_eq_eps(2.0, 1e-4));
+    /// ```
+    pub fn max_inscribed_circle_opt(
+        &self,
+        tolerance: T,
+        options: &ShapeInscribedCircleOptions<T>,
+    ) -> Option<MaxInscribedCircleResult<T>> {
+        if tolerance <= T::zero() {
+            return None;
+        }
+
+        let extents = self.extents()?;
+        let width = extents.max_x - extents.min_x;
+        let height = extents.max_y - extents.min_y;
+        let cell_size = if width < height { width } else { height };
+        if cell_size <= T::zero() {
+            return None;
+        }
+
+        let pos_equal_eps = options.pos_equal_eps;
+        let mut query_stack = Vec::new();
+        let mut create_cell = |center: Vector2<T>, half_size: T| {
+            let distance = self
+                .closest_point(center, pos_equal_eps)
+                .map_or(T::zero(), |r| r.distance);
+            let dist = if self.winding_number_at(center, &mut query_stack) != 0 {
+                distance
+            } else {
+                -distance
+            };
+            InscribedCircleCell {
+                center,
+                half_size,
+                dist,
+                max_dist: dist + half_size * T::two().sqrt(),
+            }
+        };
+
+        // cover the extents with square cells
+        let half_size = cell_size / T::two();
+        let mut heap = BinaryHeap::new();
+        let mut x = extents.min_x;
+        while x < extents.max_x {
+            let mut y = extents.min_y;
+            while y < extents.max_y {
+                heap.push(create_cell(
+                    Vector2::new(x + half_size, y + half_size),
+                    half_size,
+                ));
+                y = y + cell_size;
+            }
+            x = x + cell_size;
+        }
+
+        let mut best = create_cell(
+            Vector2::new(
+                (extents.min_x + extents.max_x) / T::two(),
+                (extents.min_y + extents.max_y) / T::two(),
+            ),
+            T::zero(),
+        );
+
+        while let Some(cell) = heap.pop() {
+            if cell.dist > best.dist {
+                best = cell;
+            }
+
+            if cell.max_dist - best.dist <= tolerance {
+                // cells are visited in order of max distance so no remaining cell can hold a
+                // larger circle
+                break;
+            }
+
+            let half_size = cell.half_size / T::two();
+            for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                let offset = Vector2::new(T::from(dx).unwrap(), T::from(dy).unwrap());
+                heap.push(create_cell(
+                    cell.center + offset.scale(half_size),
+                    half_size,
+                ));
+            }
+        }
+
+        if best.dist <= T::zero() {
+            return None;
+        }
+
+        Some(MaxInscribedCircleResult {
+            center: best.center,
+            radius: best.dist,
+        })
+    }
+
+    /// Total winding number of all the polylines in this shape around `point` (only polylines
+    /// whose bounding box contains `point` are tested).
+    pub(crate) fn winding_number_at(&self, point: Vector2<T>, query_stack: &mut Vec<usize>) -> i32 {
+        self.plines_index
+            .query_with_stack(point.x, point.y, point.x, point.y, query_stack)
+            .into_iter()
+            .map(|i| self.get_pline(i).polyline.winding_number(point))
+            .sum()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    core::{
+        math::{Affine2, Vector2},
+        traits::Real,
+    },
+    polyline::{PlineSource, PlineSourceMut, Polyline, TransformError},
+};
+
+use super::Shape;
+
+impl<T> Shape<T>
+where
+    T: Real,
+{
+    /// Translate all the polylines of the shape (mutably) by `x` and `y`, spatial indexes are
+    /// rebuilt.
+    pub fn translate_mut(&mut self, x: T, y: T) {
+        self.modify_plines(|pline| pline.translate_mut(x, y));
+    }
+
+    /// Uniformly scale all the polylines of the shape (mutably) by `scale_factor`, spatial indexes
+    /// are rebuilt.
+    pub fn scale_mut(&mut self, scale_factor: T) {
+        self.modify_plines(|pline| pline.scale_mut(scale_factor));
+    }
+
+    /// Rotate all the polylines of the shape (mutably) counter clockwise about `origin` by
+    /// `angle` in radians, spatial indexes are rebuilt.
+    pub fn rotate_mut(&mut self, angle: T, origin: Vector2<T>) {
+        self.modify_plines(|pline| pline.rotate_mut(angle, origin));
+    }
+
+    /// Mirror all the polylines of the shape (mutably) across the line passing through `point` in
+    /// the `direction` given, spatial indexes are rebuilt.
+    ///
+    /// Polyline directions are inverted after mirroring so counter clockwise polylines remain
+    /// counter clockwise and clockwise polylines remain clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::core::math::Vector2;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// let square = pline_closed![(1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+    /// let mut shape = Shape::from_plines([square]);
+    /// shape.mirror_mut(Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0));
+    /// assert_eq!(shape.ccw_plines.len(), 1);
+    /// assert_eq!(shape.ccw_plines[0].polyline.area(), 1.0);
+    /// assert!(shape.contains_point(Vector2::new(-1.5, 0.5)));
+    /// ```
+    pub fn mirror_mut(&mut self, point: Vector2<T>, direction: Vector2<T>) {
+        self.modify_plines(|pline| {
+            pline.mirror_mut(point, direction);
+            pline.invert_direction_mut();
+        });
+    }
+
+    /// Apply an affine `transform` to all the polylines of the shape (mutably), spatial indexes
+    /// are rebuilt.
+    ///
+    /// Same rules as [PlineSourceMut::transform_mut] apply, if any polyline has arcs and the
+    /// transform is not a similarity then [TransformError::NonUniformScaleWithArcs] is returned
+    /// and the shape is left unchanged. If the transform reflects then polyline directions are
+    /// inverted so orientations are kept.
+    pub fn transform_mut(&mut self, transform: &Affine2<T>) -> Result<(), TransformError> {
+        if !transform.is_similarity()
+            && self
+                .ccw_plines
+                .iter()
+                .chain(self.cw_plines.iter())
+                .any(|p| p.polyline.iter_vertexes().any(|v| !v.bulge_is_zero()))
+        {
+            return Err(TransformError::NonUniformScaleWithArcs);
+        }
+
+        let reflects = transform.determinant() < T::zero();
+        self.modify_plines(|pline| {
+            pline
+                .transform_mut(transform)
+                .expect("arcs checked against transform");
+            if reflects {
+                pline.invert_direction_mut();
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Apply `f` to every polyline of the shape then rebuild the spatial indexes.
+    fn modify_plines<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Polyline<T>),
+    {
+        let plines = std::mem::take(&mut self.ccw_plines)
+            .into_iter()
+            .chain(std::mem::take(&mut self.cw_plines))
+            .map(|p| {
+                let mut pline = p.polyline;
+                f(&mut pline);
+                pline
+            });
+
+        *self = Shape::from_plines(plines);
+    }
+}
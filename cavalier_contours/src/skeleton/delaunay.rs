@@ -0,0 +1,357 @@
+//! Incremental (Bowyer-Watson) Delaunay triangulation of a point set, used to approximate the
+//! Voronoi diagram of boundary samples.
+use crate::core::{math::Vector2, traits::Real};
+
+/// Marker for no neighbor triangle.
+pub(crate) const NO_NEIGHBOR: usize = usize::MAX;
+
+/// Delaunay triangulation of a point set.
+#[derive(Debug, Clone)]
+pub(crate) struct Triangulation {
+    /// Point indexes of each triangle in counter clockwise order.
+    pub triangles: Vec<[usize; 3]>,
+    /// Neighbor triangle indexes of each triangle, the neighbor at position `k` is across the edge
+    /// opposite point `k` ([NO_NEIGHBOR] if on the convex hull).
+    pub neighbors: Vec<[usize; 3]>,
+}
+
+/// Signed area (times 2) of the triangle `a`, `b`, `c`, positive if counter clockwise.
+#[inline]
+fn orient<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> T
+where
+    T: Real,
+{
+    (b - a).perp_dot(c - a)
+}
+
+/// Positive if `p` lies inside the circumcircle of the counter clockwise triangle `a`, `b`, `c`.
+#[inline]
+fn in_circle<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>, p: Vector2<T>) -> T
+where
+    T: Real,
+{
+    let ad = a - p;
+    let bd = b - p;
+    let cd = c - p;
+    ad.length_squared() * bd.perp_dot(cd)
+        + bd.length_squared() * cd.perp_dot(ad)
+        + cd.length_squared() * ad.perp_dot(bd)
+}
+
+struct Builder<'a, T> {
+    points: &'a [Vector2<T>],
+    /// Vertexes of the enclosing super triangle.
+    super_points: [Vector2<T>; 3],
+    triangles: Vec<[usize; 3]>,
+    neighbors: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    /// Insertion stamp of the cavity a triangle was last added to (or excluded from).
+    cavity_stamp: Vec<usize>,
+    excluded_stamp: Vec<usize>,
+    last_triangle: usize,
+}
+
+impl<T> Builder<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn point(&self, i: usize) -> Vector2<T> {
+        if i < self.points.len() {
+            self.points[i]
+        } else {
+            self.super_points[i - self.points.len()]
+        }
+    }
+
+    fn contains(&self, t: usize, p: Vector2<T>) -> Option<usize> {
+        let [a, b, c] = self.triangles[t];
+        let (a, b, c) = (self.point(a), self.point(b), self.point(c));
+        // returns the edge (by opposite vertex position) the point is outside of
+        if orient(b, c, p) < T::zero() {
+            Some(0)
+        } else if orient(c, a, p) < T::zero() {
+            Some(1)
+        } else if orient(a, b, p) < T::zero() {
+            Some(2)
+        } else {
+            None
+        }
+    }
+
+    fn locate(&self, p: Vector2<T>) -> Option<usize> {
+        let mut t = self.last_triangle;
+        for _ in 0..self.triangles.len() {
+            match self.contains(t, p) {
+                None => return Some(t),
+                Some(k) => {
+                    let n = self.neighbors[t][k];
+                    if n == NO_NEIGHBOR {
+                        break;
+                    }
+                    t = n;
+                }
+            }
+        }
+
+        // walk failed (should only happen due to round off), fall back to checking all triangles
+        (0..self.triangles.len()).find(|&t| self.alive[t] && self.contains(t, p).is_none())
+    }
+
+    fn in_cavity(&self, t: usize, stamp: usize) -> bool {
+        self.cavity_stamp[t] == stamp && self.excluded_stamp[t] != stamp
+    }
+
+    fn insert(&mut self, pi: usize, stamp: usize, pos_equal_eps: T) {
+        let p = self.points[pi];
+        let Some(start) = self.locate(p) else {
+            return;
+        };
+
+        if self.triangles[start]
+            .iter()
+            .any(|&v| self.point(v).fuzzy_eq_eps(p, pos_equal_eps))
+        {
+            // repeat position, skip
+            return;
+        }
+
+        // collect all triangles whose circumcircle contains the point (connected to the starting
+        // triangle so the cavity is always connected)
+        let mut cavity = vec![start];
+        self.cavity_stamp[start] = stamp;
+        let mut i = 0;
+        while i < cavity.len() {
+            let t = cavity[i];
+            i += 1;
+            for n in self.neighbors[t] {
+                if n == NO_NEIGHBOR || self.cavity_stamp[n] == stamp {
+                    continue;
+                }
+                let [a, b, c] = self.triangles[n];
+                if in_circle(self.point(a), self.point(b), self.point(c), p) > T::zero() {
+                    self.cavity_stamp[n] = stamp;
+                    cavity.push(n);
+                }
+            }
+        }
+
+        // round off in the in circle test may create a cavity that is not star shaped around the
+        // point, remove triangles until every boundary edge faces the point
+        let mut boundary = Vec::new();
+        loop {
+            boundary.clear();
+            let mut invalid = None;
+            for &t in cavity.iter().filter(|&&t| self.in_cavity(t, stamp)) {
+                for k in 0..3 {
+                    let n = self.neighbors[t][k];
+                    if n != NO_NEIGHBOR && self.in_cavity(n, stamp) {
+                        continue;
+                    }
+                    let a = self.triangles[t][(k + 1) % 3];
+                    let b = self.triangles[t][(k + 2) % 3];
+                    if orient(self.point(a), self.point(b), p) <= T::zero() {
+                        invalid = Some((t, n));
+                        break;
+                    }
+                    boundary.push((a, b, n, t));
+                }
+                if invalid.is_some() {
+                    break;
+                }
+            }
+
+            match invalid {
+                None => break,
+                Some((t, n)) if t == start => {
+                    // point lies on an edge of the starting triangle, the neighbor must be part
+                    // of the cavity
+                    if n == NO_NEIGHBOR || self.excluded_stamp[n] == stamp {
+                        return;
+                    }
+                    self.cavity_stamp[n] = stamp;
+                    cavity.push(n);
+                }
+                Some((t, _)) => {
+                    self.excluded_stamp[t] = stamp;
+                }
+            }
+        }
+
+        // replace the cavity triangles with triangles fanned around the point, reusing the slots
+        // of the removed triangles
+        let mut free_slots = cavity
+            .iter()
+            .copied()
+            .filter(|&t| self.in_cavity(t, stamp))
+            .collect::<Vec<_>>();
+        for &t in free_slots.iter() {
+            self.alive[t] = false;
+        }
+
+        let mut new_triangles = Vec::with_capacity(boundary.len());
+        for &(a, b, outside, _) in boundary.iter() {
+            let t = if let Some(t) = free_slots.pop() {
+                self.triangles[t] = [a, b, pi];
+                self.neighbors[t] = [NO_NEIGHBOR, NO_NEIGHBOR, outside];
+                self.alive[t] = true;
+                t
+            } else {
+                self.triangles.push([a, b, pi]);
+                self.neighbors.push([NO_NEIGHBOR, NO_NEIGHBOR, outside]);
+                self.alive.push(true);
+                self.cavity_stamp.push(0);
+                self.excluded_stamp.push(0);
+                self.triangles.len() - 1
+            };
+
+            if outside != NO_NEIGHBOR {
+                // update the outside triangle to point at the new triangle
+                let o = self.triangles[outside];
+                for k in 0..3 {
+                    if o[(k + 1) % 3] == b && o[(k + 2) % 3] == a {
+                        self.neighbors[outside][k] = t;
+                    }
+                }
+            }
+
+            new_triangles.push(t);
+        }
+
+        // link the new triangles to each other, triangle (a, b, p) shares edge (b, p) with the
+        // triangle starting at b and edge (p, a) with the triangle ending at a
+        for (i, &(a, b, _, _)) in boundary.iter().enumerate() {
+            let t = new_triangles[i];
+            for (j, &(a2, b2, _, _)) in boundary.iter().enumerate() {
+                if a2 == b {
+                    self.neighbors[t][0] = new_triangles[j];
+                }
+                if b2 == a {
+                    self.neighbors[t][1] = new_triangles[j];
+                }
+            }
+        }
+
+        self.last_triangle = new_triangles[0];
+    }
+}
+
+/// Create the Delaunay triangulation of `points`. Points within `pos_equal_eps` of an already
+/// inserted point are skipped.
+pub(crate) fn triangulate<T>(points: &[Vector2<T>], pos_equal_eps: T) -> Triangulation
+where
+    T: Real,
+{
+    if points.len() < 3 {
+        return Triangulation {
+            triangles: Vec::new(),
+            neighbors: Vec::new(),
+        };
+    }
+
+    let (mut min_x, mut min_y, mut max_x, mut max_y) =
+        (points[0].x, points[0].y, points[0].x, points[0].y);
+    for p in points.iter() {
+        if p.x < min_x {
+            min_x = p.x;
+        }
+        if p.y < min_y {
+            min_y = p.y;
+        }
+        if p.x > max_x {
+            max_x = p.x;
+        }
+        if p.y > max_y {
+            max_y = p.y;
+        }
+    }
+
+    let size = {
+        let w = max_x - min_x;
+        let h = max_y - min_y;
+        let s = if w > h { w } else { h };
+        if s > T::zero() { s } else { T::one() }
+    };
+    let center = Vector2::new((min_x + max_x) / T::two(), (min_y + max_y) / T::two());
+    let scale = T::from(100.0).unwrap() * size;
+    let super_points = [
+        center + Vector2::new(-scale, -scale),
+        center + Vector2::new(scale, -scale),
+        center + Vector2::new(T::zero(), scale),
+    ];
+
+    let n = points.len();
+    let mut builder = Builder {
+        points,
+        super_points,
+        triangles: vec![[n, n + 1, n + 2]],
+        neighbors: vec![[NO_NEIGHBOR; 3]],
+        alive: vec![true],
+        cavity_stamp: vec![0],
+        excluded_stamp: vec![0],
+        last_triangle: 0,
+    };
+
+    // insert in a shuffled order, inserting boundary points in order creates long thin triangles
+    // and large cavities on every insert, walking from the last triangle keeps locating cheap
+    let mut order = (0..n).collect::<Vec<_>>();
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    for i in (1..n).rev() {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        order.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+
+    for (stamp, &i) in order.iter().enumerate() {
+        builder.insert(i, stamp + 1, pos_equal_eps);
+    }
+
+    // keep only the triangles not connected to the super triangle and remap neighbor indexes
+    let mut new_index = vec![NO_NEIGHBOR; builder.triangles.len()];
+    let mut triangles = Vec::new();
+    for (t, tri) in builder.triangles.iter().enumerate() {
+        if builder.alive[t] && tri.iter().all(|&v| v < n) {
+            new_index[t] = triangles.len();
+            triangles.push(*tri);
+        }
+    }
+
+    let neighbors = (0..builder.triangles.len())
+        .filter(|&t| new_index[t] != NO_NEIGHBOR)
+        .map(|t| {
+            builder.neighbors[t].map(|nb| {
+                if nb == NO_NEIGHBOR {
+                    NO_NEIGHBOR
+                } else {
+                    new_index[nb]
+                }
+            })
+        })
+        .collect();
+
+    Triangulation {
+        triangles,
+        neighbors,
+    }
+}
+
+/// Center of the circle passing through `a`, `b`, and `c`, returns `None` if the points are
+/// collinear.
+pub(crate) fn circumcenter<T>(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> Option<Vector2<T>>
+where
+    T: Real,
+{
+    let ab = b - a;
+    let ac = c - a;
+    let d = T::two() * ab.perp_dot(ac);
+    if d == T::zero() {
+        return None;
+    }
+    let ab_len2 = ab.length_squared();
+    let ac_len2 = ac.length_squared();
+    let x = (ac.y * ab_len2 - ab.y * ac_len2) / d;
+    let y = (ab.x * ac_len2 - ac.x * ab_len2) / d;
+    Some(a + Vector2::new(x, y))
+}
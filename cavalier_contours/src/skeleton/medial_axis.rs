@@ -0,0 +1,505 @@
+use std::ops::Range;
+
+use crate::{
+    core::{
+        math::{Vector2, angle, angle_from_bulge, point_on_circle},
+        traits::Real,
+    },
+    polyline::{
+        PlineCreation, PlineSource, PlineSourceMut, Polyline, seg_arc_radius_and_center,
+        seg_length, seg_tangent_vector,
+    },
+    shape_algorithms::Shape,
+};
+
+use super::delaunay::{NO_NEIGHBOR, circumcenter, triangulate};
+
+/// Struct to hold options parameters when computing a medial axis.
+#[derive(Debug, Clone)]
+pub struct MedialAxisOptions<T> {
+    /// Approximate maximum distance between the medial axis returned and the exact medial axis.
+    /// The boundary is sampled with a spacing of 4 times the tolerance so the time taken grows as
+    /// the tolerance gets smaller relative to the size of the boundary.
+    pub tolerance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> MedialAxisOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            tolerance: T::from(1e-2).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for MedialAxisOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Point on a medial axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MedialAxisPoint<T> {
+    /// Position of the point.
+    pub pos: Vector2<T>,
+    /// Clearance radius at the point (distance to the closest boundary), this is the radius of the
+    /// largest circle centered at the point that fits inside the region.
+    pub radius: T,
+}
+
+/// Edge of a medial axis.
+#[derive(Debug, Clone)]
+pub struct MedialAxisEdge<T> {
+    /// Points along the edge, the edge is made up of straight lines between the points and the
+    /// clearance radius may be linearly interpolated between points. Holds a single point if the
+    /// medial axis degenerates to a point (e.g. for a circle).
+    pub points: Vec<MedialAxisPoint<T>>,
+    /// Whether the edge forms a closed loop (last point connects back to the first point), e.g.
+    /// the medial axis around a hole.
+    pub is_closed: bool,
+}
+
+impl<T> MedialAxisEdge<T>
+where
+    T: Real,
+{
+    /// Create a polyline of line segments from the edge points.
+    pub fn to_pline(&self) -> Polyline<T> {
+        let mut pline = Polyline::with_capacity(self.points.len(), self.is_closed);
+        for p in self.points.iter() {
+            pline.add(p.pos.x, p.pos.y, T::zero());
+        }
+        pline
+    }
+}
+
+/// Medial axis of a region, see [pline_medial_axis] and [shape_medial_axis].
+#[derive(Debug, Clone)]
+pub struct MedialAxis<T> {
+    /// Edges of the medial axis, edges start and end at branch points (where 3 or more edges
+    /// meet) or at the ends of the medial axis. Edges reaching a convex corner of the boundary end
+    /// at the corner with a clearance radius of zero.
+    pub edges: Vec<MedialAxisEdge<T>>,
+}
+
+impl<T> MedialAxis<T> {
+    /// Return an empty medial axis (no edges).
+    #[inline]
+    pub fn empty() -> Self {
+        Self { edges: Vec::new() }
+    }
+}
+
+/// Compute the medial axis of the region inside the closed polyline `pline` (orientation does not
+/// matter). Returns an empty medial axis if the polyline is open or has less than 2 vertexes.
+///
+/// See [shape_medial_axis] for details.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::core::traits::*;
+/// # use cavalier_contours::pline_closed;
+/// # use cavalier_contours::skeleton::*;
+/// let rectangle = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 4.0, 0.0), (0.0, 4.0, 0.0)];
+/// let axis = pline_medial_axis(&rectangle, &MedialAxisOptions::new());
+/// // center line from (2, 2) to (8, 2) plus a branch to each corner
+/// assert_eq!(axis.edges.len(), 5);
+/// let center_line = axis.edges.iter().find(|e| e.points.iter().all(|p| p.radius > 1.0)).unwrap();
+/// for p in center_line.points.iter() {
+///     assert!(p.pos.y.fuzzy_eq_eps(2.0, 1e-2));
+///     assert!(p.radius.fuzzy_eq_eps(2.0, 1e-2));
+/// }
+/// ```
+pub fn pline_medial_axis<P, T>(pline: &P, options: &MedialAxisOptions<T>) -> MedialAxis<T>
+where
+    P: PlineSource<Num = T> + ?Sized,
+    T: Real,
+{
+    if !pline.is_closed() || pline.vertex_count() < 2 {
+        return MedialAxis::empty();
+    }
+
+    let mut pline = Polyline::create_from(pline);
+    if pline.area() < T::zero() {
+        pline.invert_direction_mut();
+    }
+
+    shape_medial_axis(&Shape::from_plines([pline]), options)
+}
+
+/// Compute the medial axis of the area of `shape` (inside the counter clockwise polylines and
+/// outside the clockwise hole polylines).
+///
+/// The medial axis is the set of centers of all circles inside the region that touch the boundary
+/// at two or more points (or are the largest circle at a corner), each point on the medial axis
+/// has a clearance radius (distance to the closest boundary). Arc segments of the boundary are
+/// handled directly, the clearance radius is computed exactly from the boundary at every point
+/// returned.
+///
+/// The medial axis is found from the Voronoi diagram (dual of the Delaunay triangulation) of
+/// points sampled along the boundary, Voronoi edges between samples which are not adjacent on the
+/// boundary approximate the medial axis. Edges are simplified within
+/// [MedialAxisOptions::tolerance] (including the clearance radius).
+pub fn shape_medial_axis<T>(shape: &Shape<T>, options: &MedialAxisOptions<T>) -> MedialAxis<T>
+where
+    T: Real,
+{
+    let pos_equal_eps = options.pos_equal_eps;
+    let tolerance = options.tolerance;
+    if tolerance <= T::zero() {
+        return MedialAxis::empty();
+    }
+
+    let (samples, loop_ranges) = sample_boundary(shape, T::four() * tolerance, pos_equal_eps);
+    let positions = samples.iter().map(|s| s.pos).collect::<Vec<_>>();
+    let triangulation = triangulate(&positions, pos_equal_eps);
+
+    // medial axis node for each triangle (circumcenter) if it lies on the medial axis, convex
+    // corner nodes are appended after
+    let mut nodes = triangulation
+        .triangles
+        .iter()
+        .map(|tri| {
+            let [a, b, c] = tri.map(|i| positions[i]);
+            let center = circumcenter(a, b, c)?;
+            let circle_radius = (a - center).length();
+            let radius = shape.closest_point(center, pos_equal_eps)?.distance;
+            // a closer boundary than the samples indicate means the circle is not inside the
+            // region (only happens due to round off in the triangulation)
+            if radius < circle_radius - T::two() * tolerance || !shape.contains_point(center) {
+                return None;
+            }
+            Some(MedialAxisPoint {
+                pos: center,
+                radius,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let is_adjacent = |s1: usize, s2: usize| {
+        let loop_range = &loop_ranges[samples[s1].loop_idx];
+        if samples[s2].loop_idx != samples[s1].loop_idx {
+            return false;
+        }
+        let next = |s: usize| {
+            if s + 1 == loop_range.end {
+                loop_range.start
+            } else {
+                s + 1
+            }
+        };
+        next(s1) == s2 || next(s2) == s1
+    };
+
+    // voronoi edges (between neighboring triangles) not separating adjacent samples
+    let mut edges = Vec::new();
+    for (t, tri) in triangulation.triangles.iter().enumerate() {
+        if nodes[t].is_none() {
+            continue;
+        }
+        for k in 0..3 {
+            let n = triangulation.neighbors[t][k];
+            if n == NO_NEIGHBOR || n < t || nodes[n].is_none() {
+                continue;
+            }
+            if !is_adjacent(tri[(k + 1) % 3], tri[(k + 2) % 3]) {
+                edges.push((t, n));
+            }
+        }
+    }
+
+    // extend to convex corners from the triangle formed by the corner and its adjacent samples
+    let mut corner_triangles = vec![Vec::new(); samples.len()];
+    for (t, tri) in triangulation.triangles.iter().enumerate() {
+        for &s in tri.iter().filter(|&&s| samples[s].is_convex_corner) {
+            corner_triangles[s].push(t);
+        }
+    }
+    for (s, triangles) in corner_triangles.iter().enumerate() {
+        for &t in triangles.iter() {
+            let tri = triangulation.triangles[t];
+            if nodes[t].is_some() && tri.iter().filter(|&&i| i != s).all(|&i| is_adjacent(s, i)) {
+                nodes.push(Some(MedialAxisPoint {
+                    pos: samples[s].pos,
+                    radius: T::zero(),
+                }));
+                edges.push((t, nodes.len() - 1));
+                break;
+            }
+        }
+    }
+
+    // merge nodes at the same position (e.g. all triangles of cocircular samples)
+    let mut groups = (0..nodes.len()).collect::<Vec<_>>();
+    fn find_root(groups: &mut [usize], mut i: usize) -> usize {
+        while groups[i] != i {
+            groups[i] = groups[groups[i]];
+            i = groups[i];
+        }
+        i
+    }
+    for &(a, b) in edges.iter() {
+        let (pa, pb) = (nodes[a].unwrap().pos, nodes[b].unwrap().pos);
+        if pa.fuzzy_eq_eps(pb, pos_equal_eps) {
+            let ra = find_root(&mut groups, a);
+            let rb = find_root(&mut groups, b);
+            if ra != rb {
+                let (keep, merge) = if ra < rb { (ra, rb) } else { (rb, ra) };
+                groups[merge] = keep;
+            }
+        }
+    }
+
+    let mut group_sizes = vec![0usize; nodes.len()];
+    for i in 0..nodes.len() {
+        group_sizes[find_root(&mut groups, i)] += 1;
+    }
+
+    let mut graph_edges = edges
+        .iter()
+        .filter_map(|&(a, b)| {
+            let ra = find_root(&mut groups, a);
+            let rb = find_root(&mut groups, b);
+            match ra.cmp(&rb) {
+                std::cmp::Ordering::Less => Some((ra, rb)),
+                std::cmp::Ordering::Greater => Some((rb, ra)),
+                std::cmp::Ordering::Equal => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    graph_edges.sort_unstable();
+    graph_edges.dedup();
+
+    let mut adjacency = vec![Vec::new(); nodes.len()];
+    for (e, &(a, b)) in graph_edges.iter().enumerate() {
+        adjacency[a].push((b, e));
+        adjacency[b].push((a, e));
+    }
+
+    // walk from `start` along `first_edge` to `next_node` until reaching a branch point, an end,
+    // or back to `start`
+    fn walk(
+        adjacency: &[Vec<(usize, usize)>],
+        visited: &mut [bool],
+        start: usize,
+        first_edge: usize,
+        next_node: usize,
+    ) -> (Vec<usize>, bool) {
+        let mut chain = vec![start];
+        let mut curr = next_node;
+        let mut edge = first_edge;
+        loop {
+            visited[edge] = true;
+            if curr == start {
+                break;
+            }
+            chain.push(curr);
+            if adjacency[curr].len() != 2 {
+                break;
+            }
+            match adjacency[curr].iter().find(|(_, e)| !visited[*e]) {
+                Some(&(n, e)) => {
+                    curr = n;
+                    edge = e;
+                }
+                None => break,
+            }
+        }
+        let is_closed = curr == start && adjacency[start].len() == 2;
+        if curr == start && !is_closed {
+            chain.push(start);
+        }
+        (chain, is_closed)
+    }
+
+    let mut visited = vec![false; graph_edges.len()];
+    let mut chains = Vec::new();
+    for node in (0..nodes.len()).filter(|&i| !adjacency[i].is_empty() && adjacency[i].len() != 2) {
+        for i in 0..adjacency[node].len() {
+            let (next, e) = adjacency[node][i];
+            if !visited[e] {
+                chains.push(walk(&adjacency, &mut visited, node, e, next));
+            }
+        }
+    }
+    for e in 0..graph_edges.len() {
+        if !visited[e] {
+            let (a, b) = graph_edges[e];
+            chains.push(walk(&adjacency, &mut visited, a, e, b));
+        }
+    }
+
+    let mut result = Vec::with_capacity(chains.len());
+    for (chain, is_closed) in chains {
+        let mut points = chain.iter().map(|&i| nodes[i].unwrap()).collect::<Vec<_>>();
+        if is_closed {
+            points.push(points[0]);
+        }
+        let mut points = simplify(&points, tolerance);
+        if is_closed {
+            points.pop();
+        }
+        result.push(MedialAxisEdge { points, is_closed });
+    }
+
+    // medial axis which degenerates to a single point
+    for (i, node) in nodes.iter().enumerate() {
+        if let Some(p) = node
+            && groups[i] == i
+            && adjacency[i].is_empty()
+            && group_sizes[i] > 1
+        {
+            result.push(MedialAxisEdge {
+                points: vec![*p],
+                is_closed: false,
+            });
+        }
+    }
+
+    MedialAxis { edges: result }
+}
+
+/// Point sampled along the boundary of a shape.
+#[derive(Debug, Clone, Copy)]
+struct BoundarySample<T> {
+    pos: Vector2<T>,
+    /// Index of the loop in the [Shape::plines_index] the sample is on.
+    loop_idx: usize,
+    /// Whether the sample is at a convex corner vertex of the region.
+    is_convex_corner: bool,
+}
+
+/// Sample points along all the polylines of `shape` with a maximum distance of `spacing` between
+/// samples, also returns the range of samples for each loop.
+fn sample_boundary<T>(
+    shape: &Shape<T>,
+    spacing: T,
+    pos_equal_eps: T,
+) -> (Vec<BoundarySample<T>>, Vec<Range<usize>>)
+where
+    T: Real,
+{
+    let loop_count = shape.ccw_plines.len() + shape.cw_plines.len();
+    let mut samples = Vec::new();
+    let mut loop_ranges = Vec::with_capacity(loop_count);
+    for loop_idx in 0..loop_count {
+        let pline = &shape.get_pline(loop_idx).polyline;
+        let start = samples.len();
+        for (i, j) in pline.iter_segment_indexes() {
+            let v1 = pline.at(i);
+            let v2 = pline.at(j);
+            let length = seg_length(v1, v2);
+            if length < pos_equal_eps {
+                continue;
+            }
+
+            // region is to the left of all shape polylines, convex corners turn left
+            let prev = pline.at(pline.prev_wrapping_index(i));
+            let is_convex_corner = {
+                let t1 = seg_tangent_vector(prev, v1, v1.pos());
+                let t2 = seg_tangent_vector(v1, v2, v1.pos());
+                let (l1, l2) = (t1.length(), t2.length());
+                l1 > T::zero() && l2 > T::zero() && t1.perp_dot(t2) / (l1 * l2) > pos_equal_eps
+            };
+
+            samples.push(BoundarySample {
+                pos: v1.pos(),
+                loop_idx,
+                is_convex_corner,
+            });
+
+            let count = (length / spacing).ceil().to_usize().unwrap().max(1);
+            let point_at = |t: T| {
+                if v1.bulge_is_zero() {
+                    v1.pos() + (v2.pos() - v1.pos()).scale(t)
+                } else {
+                    let (radius, center) = seg_arc_radius_and_center(v1, v2);
+                    let start_angle = angle(center, v1.pos());
+                    let sweep = angle_from_bulge(v1.bulge);
+                    point_on_circle(radius, center, start_angle + sweep * t)
+                }
+            };
+            for k in 1..count {
+                samples.push(BoundarySample {
+                    pos: point_at(T::from(k).unwrap() / T::from(count).unwrap()),
+                    loop_idx,
+                    is_convex_corner: false,
+                });
+            }
+        }
+        loop_ranges.push(start..samples.len());
+    }
+
+    (samples, loop_ranges)
+}
+
+/// Douglas-Peucker simplification of the medial axis points treating the clearance radius as a
+/// third coordinate, first and last points are always kept.
+fn simplify<T>(points: &[MedialAxisPoint<T>], tolerance: T) -> Vec<MedialAxisPoint<T>>
+where
+    T: Real,
+{
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let dist_to_seg = |p: &MedialAxisPoint<T>, a: &MedialAxisPoint<T>, b: &MedialAxisPoint<T>| {
+        let d = (b.pos.x - a.pos.x, b.pos.y - a.pos.y, b.radius - a.radius);
+        let v = (p.pos.x - a.pos.x, p.pos.y - a.pos.y, p.radius - a.radius);
+        let len_squared = d.0 * d.0 + d.1 * d.1 + d.2 * d.2;
+        let t = if len_squared > T::zero() {
+            let t = (v.0 * d.0 + v.1 * d.1 + v.2 * d.2) / len_squared;
+            if t < T::zero() {
+                T::zero()
+            } else if t > T::one() {
+                T::one()
+            } else {
+                t
+            }
+        } else {
+            T::zero()
+        };
+        let diff = (v.0 - d.0 * t, v.1 - d.1 * t, v.2 - d.2 * t);
+        (diff.0 * diff.0 + diff.1 * diff.1 + diff.2 * diff.2).sqrt()
+    };
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let mut max_dist = T::zero();
+        let mut max_idx = start;
+        for i in (start + 1)..end {
+            let dist = dist_to_seg(&points[i], &points[start], &points[end]);
+            if dist > max_dist {
+                max_dist = dist;
+                max_idx = i;
+            }
+        }
+
+        if max_dist > tolerance {
+            keep[max_idx] = true;
+            stack.push((start, max_idx));
+            stack.push((max_idx, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, k)| k.then_some(*p))
+        .collect()
+}
@@ -0,0 +1,10 @@
+//! This module has functions for computing the medial axis (skeleton) of closed polylines and
+//! shapes, including regions bounded by arc segments.
+//!
+//! Each point of the medial axis holds the clearance radius (distance to the closest boundary) so
+//! it can be used for V-carving and engraving paths (depth from the clearance radius) or to find
+//! the largest circle that fits inside a region, see [pline_medial_axis] and [shape_medial_axis].
+mod delaunay;
+mod medial_axis;
+
+pub use medial_axis::*;
@@ -0,0 +1,145 @@
+use std::fmt::Write;
+
+use crate::{
+    core::{math::to_f64, traits::Real},
+    polyline::{PlineSource, PlineVertex, seg_arc_radius_and_center},
+    shape_algorithms::Shape,
+};
+
+use super::SvgFillRule;
+
+/// Convert a polyline to SVG path data.
+///
+/// Line segments are written as `L` commands and arc segments as `A` commands, closed polylines
+/// end with a `Z` command. Empty polylines return an empty string.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::pline_closed;
+/// # use cavalier_contours::svg::*;
+/// let pline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0)];
+/// assert_eq!(pline_to_path_data(&pline), "M 0 0 L 10 0 A 5 5 0 0 1 10 10 Z");
+/// ```
+pub fn pline_to_path_data<P>(pline: &P) -> String
+where
+    P: PlineSource + ?Sized,
+{
+    let mut output = String::new();
+    write_pline_path_data(&mut output, pline);
+    output
+}
+
+/// Convert multiple polylines to SVG path data, each polyline is written as a subpath.
+pub fn plines_to_path_data<'a, P, I>(plines: I) -> String
+where
+    P: PlineSource + ?Sized + 'a,
+    I: IntoIterator<Item = &'a P>,
+{
+    let mut output = String::new();
+    for pline in plines {
+        write_pline_path_data(&mut output, pline);
+    }
+    output
+}
+
+/// Convert a shape to SVG path data, all the counter clockwise polylines are written as subpaths
+/// followed by all the clockwise polylines (holes).
+pub fn shape_to_path_data<T>(shape: &Shape<T>) -> String
+where
+    T: Real,
+{
+    plines_to_path_data(
+        shape
+            .ccw_plines
+            .iter()
+            .chain(shape.cw_plines.iter())
+            .map(|p| &p.polyline),
+    )
+}
+
+/// Convert a shape to an SVG `<path>` element with the given `fill_rule`.
+///
+/// Shape holes are clockwise and islands counter clockwise so either fill rule will render the
+/// holes as cut out.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::shape_algorithms::*;
+/// # use cavalier_contours::pline_closed;
+/// # use cavalier_contours::svg::*;
+/// let outer = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)];
+/// let hole = pline_closed![(2.0, 2.0, 0.0), (2.0, 8.0, 0.0), (8.0, 8.0, 0.0), (8.0, 2.0, 0.0)];
+/// let shape = Shape::from_plines(vec![outer, hole]);
+/// let element = shape_to_path_element(&shape, SvgFillRule::EvenOdd);
+/// assert!(element.starts_with("<path d=\"M 0 0 L 10 0"));
+/// assert!(element.ends_with("fill-rule=\"evenodd\"/>"));
+/// ```
+pub fn shape_to_path_element<T>(shape: &Shape<T>, fill_rule: SvgFillRule) -> String
+where
+    T: Real,
+{
+    format!(
+        "<path d=\"{}\" fill-rule=\"{}\"/>",
+        shape_to_path_data(shape),
+        fill_rule.as_str()
+    )
+}
+
+fn write_pline_path_data<P>(output: &mut String, pline: &P)
+where
+    P: PlineSource + ?Sized,
+{
+    if pline.vertex_count() == 0 {
+        return;
+    }
+
+    if !output.is_empty() {
+        output.push(' ');
+    }
+
+    let start = pline.at(0);
+    // writing to a String never fails
+    write!(output, "M {} {}", to_f64(start.x), to_f64(start.y)).unwrap();
+
+    let seg_count = pline.segment_count();
+    for (i, (v1, v2)) in pline.iter_segments().enumerate() {
+        let is_closing_seg = pline.is_closed() && i + 1 == seg_count;
+        if is_closing_seg && v1.bulge_is_zero() {
+            // closing line segment is implied by Z
+            continue;
+        }
+        write_seg(output, v1, v2);
+    }
+
+    if pline.is_closed() {
+        output.push_str(" Z");
+    }
+}
+
+fn write_seg<T>(output: &mut String, v1: PlineVertex<T>, v2: PlineVertex<T>)
+where
+    T: Real,
+{
+    if v1.bulge_is_zero() {
+        write!(output, " L {} {}", to_f64(v2.x), to_f64(v2.y)).unwrap();
+        return;
+    }
+
+    let (radius, _) = seg_arc_radius_and_center(v1, v2);
+    let radius = to_f64(radius);
+    // arc sweep greater than PI when bulge magnitude greater than 1
+    let large_arc = if v1.bulge.abs() > T::one() { 1 } else { 0 };
+    // SVG positive angle direction (sweep flag 1) is counter clockwise in the coordinate system
+    let sweep = if v1.bulge_is_pos() { 1 } else { 0 };
+    write!(
+        output,
+        " A {radius} {radius} 0 {large_arc} {sweep} {} {}",
+        to_f64(v2.x),
+        to_f64(v2.y)
+    )
+    .unwrap();
+}
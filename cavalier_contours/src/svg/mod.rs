@@ -0,0 +1,109 @@
+//! This module has functions for converting polylines and shapes to and from
+//! [SVG path data](https://www.w3.org/TR/SVG/paths.html#PathData).
+//!
+//! Coordinates are written and read as is, note SVG has the Y axis pointing down so when
+//! displaying in a browser the path will appear mirrored vertically unless a transform such as
+//! `transform="scale(1, -1)"` is applied.
+mod export;
+mod parse;
+
+pub use export::*;
+pub use parse::*;
+
+use std::fmt;
+
+use crate::core::traits::Real;
+
+/// Error returned when parsing SVG path data fails, `position` is the byte position in the path
+/// data string where the error occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SvgPathError {
+    /// Path data does not start with a move to command.
+    MissingMoveTo,
+    /// Unrecognized path command character.
+    InvalidCommand { position: usize, command: char },
+    /// Expected a number argument.
+    ExpectedNumber { position: usize },
+    /// Expected an arc flag argument (`0` or `1`).
+    ExpectedFlag { position: usize },
+}
+
+impl fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgPathError::MissingMoveTo => {
+                write!(f, "SVG path data must begin with a move to command")
+            }
+            SvgPathError::InvalidCommand { position, command } => {
+                write!(
+                    f,
+                    "invalid SVG path command '{command}' at position {position}"
+                )
+            }
+            SvgPathError::ExpectedNumber { position } => {
+                write!(f, "expected number in SVG path data at position {position}")
+            }
+            SvgPathError::ExpectedFlag { position } => {
+                write!(
+                    f,
+                    "expected arc flag in SVG path data at position {position}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SvgPathError {}
+
+/// Fill rule used for the `fill-rule` attribute of an SVG path element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SvgFillRule {
+    /// Area is filled where the winding number is non zero, holes must wind in the opposite
+    /// direction of the area they cut out of.
+    NonZero,
+    /// Area is filled where the winding number is odd, holes are cut out regardless of direction.
+    EvenOdd,
+}
+
+impl SvgFillRule {
+    /// Returns the SVG attribute value for the fill rule.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SvgFillRule::NonZero => "nonzero",
+            SvgFillRule::EvenOdd => "evenodd",
+        }
+    }
+}
+
+/// Struct to hold options parameters when parsing SVG path data.
+#[derive(Debug, Clone)]
+pub struct SvgParseOptions<T> {
+    /// Maximum distance allowed between a curve (cubic/quadratic Bézier or elliptical arc) and the
+    /// arc segments used to approximate it.
+    pub curve_tolerance: T,
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> SvgParseOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            curve_tolerance: T::from(1e-3).unwrap(),
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for SvgParseOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
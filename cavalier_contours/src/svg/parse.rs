@@ -0,0 +1,558 @@
+use crate::{
+    core::{
+        math::{Vector2, bulge_from_angle},
+        traits::Real,
+    },
+    polyline::{PlineSource, PlineSourceMut, PlineVertex, Polyline, seg_closest_point},
+};
+
+use super::{SvgParseOptions, SvgPathError};
+
+/// Parse SVG path data into polylines using default options.
+///
+/// See [parse_path_data_opt] for more information.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::core::traits::*;
+/// # use cavalier_contours::svg::*;
+/// let plines: Vec<Polyline> = parse_path_data("M 0 0 h 10 A 5 5 0 0 1 10 10 z").unwrap();
+/// assert_eq!(plines.len(), 1);
+/// assert!(plines[0].is_closed());
+/// assert_eq!(plines[0].vertex_count(), 3);
+/// assert!(plines[0][1].bulge.fuzzy_eq(1.0));
+/// ```
+pub fn parse_path_data<T>(data: &str) -> Result<Vec<Polyline<T>>, SvgPathError>
+where
+    T: Real,
+{
+    parse_path_data_opt(data, &SvgParseOptions::new())
+}
+
+/// Parse SVG path data into polylines.
+///
+/// Each subpath becomes a polyline, subpaths ending with a close path command (`Z`/`z`) are
+/// closed polylines. All path commands are supported (absolute and relative):
+///
+/// * `M`, `L`, `H`, `V`, and `Z` create line segments.
+/// * `A` creates an arc segment if the radii are equal (split into two arc segments if sweeping
+///   more than a half circle), otherwise the elliptical arc is approximated by arc segments.
+/// * `C`, `S`, `Q`, and `T` Bézier curves are approximated by arc segments.
+///
+/// Curves are approximated so no point on the curve is further than `options.curve_tolerance`
+/// from the arc segments. Repeat position vertexes are removed from all polylines returned and
+/// subpaths with less than two vertexes are discarded.
+pub fn parse_path_data_opt<T>(
+    data: &str,
+    options: &SvgParseOptions<T>,
+) -> Result<Vec<Polyline<T>>, SvgPathError>
+where
+    T: Real,
+{
+    let mut lexer = Lexer::new(data);
+    let mut builder = PathBuilder::new(options);
+    let mut prev_cmd: Option<u8> = None;
+
+    loop {
+        lexer.skip_separators();
+        let Some(next) = lexer.peek() else {
+            break;
+        };
+
+        let cmd = if next.is_ascii_alphabetic() {
+            lexer.pos += 1;
+            next
+        } else {
+            // implicit repeat of previous command (move to repeats as line to)
+            match prev_cmd {
+                None => return Err(SvgPathError::MissingMoveTo),
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z' | b'z') => {
+                    return Err(SvgPathError::InvalidCommand {
+                        position: lexer.pos,
+                        command: next as char,
+                    });
+                }
+                Some(c) => c,
+            }
+        };
+
+        if prev_cmd.is_none() && !matches!(cmd, b'M' | b'm') {
+            return Err(SvgPathError::MissingMoveTo);
+        }
+
+        let relative = cmd.is_ascii_lowercase();
+        let cur = builder.current_pt;
+        let point = |lexer: &mut Lexer| -> Result<Vector2<T>, SvgPathError> {
+            let x = lexer.number()?;
+            let y = lexer.number()?;
+            Ok(if relative {
+                Vector2::new(cur.x + x, cur.y + y)
+            } else {
+                Vector2::new(x, y)
+            })
+        };
+
+        match cmd.to_ascii_uppercase() {
+            b'M' => {
+                let p = point(&mut lexer)?;
+                builder.move_to(p);
+            }
+            b'L' => {
+                let p = point(&mut lexer)?;
+                builder.line_to(p);
+            }
+            b'H' => {
+                let x: T = lexer.number()?;
+                let x = if relative { cur.x + x } else { x };
+                builder.line_to(Vector2::new(x, cur.y));
+            }
+            b'V' => {
+                let y: T = lexer.number()?;
+                let y = if relative { cur.y + y } else { y };
+                builder.line_to(Vector2::new(cur.x, y));
+            }
+            b'C' => {
+                let c1 = point(&mut lexer)?;
+                let c2 = point(&mut lexer)?;
+                let p = point(&mut lexer)?;
+                builder.cubic_to(c1, c2, p);
+            }
+            b'S' => {
+                let c1 = builder.reflected_ctrl(true);
+                let c2 = point(&mut lexer)?;
+                let p = point(&mut lexer)?;
+                builder.cubic_to(c1, c2, p);
+            }
+            b'Q' => {
+                let c = point(&mut lexer)?;
+                let p = point(&mut lexer)?;
+                builder.quadratic_to(c, p);
+            }
+            b'T' => {
+                let c = builder.reflected_ctrl(false);
+                let p = point(&mut lexer)?;
+                builder.quadratic_to(c, p);
+            }
+            b'A' => {
+                let rx: T = lexer.number()?;
+                let ry: T = lexer.number()?;
+                let x_axis_rotation: T = lexer.number()?;
+                let large_arc = lexer.flag()?;
+                let sweep = lexer.flag()?;
+                let p = point(&mut lexer)?;
+                builder.elliptical_arc_to(
+                    rx,
+                    ry,
+                    x_axis_rotation.to_radians(),
+                    large_arc,
+                    sweep,
+                    p,
+                );
+            }
+            b'Z' => {
+                builder.close();
+            }
+            _ => {
+                return Err(SvgPathError::InvalidCommand {
+                    position: lexer.pos - 1,
+                    command: cmd as char,
+                });
+            }
+        }
+
+        prev_cmd = Some(cmd);
+    }
+
+    Ok(builder.finish())
+}
+
+/// Tokenizer for SVG path data.
+struct Lexer<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(data: &'a str) -> Self {
+        Self {
+            bytes: data.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Skip whitespace and comma separators.
+    fn skip_separators(&mut self) {
+        while let Some(b) = self.peek() {
+            if b.is_ascii_whitespace() || b == b',' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn number<T: Real>(&mut self) -> Result<T, SvgPathError> {
+        self.skip_separators();
+        let start = self.pos;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+
+        let mut digit_count = self.skip_digits();
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            digit_count += self.skip_digits();
+        }
+
+        if digit_count == 0 {
+            self.pos = start;
+            return Err(SvgPathError::ExpectedNumber { position: start });
+        }
+
+        // exponent only consumed if followed by digits
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let exp_start = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                self.pos = exp_start;
+            }
+        }
+
+        // slice only contains ascii sign, digits, '.', and exponent so always valid utf8
+        let s = std::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        let value: f64 = s
+            .parse()
+            .map_err(|_| SvgPathError::ExpectedNumber { position: start })?;
+        Ok(T::from(value).unwrap())
+    }
+
+    /// Arc flags are a single `0` or `1` character and may not be separated from the next
+    /// argument.
+    fn flag(&mut self) -> Result<bool, SvgPathError> {
+        self.skip_separators();
+        let result = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(SvgPathError::ExpectedFlag { position: self.pos }),
+        };
+        self.pos += 1;
+        Ok(result)
+    }
+}
+
+/// Maximum subdivision depth when approximating curves with arcs.
+const MAX_CURVE_SUBDIVISION_DEPTH: usize = 16;
+
+/// Number of intervals sampled along a curve piece to test it is within tolerance of the arc
+/// approximating it.
+const CURVE_SAMPLE_COUNT: usize = 16;
+
+/// Builds polylines from path commands.
+struct PathBuilder<'a, T>
+where
+    T: Real,
+{
+    options: &'a SvgParseOptions<T>,
+    result: Vec<Polyline<T>>,
+    current: Polyline<T>,
+    current_pt: Vector2<T>,
+    subpath_start: Vector2<T>,
+    /// Last control point and whether it was from a cubic (true) or quadratic (false) curve.
+    last_ctrl: Option<(Vector2<T>, bool)>,
+}
+
+impl<'a, T> PathBuilder<'a, T>
+where
+    T: Real,
+{
+    fn new(options: &'a SvgParseOptions<T>) -> Self {
+        Self {
+            options,
+            result: Vec::new(),
+            current: Polyline::new(),
+            current_pt: Vector2::zero(),
+            subpath_start: Vector2::zero(),
+            last_ctrl: None,
+        }
+    }
+
+    fn finish_subpath(&mut self) {
+        let pline = std::mem::take(&mut self.current);
+        let pline = pline
+            .remove_repeat_pos(self.options.pos_equal_eps)
+            .unwrap_or(pline);
+        if pline.vertex_count() > 1 {
+            self.result.push(pline);
+        }
+    }
+
+    fn finish(mut self) -> Vec<Polyline<T>> {
+        self.finish_subpath();
+        self.result
+    }
+
+    /// Add the current point as the first vertex if subpath has not started.
+    fn ensure_started(&mut self) {
+        if self.current.vertex_count() == 0 {
+            self.current
+                .add(self.current_pt.x, self.current_pt.y, T::zero());
+        }
+    }
+
+    /// Add segment from current point to `p` with `bulge`.
+    fn seg_to(&mut self, p: Vector2<T>, bulge: T) {
+        self.ensure_started();
+        let last = self.current.last().unwrap();
+        self.current.set_last(last.with_bulge(bulge));
+        self.current.add(p.x, p.y, T::zero());
+        self.current_pt = p;
+    }
+
+    fn move_to(&mut self, p: Vector2<T>) {
+        self.finish_subpath();
+        self.current_pt = p;
+        self.subpath_start = p;
+        self.last_ctrl = None;
+    }
+
+    fn line_to(&mut self, p: Vector2<T>) {
+        self.seg_to(p, T::zero());
+        self.last_ctrl = None;
+    }
+
+    fn close(&mut self) {
+        if self.current.vertex_count() > 1 {
+            let first = self.current.at(0).pos();
+            if self
+                .current
+                .last()
+                .unwrap()
+                .pos()
+                .fuzzy_eq_eps(first, self.options.pos_equal_eps)
+            {
+                self.current.remove_last();
+            }
+            self.current.set_is_closed(true);
+        }
+        self.finish_subpath();
+        self.current_pt = self.subpath_start;
+        self.last_ctrl = None;
+    }
+
+    /// Control point reflected about the current point for smooth curve commands (`S`/`T`), if
+    /// previous command was not the same kind of curve then the current point is returned.
+    fn reflected_ctrl(&self, cubic: bool) -> Vector2<T> {
+        match self.last_ctrl {
+            Some((ctrl, is_cubic)) if is_cubic == cubic => self.current_pt.scale(T::two()) - ctrl,
+            _ => self.current_pt,
+        }
+    }
+
+    fn cubic_to(&mut self, c1: Vector2<T>, c2: Vector2<T>, p: Vector2<T>) {
+        let p0 = self.current_pt;
+        let three = T::two() + T::one();
+        let curve = |t: T| {
+            let mt = T::one() - t;
+            let a = mt * mt * mt;
+            let b = three * mt * mt * t;
+            let c = three * mt * t * t;
+            let d = t * t * t;
+            Vector2::new(
+                a * p0.x + b * c1.x + c * c2.x + d * p.x,
+                a * p0.y + b * c1.y + c * c2.y + d * p.y,
+            )
+        };
+        self.curve_to(&curve, p);
+        self.last_ctrl = Some((c2, true));
+    }
+
+    fn quadratic_to(&mut self, c: Vector2<T>, p: Vector2<T>) {
+        let p0 = self.current_pt;
+        let curve = |t: T| {
+            let mt = T::one() - t;
+            let a = mt * mt;
+            let b = T::two() * mt * t;
+            let d = t * t;
+            Vector2::new(a * p0.x + b * c.x + d * p.x, a * p0.y + b * c.y + d * p.y)
+        };
+        self.curve_to(&curve, p);
+        self.last_ctrl = Some((c, false));
+    }
+
+    /// Elliptical arc, see the SVG specification "Elliptical arc implementation notes" for the
+    /// conversion from endpoint to center parameterization.
+    fn elliptical_arc_to(
+        &mut self,
+        rx: T,
+        ry: T,
+        rotation: T,
+        large_arc: bool,
+        sweep: bool,
+        p: Vector2<T>,
+    ) {
+        self.last_ctrl = None;
+        let p0 = self.current_pt;
+        if p0.fuzzy_eq_eps(p, self.options.pos_equal_eps) {
+            // arc omitted when end points are the same
+            return;
+        }
+
+        let mut rx = rx.abs();
+        let mut ry = ry.abs();
+        if rx.fuzzy_eq_zero() || ry.fuzzy_eq_zero() {
+            self.line_to(p);
+            return;
+        }
+
+        let (sin_phi, cos_phi) = rotation.sin_cos();
+        let dx2 = (p0.x - p.x) / T::two();
+        let dy2 = (p0.y - p.y) / T::two();
+        let x1p = cos_phi * dx2 + sin_phi * dy2;
+        let y1p = -sin_phi * dx2 + cos_phi * dy2;
+
+        // scale up radii if too small to reach end point
+        let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+        if lambda > T::one() {
+            let s = lambda.sqrt();
+            rx = rx * s;
+            ry = ry * s;
+        }
+
+        let rx2 = rx * rx;
+        let ry2 = ry * ry;
+        let num = rx2 * ry2 - rx2 * y1p * y1p - ry2 * x1p * x1p;
+        let den = rx2 * y1p * y1p + ry2 * x1p * x1p;
+        let coef = {
+            let sq = num / den;
+            let c = if sq > T::zero() { sq.sqrt() } else { T::zero() };
+            if large_arc == sweep { -c } else { c }
+        };
+        let cxp = coef * rx * y1p / ry;
+        let cyp = -coef * ry * x1p / rx;
+        let center = Vector2::new(
+            cos_phi * cxp - sin_phi * cyp + (p0.x + p.x) / T::two(),
+            sin_phi * cxp + cos_phi * cyp + (p0.y + p.y) / T::two(),
+        );
+
+        let u = Vector2::new((x1p - cxp) / rx, (y1p - cyp) / ry);
+        let v = Vector2::new((-x1p - cxp) / rx, (-y1p - cyp) / ry);
+        let start_angle = u.y.atan2(u.x);
+        let mut sweep_angle = u.perp_dot(v).atan2(u.dot(v));
+        if !sweep && sweep_angle > T::zero() {
+            sweep_angle = sweep_angle - T::tau();
+        } else if sweep && sweep_angle < T::zero() {
+            sweep_angle = sweep_angle + T::tau();
+        }
+
+        let curve = |t: T| {
+            let (s, c) = (start_angle + t * sweep_angle).sin_cos();
+            let x = rx * c;
+            let y = ry * s;
+            Vector2::new(
+                center.x + cos_phi * x - sin_phi * y,
+                center.y + sin_phi * x + cos_phi * y,
+            )
+        };
+
+        if (rx - ry).abs() <= self.options.pos_equal_eps {
+            // circular arc maps directly to arc segments, split in two if sweeping more than a
+            // half circle (maximum sweep for a polyline arc segment)
+            if sweep_angle.abs() > T::pi() {
+                let bulge = bulge_from_angle(sweep_angle / T::two());
+                self.seg_to(curve(T::one() / T::two()), bulge);
+                self.seg_to(p, bulge);
+            } else {
+                self.seg_to(p, bulge_from_angle(sweep_angle));
+            }
+            return;
+        }
+
+        self.curve_to(&curve, p);
+    }
+
+    /// Approximate parametric `curve` (t from 0 to 1) from current point to `end` with arcs.
+    fn curve_to<F>(&mut self, curve: &F, end: Vector2<T>)
+    where
+        F: Fn(T) -> Vector2<T>,
+    {
+        let start = self.current_pt;
+        self.curve_range_to(curve, T::zero(), T::one(), start, end, 0);
+    }
+
+    fn curve_range_to<F>(
+        &mut self,
+        curve: &F,
+        t0: T,
+        t1: T,
+        p0: Vector2<T>,
+        p1: Vector2<T>,
+        depth: usize,
+    ) where
+        F: Fn(T) -> Vector2<T>,
+    {
+        let tm = (t0 + t1) / T::two();
+        let pm = curve(tm);
+        let bulge = bulge_through_points(p0, pm, p1);
+
+        if depth < MAX_CURVE_SUBDIVISION_DEPTH {
+            let v1 = PlineVertex::from_vector2(p0, bulge);
+            let v2 = PlineVertex::from_vector2(p1, T::zero());
+            let within_tolerance = bulge.abs() <= T::one()
+                && (1..CURVE_SAMPLE_COUNT).all(|k| {
+                    let t =
+                        t0 + (t1 - t0) * T::from(k).unwrap() / T::from(CURVE_SAMPLE_COUNT).unwrap();
+                    let q = curve(t);
+                    let closest = seg_closest_point(v1, v2, q, self.options.pos_equal_eps);
+                    (q - closest).length() <= self.options.curve_tolerance
+                });
+
+            if !within_tolerance {
+                self.curve_range_to(curve, t0, tm, p0, pm, depth + 1);
+                self.curve_range_to(curve, tm, t1, pm, p1, depth + 1);
+                return;
+            }
+        }
+
+        self.seg_to(p1, bulge);
+    }
+}
+
+/// Returns the bulge of the arc going from `p0` to `p1` that passes through `pm`, returns zero if
+/// the points are collinear.
+fn bulge_through_points<T>(p0: Vector2<T>, pm: Vector2<T>, p1: Vector2<T>) -> T
+where
+    T: Real,
+{
+    // inscribed angle at pm is half the sweep of the arc not containing pm so
+    // bulge = tan((2PI - 2 * angle) / 4) = cot(angle / 2) = (|a||b| + a.b) / |a x b|
+    let a = p0 - pm;
+    let b = p1 - pm;
+    let cross = (p1 - p0).perp_dot(pm - p0);
+    if cross == T::zero() {
+        return T::zero();
+    }
+
+    let bulge = (a.length() * b.length() + a.dot(b)) / cross.abs();
+
+    // arc is counter clockwise if pm is to the right of the chord direction
+    if cross < T::zero() { bulge } else { -bulge }
+}
@@ -0,0 +1,7 @@
+//! This module has functions for generating CNC toolpaths from shapes.
+//!
+//! Pocketing toolpaths are created by repeatedly offsetting a [Shape](crate::shape_algorithms::Shape)
+//! inward by a stepover distance until no area remains, see [pocket_offsets].
+mod pocket;
+
+pub use pocket::*;
@@ -0,0 +1,407 @@
+use crate::{
+    core::{math::Vector2, traits::Real},
+    polyline::{
+        PlineCreation, PlineOrientation, PlineSource, PlineSourceMut, Polyline, seg_split_at_point,
+    },
+    shape_algorithms::{Shape, ShapeOffsetOptions},
+};
+
+/// Struct to hold options parameters when generating pocket offsets.
+#[derive(Debug, Clone)]
+pub struct PocketOptions<T> {
+    /// Offset distance of the first loops from the shape boundary (e.g. the tool radius). If zero
+    /// then the shape polylines are used as the first loops.
+    pub start_offset: T,
+    /// Maximum number of offset steps to perform, `None` for no limit (offsetting continues until
+    /// no area remains).
+    pub max_steps: Option<usize>,
+    /// Options used for each shape offset, `pos_equal_eps` is also used when finding the parent of
+    /// each loop.
+    pub offset_options: ShapeOffsetOptions<T>,
+}
+
+impl<T> PocketOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            start_offset: T::zero(),
+            max_steps: None,
+            offset_options: ShapeOffsetOptions::new(),
+        }
+    }
+}
+
+impl<T> Default for PocketOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct to hold options parameters when linking pocket loops, see [PocketTree::link_paths_opt].
+#[derive(Debug, Clone)]
+pub struct PocketLinkOptions<T> {
+    /// Fuzzy comparison epsilon used for determining if two positions are equal.
+    pub pos_equal_eps: T,
+}
+
+impl<T> PocketLinkOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pos_equal_eps: T::from(1e-5).unwrap(),
+        }
+    }
+}
+
+impl<T> Default for PocketLinkOptions<T>
+where
+    T: Real,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Order used when linking pocket loops into continuous paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PocketLinkOrder {
+    /// Paths start at the loops nearest the shape boundary and move inward.
+    OutsideIn,
+    /// Paths start at the innermost loops and move outward.
+    InsideOut,
+}
+
+/// Single offset loop of a [PocketTree].
+#[derive(Debug, Clone)]
+pub struct PocketLoop<T: Real> {
+    /// Closed polyline of the loop, counter clockwise loops bound area while clockwise loops bound
+    /// islands (holes).
+    pub pline: Polyline<T>,
+    /// Offset step the loop was created at (0 for the first loops).
+    pub depth: usize,
+    /// Total offset distance from the shape boundary.
+    pub offset: T,
+    /// Index of the loop (in [PocketTree::loops]) at the previous step this loop was offset from.
+    pub parent: Option<usize>,
+    /// Indexes of the loops (in [PocketTree::loops]) at the next step offset from this loop, more
+    /// than one child means the area split.
+    pub children: Vec<usize>,
+}
+
+/// Tree of pocket offset loops returned by [pocket_offsets].
+#[derive(Debug, Clone)]
+pub struct PocketTree<T: Real> {
+    /// All the loops ordered by depth (all loops at a step appear before any loops of the next
+    /// step).
+    pub loops: Vec<PocketLoop<T>>,
+}
+
+impl<T> PocketTree<T>
+where
+    T: Real,
+{
+    /// Returns true if there are no loops.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.loops.is_empty()
+    }
+
+    /// Iterate over the indexes of the loops without a parent.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.loops
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.parent.is_none())
+            .map(|(i, _)| i)
+    }
+
+    /// Link the loops into continuous open polylines ordered by `order`.
+    ///
+    /// Each path follows a chain of nested loops, moving from a loop to its first child (or parent
+    /// when ordered inside out) with a line segment joining the closest points. Each loop is
+    /// traversed fully in its own direction. Where the area splits (loop has multiple children)
+    /// the other children start new paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cavalier_contours::pline_closed;
+    /// # use cavalier_contours::polyline::*;
+    /// # use cavalier_contours::shape_algorithms::Shape;
+    /// # use cavalier_contours::toolpath::*;
+    /// let rectangle = pline_closed![
+    ///     (0.0, 0.0, 0.0),
+    ///     (10.0, 0.0, 0.0),
+    ///     (10.0, 6.0, 0.0),
+    ///     (0.0, 6.0, 0.0),
+    /// ];
+    /// let shape = Shape::from_plines([rectangle]);
+    /// let tree = pocket_offsets(&shape, 1.0, &PocketOptions::new());
+    /// // offsets at 0, 1 and 2 (offset 3 collapses the rectangle)
+    /// assert_eq!(tree.loops.len(), 3);
+    /// let paths = tree.link_paths(PocketLinkOrder::OutsideIn);
+    /// assert_eq!(paths.len(), 1);
+    /// assert!(!paths[0].is_closed());
+    /// ```
+    pub fn link_paths(&self, order: PocketLinkOrder) -> Vec<Polyline<T>> {
+        self.link_paths_opt(order, &Default::default())
+    }
+
+    /// Link the loops into continuous open polylines ordered by `order` using the options given.
+    ///
+    /// See [PocketTree::link_paths] for more information.
+    pub fn link_paths_opt(
+        &self,
+        order: PocketLinkOrder,
+        options: &PocketLinkOptions<T>,
+    ) -> Vec<Polyline<T>> {
+        let pos_equal_eps = options.pos_equal_eps;
+        let mut chains = self.chains();
+        if order == PocketLinkOrder::InsideOut {
+            for chain in chains.iter_mut() {
+                chain.reverse();
+            }
+            chains.reverse();
+        }
+
+        chains
+            .iter()
+            .map(|chain| self.link_chain(chain, pos_equal_eps))
+            .collect()
+    }
+
+    /// Chains of loop indexes (ordered outside in) found by depth first traversal, a chain
+    /// continues into the first child of each loop and the other children start new chains.
+    fn chains(&self) -> Vec<Vec<usize>> {
+        let mut result = Vec::new();
+        let mut stack: Vec<usize> = self.roots().collect();
+        stack.reverse();
+        while let Some(start) = stack.pop() {
+            let mut chain = vec![start];
+            let mut current = start;
+            while let Some((&first, rest)) = self.loops[current].children.split_first() {
+                stack.extend(rest.iter().rev());
+                chain.push(first);
+                current = first;
+            }
+
+            result.push(chain);
+        }
+
+        result
+    }
+
+    /// Join the loops of `chain` into a single open polyline, each loop starts and ends at the
+    /// point closest to the end of the previous loop.
+    fn link_chain(&self, chain: &[usize], pos_equal_eps: T) -> Polyline<T> {
+        let capacity = chain
+            .iter()
+            .map(|&i| self.loops[i].pline.vertex_count() + 2)
+            .sum();
+        let mut result = Polyline::with_capacity(capacity, false);
+        let mut current_pos: Option<Vector2<T>> = None;
+        for &i in chain {
+            let pline = &self.loops[i].pline;
+            let (seg_index, start_pos) = match current_pos {
+                Some(pos) => {
+                    let closest = pline
+                        .closest_point(pos, pos_equal_eps)
+                        .expect("loop polylines are not empty");
+                    (closest.seg_start_index, closest.seg_point)
+                }
+                None => (0, pline.at(0).pos()),
+            };
+
+            append_loop_from_point(&mut result, pline, seg_index, start_pos, pos_equal_eps);
+            current_pos = Some(start_pos);
+        }
+
+        result
+    }
+}
+
+/// Append closed polyline `pline` to `result` traversing from `start_pos` (which lies on the
+/// segment starting at `seg_index`) all the way around back to `start_pos`.
+fn append_loop_from_point<T>(
+    result: &mut Polyline<T>,
+    pline: &Polyline<T>,
+    seg_index: usize,
+    start_pos: Vector2<T>,
+    pos_equal_eps: T,
+) where
+    T: Real,
+{
+    let vc = pline.vertex_count();
+    let v1 = pline.at(seg_index);
+    let v2 = pline.at(pline.next_wrapping_index(seg_index));
+    let split = seg_split_at_point(v1, v2, start_pos, pos_equal_eps);
+    result.add_or_replace_vertex(split.split_vertex, pos_equal_eps);
+    for k in 1..vc {
+        result.add_or_replace_vertex(pline.at((seg_index + k) % vc), pos_equal_eps);
+    }
+    result.add_or_replace_vertex(split.updated_start, pos_equal_eps);
+    result.add_or_replace(start_pos.x, start_pos.y, T::zero(), pos_equal_eps);
+}
+
+/// Generate pocketing offset loops of `shape` by repeatedly offsetting inward by `stepover`
+/// until no area remains.
+///
+/// The loop at step `k` is the shape offset by `options.start_offset + k * stepover` (each step
+/// offsets the original shape so error does not accumulate). Loops are returned as a
+/// [PocketTree] with each loop parented to the loop at the previous step it was offset from.
+/// Returns an empty tree if `stepover` is not positive or the shape is empty.
+///
+/// # Examples
+///
+/// ```
+/// # use cavalier_contours::pline_closed;
+/// # use cavalier_contours::polyline::*;
+/// # use cavalier_contours::shape_algorithms::Shape;
+/// # use cavalier_contours::toolpath::*;
+/// let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+/// let shape = Shape::from_plines([circle]);
+/// let mut options = PocketOptions::new();
+/// // tool radius
+/// options.start_offset = 0.5;
+/// let tree = pocket_offsets(&shape, 1.0, &options);
+/// // offsets at 0.5, 1.5, 2.5, 3.5 and 4.5
+/// assert_eq!(tree.loops.len(), 5);
+/// assert_eq!(tree.loops[4].depth, 4);
+/// assert_eq!(tree.loops[4].parent, Some(3));
+/// ```
+pub fn pocket_offsets<T>(shape: &Shape<T>, stepover: T, options: &PocketOptions<T>) -> PocketTree<T>
+where
+    T: Real,
+{
+    let mut loops: Vec<PocketLoop<T>> = Vec::new();
+    if stepover <= T::zero() {
+        return PocketTree { loops };
+    }
+
+    // offsets at the point of collapse may leave zero area loops which are skipped
+    let area_eps = options.offset_options.pos_equal_eps;
+    let shape_plines = |s: &Shape<T>| -> Vec<Polyline<T>> {
+        s.ccw_plines
+            .iter()
+            .chain(s.cw_plines.iter())
+            .filter(|p| p.polyline.area().abs() > area_eps)
+            .map(|p| p.polyline.clone())
+            .collect()
+    };
+
+    let mut prev_start = 0;
+    let mut depth = 0;
+    loop {
+        if options.max_steps.is_some_and(|max| depth >= max) {
+            break;
+        }
+
+        let offset = options.start_offset + stepover * T::from(depth).unwrap();
+        let plines = if depth == 0 && offset.fuzzy_eq_zero() {
+            shape_plines(shape)
+        } else {
+            shape_plines(&shape.parallel_offset(offset, options.offset_options.clone()))
+        };
+
+        if plines.is_empty() {
+            break;
+        }
+
+        let start = loops.len();
+        for pline in plines {
+            let parent = if depth == 0 {
+                None
+            } else {
+                find_parent(
+                    &loops[prev_start..start],
+                    &pline,
+                    options.offset_options.pos_equal_eps,
+                )
+                .map(|i| prev_start + i)
+            };
+
+            let index = loops.len();
+            if let Some(p) = parent {
+                loops[p].children.push(index);
+            }
+
+            loops.push(PocketLoop {
+                pline,
+                depth,
+                offset,
+                parent,
+                children: Vec::new(),
+            });
+        }
+
+        prev_start = start;
+        depth += 1;
+    }
+
+    PocketTree { loops }
+}
+
+/// Find the loop in `prev_loops` that `pline` was offset from.
+///
+/// Counter clockwise loops shrink so the parent is the smallest counter clockwise loop enclosing
+/// `pline`, clockwise (island) loops grow so the parent is the largest clockwise loop enclosed by
+/// `pline`. Falls back to the nearest loop if no enclosing loop is found.
+fn find_parent<T>(
+    prev_loops: &[PocketLoop<T>],
+    pline: &Polyline<T>,
+    pos_equal_eps: T,
+) -> Option<usize>
+where
+    T: Real,
+{
+    let is_ccw = pline.orientation() == PlineOrientation::CounterClockwise;
+    let pos = pline.at(0).pos();
+    let mut best: Option<(usize, T)> = None;
+    for (i, l) in prev_loops.iter().enumerate() {
+        let area = l.pline.area();
+        if (area > T::zero()) != is_ccw {
+            continue;
+        }
+
+        let enclosed = if is_ccw {
+            l.pline.winding_number(pos) != 0
+        } else {
+            pline.winding_number(l.pline.at(0).pos()) != 0
+        };
+
+        if !enclosed {
+            continue;
+        }
+
+        // minimum signed area picks the smallest enclosing ccw loop or largest enclosed cw loop
+        if best.is_none_or(|(_, a)| area < a) {
+            best = Some((i, area));
+        }
+    }
+
+    if let Some((i, _)) = best {
+        return Some(i);
+    }
+
+    let mut nearest: Option<(usize, T)> = None;
+    for (i, l) in prev_loops.iter().enumerate() {
+        if let Some(closest) = l.pline.closest_point(pos, pos_equal_eps)
+            && nearest.is_none_or(|(_, d)| closest.distance < d)
+        {
+            nearest = Some((i, closest.distance));
+        }
+    }
+
+    nearest.map(|(i, _)| i)
+}
@@ -0,0 +1,288 @@
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    dxf::{DxfError, DxfReadOptions, read_dxf, read_dxf_opt, write_dxf},
+    pline_closed, pline_open,
+    polyline::{PlineSource, Polyline},
+};
+use std::f64::consts::PI;
+
+/// Wrap entity group pairs in an ENTITIES section.
+fn entities_dxf(entities: &str) -> String {
+    format!("0\nSECTION\n2\nENTITIES\n{entities}0\nENDSEC\n0\nEOF\n")
+}
+
+fn assert_plines_eq(result: &[Polyline], expected: &[Polyline]) {
+    assert_eq!(
+        result.len(),
+        expected.len(),
+        "polyline count does not match"
+    );
+    for (r, e) in result.iter().zip(expected) {
+        assert!(r.fuzzy_eq(e), "polylines do not match\n{r:?}\n{e:?}");
+    }
+}
+
+#[test]
+fn write_then_read_round_trip() {
+    let plines = vec![
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.5),
+            (10.0, 10.0, -0.25),
+            (0.0, 10.0, 0.0)
+        ],
+        pline_open![
+            (1.5, -2.25, 1.0),
+            (3.0, 4.0, 0.0),
+            (7.125, 1e-7, -0.75),
+            (8.0, 8.0, 0.0)
+        ],
+        pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)],
+        pline_open![(0.1, 0.2, 0.0), (0.3, 0.4, 0.0)],
+    ];
+
+    let dxf_str = write_dxf(&plines);
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_plines_eq(&result, &plines);
+}
+
+#[test]
+fn write_skips_empty() {
+    let plines = vec![
+        Polyline::new(),
+        pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+    ];
+    let result: Vec<Polyline> = read_dxf(&write_dxf(&plines)).unwrap();
+    assert_plines_eq(&result, &plines[1..]);
+}
+
+#[test]
+fn write_header_and_handles() {
+    let plines = vec![
+        pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+        pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)],
+    ];
+    let dxf_str = write_dxf(&plines);
+    let lines = dxf_str.lines().map(str::trim).collect::<Vec<_>>();
+    let value_after = |code: &str, value: &str| {
+        lines
+            .chunks(2)
+            .position(|pair| pair == [code, value])
+            .map(|i| lines[2 * i + 3])
+    };
+
+    assert_eq!(lines[..4], ["0", "SECTION", "2", "HEADER"]);
+    assert_eq!(value_after("9", "$ACADVER"), Some("AC1015"));
+    assert_eq!(value_after("9", "$HANDSEED"), Some("3"));
+
+    // each entity has a unique handle and AcDbEntity subclass marker before the layer
+    let entities = dxf_str.split("LWPOLYLINE\n").skip(1).collect::<Vec<_>>();
+    assert_eq!(entities.len(), 2);
+    for (i, entity) in entities.iter().enumerate() {
+        let expected = format!(
+            "  5\n{}\n100\nAcDbEntity\n  8\n0\n100\nAcDbPolyline\n",
+            i + 1
+        );
+        assert!(entity.starts_with(&expected), "{entity}");
+    }
+}
+
+#[test]
+fn read_lwpolyline() {
+    // closed flag with last vertex repeating the first (removed) and bulges
+    let dxf_str = entities_dxf(
+        "0\nLWPOLYLINE\n8\n0\n90\n4\n70\n1\n\
+         10\n0.0\n20\n0.0\n\
+         10\n10.0\n20\n0.0\n42\n1.0\n\
+         10\n10.0\n20\n10.0\n\
+         10\n0.0\n20\n0.0\n",
+    );
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_plines_eq(
+        &result,
+        &[pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 1.0),
+            (10.0, 10.0, 0.0)
+        ]],
+    );
+}
+
+#[test]
+fn read_polyline_with_vertexes() {
+    let dxf_str = entities_dxf(
+        "0\nPOLYLINE\n8\n0\n66\n1\n70\n0\n\
+         0\nVERTEX\n8\n0\n10\n0.0\n20\n0.0\n42\n-0.5\n\
+         0\nVERTEX\n8\n0\n10\n5.0\n20\n0.0\n\
+         0\nVERTEX\n8\n0\n10\n5.0\n20\n5.0\n\
+         0\nSEQEND\n8\n0\n\
+         0\nPOLYLINE\n8\n0\n66\n1\n70\n1\n\
+         0\nVERTEX\n8\n0\n10\n0.0\n20\n0.0\n\
+         0\nVERTEX\n8\n0\n10\n1.0\n20\n0.0\n\
+         0\nVERTEX\n8\n0\n10\n1.0\n20\n1.0\n\
+         0\nSEQEND\n8\n0\n",
+    );
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_plines_eq(
+        &result,
+        &[
+            pline_open![(0.0, 0.0, -0.5), (5.0, 0.0, 0.0), (5.0, 5.0, 0.0)],
+            pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)],
+        ],
+    );
+}
+
+#[test]
+fn read_polyline_skips_meshes() {
+    let dxf_str = entities_dxf(
+        "0\nPOLYLINE\n8\n0\n66\n1\n70\n64\n\
+         0\nVERTEX\n8\n0\n10\n0.0\n20\n0.0\n\
+         0\nVERTEX\n8\n0\n10\n5.0\n20\n0.0\n\
+         0\nSEQEND\n8\n0\n",
+    );
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn read_circle() {
+    let dxf_str = entities_dxf("0\nCIRCLE\n8\n0\n10\n1.0\n20\n2.0\n40\n3.0\n");
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_plines_eq(&result, &[pline_closed![(-2.0, 2.0, 1.0), (4.0, 2.0, 1.0)]]);
+    assert!(result[0].area().fuzzy_eq(9.0 * PI));
+}
+
+#[test]
+fn read_lines_and_arcs_chained_closed() {
+    // stadium shape with entities out of order and top line reversed
+    let dxf_str = entities_dxf(
+        "0\nARC\n8\n0\n10\n10.0\n20\n5.0\n40\n5.0\n50\n270.0\n51\n90.0\n\
+         0\nLINE\n8\n0\n10\n0.0\n20\n10.0\n11\n10.0\n21\n10.0\n\
+         0\nLINE\n8\n0\n10\n0.0\n20\n0.0\n11\n10.0\n21\n0.0\n\
+         0\nARC\n8\n0\n10\n0.0\n20\n5.0\n40\n5.0\n50\n90.0\n51\n270.0\n",
+    );
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_eq!(result.len(), 1);
+    let pline = &result[0];
+    assert!(pline.is_closed());
+    assert_eq!(pline.vertex_count(), 4);
+    assert!(pline.area().abs().fuzzy_eq(100.0 + 25.0 * PI));
+    assert!(pline.path_length().fuzzy_eq(20.0 + 10.0 * PI));
+}
+
+#[test]
+fn read_splits_large_arcs() {
+    // 3/4 circle arc entity and polyline vertex bulge greater than 1
+    let bulge = (3.0 * PI / 2.0 / 4.0).tan();
+    let dxf_str = entities_dxf(&format!(
+        "0\nARC\n8\n0\n10\n0.0\n20\n0.0\n40\n2.0\n50\n0.0\n51\n270.0\n\
+         0\nLWPOLYLINE\n8\n0\n90\n2\n70\n0\n\
+         10\n0.0\n20\n0.0\n42\n{bulge}\n\
+         10\n2.0\n20\n2.0\n"
+    ));
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_eq!(result.len(), 2);
+    for pline in result.iter() {
+        assert_eq!(pline.vertex_count(), 3);
+        assert!(pline.iter_vertexes().all(|v| v.bulge.abs() <= 1.0));
+    }
+
+    // polyline arc is centered at (2, 0)
+    let half_bulge = (3.0 * PI / 4.0 / 4.0).tan();
+    let sqrt2 = 2.0f64.sqrt();
+    assert_plines_eq(
+        &result[..1],
+        &[pline_open![
+            (0.0, 0.0, half_bulge),
+            (2.0 + sqrt2, -sqrt2, half_bulge),
+            (2.0, 2.0, 0.0)
+        ]],
+    );
+    assert!(result[1].path_length().fuzzy_eq(3.0 * PI));
+    assert!(result[1][0].pos().fuzzy_eq(Vector2::new(2.0, 0.0)));
+}
+
+#[test]
+fn read_lines_chained_open_from_middle() {
+    // first segment is in the middle of the chain so chain must be extended in both directions
+    let dxf_str = entities_dxf(
+        "0\nLINE\n8\n0\n10\n5.0\n20\n0.0\n11\n5.0\n21\n5.0\n\
+         0\nLINE\n8\n0\n10\n5.0\n20\n0.0\n11\n0.0\n21\n0.0\n\
+         0\nLINE\n8\n0\n10\n5.0\n20\n5.0\n11\n10.0\n21\n5.0\n\
+         0\nLINE\n8\n0\n10\n20.0\n20\n20.0\n11\n30.0\n21\n20.0\n",
+    );
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_plines_eq(
+        &result,
+        &[
+            pline_open![
+                (0.0, 0.0, 0.0),
+                (5.0, 0.0, 0.0),
+                (5.0, 5.0, 0.0),
+                (10.0, 5.0, 0.0)
+            ],
+            pline_open![(20.0, 20.0, 0.0), (30.0, 20.0, 0.0)],
+        ],
+    );
+}
+
+#[test]
+fn read_chain_uses_pos_equal_eps() {
+    let dxf_str = entities_dxf(
+        "0\nLINE\n8\n0\n10\n0.0\n20\n0.0\n11\n10.0\n21\n0.0\n\
+         0\nLINE\n8\n0\n10\n10.001\n20\n0.0\n11\n10.0\n21\n10.0\n",
+    );
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_eq!(result.len(), 2);
+
+    let options = DxfReadOptions {
+        pos_equal_eps: 1e-2,
+    };
+    let result: Vec<Polyline> = read_dxf_opt(&dxf_str, &options).unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].vertex_count(), 3);
+}
+
+#[test]
+fn read_flipped_extrusion() {
+    // half circle arc with negative Z extrusion is mirrored in X and becomes clockwise
+    let dxf_str = entities_dxf(
+        "0\nARC\n8\n0\n10\n5.0\n20\n0.0\n30\n0.0\n40\n5.0\n210\n0.0\n220\n0.0\n230\n-1.0\n\
+         50\n0.0\n51\n180.0\n",
+    );
+    let result: Vec<Polyline> = read_dxf(&dxf_str).unwrap();
+    assert_plines_eq(&result, &[pline_open![(-10.0, 0.0, -1.0), (0.0, 0.0, 0.0)]]);
+}
+
+#[test]
+fn read_ignores_other_sections_and_entities() {
+    let dxf_str = "0\nSECTION\n2\nHEADER\n9\n$ACADVER\n1\nAC1015\n0\nENDSEC\n\
+                   0\nSECTION\n2\nBLOCKS\n0\nBLOCK\n8\n0\n\
+                   0\nLINE\n8\n0\n10\n0.0\n20\n0.0\n11\n1.0\n21\n0.0\n\
+                   0\nENDBLK\n8\n0\n0\nENDSEC\n\
+                   0\nSECTION\n2\nENTITIES\n\
+                   0\nTEXT\n8\n0\n10\n0.0\n20\n0.0\n40\n1.0\n1\nhello\n\
+                   0\nLINE\n8\n0\n10\n0.0\n20\n0.0\n11\n2.0\n21\n0.0\n\
+                   0\nENDSEC\n0\nEOF\n\n";
+    let result: Vec<Polyline> = read_dxf(dxf_str).unwrap();
+    assert_plines_eq(&result, &[pline_open![(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)]]);
+}
+
+#[test]
+fn read_errors() {
+    let result = read_dxf::<f64>("0\nSECTION\nabc\nENTITIES\n").unwrap_err();
+    assert_eq!(result, DxfError::InvalidGroupCode { line: 3 });
+
+    let result =
+        read_dxf::<f64>(&entities_dxf("0\nCIRCLE\n10\n1.0\n20\nabc\n40\n3.0\n")).unwrap_err();
+    assert_eq!(
+        result,
+        DxfError::InvalidValue {
+            line: 10,
+            group_code: 20
+        }
+    );
+
+    let result = read_dxf::<f64>("0\nSECTION\n2\n").unwrap_err();
+    assert_eq!(result, DxfError::UnexpectedEndOfInput);
+}
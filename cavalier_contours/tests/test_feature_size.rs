@@ -0,0 +1,211 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{PlineSource, PlineSourceMut, Polyline},
+    shape_algorithms::Shape,
+};
+use test_utils::{arc_neck_dumbbell, polygon_neck_dumbbell, rectangle};
+
+const EPS: f64 = 1e-5;
+
+mod min_feature_width {
+    use super::*;
+
+    #[test]
+    fn rectangle_width() {
+        let result = rectangle(0.0, 0.0, 10.0, 4.0).min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(4.0, EPS));
+        assert!(
+            (result.point1 - result.point2)
+                .length()
+                .fuzzy_eq(result.width)
+        );
+        let ys = [result.point1.y, result.point2.y];
+        assert!(ys.iter().any(|y| y.fuzzy_eq(0.0)));
+        assert!(ys.iter().any(|y| y.fuzzy_eq(4.0)));
+    }
+
+    #[test]
+    fn orientation_does_not_matter() {
+        for pline in [
+            rectangle(0.0, 0.0, 10.0, 4.0),
+            arc_neck_dumbbell(),
+            polygon_neck_dumbbell(64),
+        ] {
+            let mut inverted = pline.clone();
+            inverted.invert_direction_mut();
+            let w1 = pline.min_feature_width().unwrap().width;
+            let w2 = inverted.min_feature_width().unwrap().width;
+            assert!(w1.fuzzy_eq_eps(w2, EPS));
+        }
+    }
+
+    #[test]
+    fn circle_width_is_diameter() {
+        let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+        let result = circle.min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(10.0, EPS));
+    }
+
+    #[test]
+    fn obround_width() {
+        let obround = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 1.0),
+            (10.0, 4.0, 0.0),
+            (0.0, 4.0, 1.0)
+        ];
+        let result = obround.min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(4.0, EPS));
+    }
+
+    #[test]
+    fn arc_neck() {
+        let result = arc_neck_dumbbell().min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(1.0, EPS));
+        let mut points = [result.point1, result.point2];
+        points.sort_by(|a, b| a.y.total_cmp(&b.y));
+        assert!(points[0].fuzzy_eq_eps(Vector2::new(5.0, 0.0), EPS));
+        assert!(points[1].fuzzy_eq_eps(Vector2::new(5.0, 1.0), EPS));
+    }
+
+    #[test]
+    fn reflex_corner_to_side() {
+        // L shape, narrowest is from the inner corner across the arm to the outer sides
+        let l_shape = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 3.0, 0.0),
+            (2.0, 3.0, 0.0),
+            (2.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let result = l_shape.min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(2.0, EPS));
+
+        // notch cut into a rectangle leaving a 1 wide bridge, narrowest is from the notch tip
+        let notched = pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (5.0, 3.0, 0.0),
+            (6.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 4.0, 0.0),
+            (0.0, 4.0, 0.0)
+        ];
+        let result = notched.min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(1.0, EPS));
+        assert!(
+            result.point1.fuzzy_eq(Vector2::new(5.0, 3.0))
+                || result.point2.fuzzy_eq(Vector2::new(5.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn gap_outside_region_not_counted() {
+        // U shape with a 1 wide slot between 2 wide arms, the slot is outside the region
+        let u_shape = pline_closed![
+            (0.0, 0.0, 0.0),
+            (5.0, 0.0, 0.0),
+            (5.0, 10.0, 0.0),
+            (3.0, 10.0, 0.0),
+            (3.0, 5.0, 0.0),
+            (2.0, 5.0, 0.0),
+            (2.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let result = u_shape.min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(2.0, EPS));
+    }
+
+    #[test]
+    fn polygon_neck() {
+        // neck formed by reflex vertexes, many segments so most are skipped by the spatial index
+        let pline = polygon_neck_dumbbell(500);
+        let result = pline.min_feature_width().unwrap();
+        assert!(result.width.fuzzy_eq_eps(1.0, EPS));
+        let center = (result.point1 + result.point2).scale(0.5);
+        assert!(center.fuzzy_eq_eps(Vector2::new(5.0, 0.5), EPS));
+        assert!(pline.winding_number(center) != 0);
+    }
+
+    #[test]
+    fn no_width() {
+        let triangle = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (3.0, 6.0, 0.0)];
+        assert!(triangle.min_feature_width().is_none());
+        let open = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        assert!(open.min_feature_width().is_none());
+        assert!(Polyline::<f64>::new_closed().min_feature_width().is_none());
+    }
+}
+
+mod max_inscribed_circle {
+    use super::*;
+
+    #[test]
+    fn rectangle_circle() {
+        let circle = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 4.0)])
+            .max_inscribed_circle(1e-4)
+            .unwrap();
+        assert!(circle.radius.fuzzy_eq_eps(2.0, 1e-4));
+        assert!(circle.center.y.fuzzy_eq_eps(2.0, 1e-4));
+        assert!(circle.center.x > 2.0 - 1e-4 && circle.center.x < 8.0 + 1e-4);
+    }
+
+    #[test]
+    fn circle_and_triangle() {
+        let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+        let result = Shape::from_plines([circle])
+            .max_inscribed_circle(1e-5)
+            .unwrap();
+        assert!(result.center.fuzzy_eq_eps(Vector2::new(5.0, 0.0), 1e-2));
+        assert!(result.radius.fuzzy_eq_eps(5.0, 1e-5));
+
+        let triangle = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (3.0, 6.0, 0.0)];
+        let result = Shape::from_plines([triangle])
+            .max_inscribed_circle(1e-5)
+            .unwrap();
+        let perimeter = 85.0f64.sqrt() + 45.0f64.sqrt() + 10.0;
+        let inradius = 30.0 / (perimeter / 2.0);
+        assert!(result.radius.fuzzy_eq_eps(inradius, 1e-5));
+    }
+
+    #[test]
+    fn holes_are_avoided() {
+        // square with a circle hole in the middle, largest circle fits in a corner region
+        let mut hole = pline_closed![(2.0, 5.0, 1.0), (8.0, 5.0, 1.0)];
+        hole.invert_direction_mut();
+        let outer = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let shape = Shape::from_plines([outer, hole]);
+        let result = shape.max_inscribed_circle(1e-5).unwrap();
+        assert!(shape.contains_point(result.center));
+        let closest = shape.closest_point(result.center, EPS).unwrap();
+        assert!(closest.distance.fuzzy_eq_eps(result.radius, 1e-8));
+        // circle in corner touching two sides and the hole: r + r * sqrt(2) + 3 = 5 * sqrt(2)
+        let expected = (5.0 * 2.0f64.sqrt() - 3.0) / (1.0 + 2.0f64.sqrt());
+        assert!(result.radius.fuzzy_eq_eps(expected, 1e-5));
+    }
+
+    #[test]
+    fn radius_within_tolerance() {
+        let shape = Shape::from_plines([polygon_neck_dumbbell(100)]);
+        let coarse = shape.max_inscribed_circle(1e-1).unwrap();
+        let fine = shape.max_inscribed_circle(1e-6).unwrap();
+        assert!(coarse.radius <= fine.radius + 1e-6);
+        assert!(fine.radius - coarse.radius <= 1e-1);
+    }
+
+    #[test]
+    fn empty_or_invalid_tolerance() {
+        assert!(Shape::<f64>::empty().max_inscribed_circle(1e-5).is_none());
+        let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 4.0)]);
+        assert!(shape.max_inscribed_circle(0.0).is_none());
+    }
+}
@@ -0,0 +1,154 @@
+use cavalier_contours::{
+    pline_closed, pline_open,
+    polyline::{
+        BooleanOp, BooleanResultPline, OffsetContext, PlineBooleanOptions, PlineOffsetOptions,
+        PlineSource, PlineVertex, Polyline,
+    },
+};
+
+fn test_plines() -> Vec<Polyline> {
+    vec![
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ],
+        // closed with arcs and a narrow neck (pinches off when offset inward)
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.5),
+            (10.0, 4.0, 0.0),
+            (6.0, 4.5, 0.0),
+            (10.0, 5.0, -0.5),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0),
+            (0.0, 5.0, 0.0),
+            (4.0, 4.5, 0.0),
+            (0.0, 4.0, 0.0)
+        ],
+        // circle
+        pline_closed![(0.0, 0.0, 1.0), (4.0, 0.0, 1.0)],
+        // open
+        pline_open![
+            (0.0, 0.0, 0.0),
+            (5.0, 0.0, 0.5),
+            (5.0, 5.0, 0.0),
+            (0.0, 5.0, -1.0),
+            (0.0, 8.0, 0.0)
+        ],
+        // figure eight (self intersecting)
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (4.0, 4.0, 0.0),
+            (4.0, 0.0, 0.0),
+            (0.0, 4.0, 0.0)
+        ],
+    ]
+}
+
+fn vertexes(plines: &[Polyline]) -> Vec<(bool, Vec<PlineVertex>)> {
+    plines
+        .iter()
+        .map(|p| (p.is_closed(), p.iter_vertexes().collect()))
+        .collect()
+}
+
+fn result_vertexes(results: &[BooleanResultPline<Polyline>]) -> Vec<(bool, Vec<PlineVertex>)> {
+    let plines: Vec<_> = results.iter().map(|r| r.pline.clone()).collect();
+    vertexes(&plines)
+}
+
+#[test]
+fn offset_same_with_context() {
+    let context = OffsetContext::new();
+    for handle_self_intersects in [false, true] {
+        let options = PlineOffsetOptions {
+            handle_self_intersects,
+            ..Default::default()
+        };
+        let context_options = PlineOffsetOptions {
+            context: Some(&context),
+            ..options.clone()
+        };
+
+        for pline in test_plines() {
+            for offset in [-2.0, -0.5, 0.25, 1.0, 2.5] {
+                let expected = pline.parallel_offset_opt(offset, &options);
+                let result = pline.parallel_offset_opt(offset, &context_options);
+                assert_eq!(vertexes(&result), vertexes(&expected));
+            }
+        }
+    }
+}
+
+#[test]
+fn boolean_same_with_context() {
+    let context = OffsetContext::new();
+    let context_options = PlineBooleanOptions {
+        context: Some(&context),
+        ..Default::default()
+    };
+
+    let plines = test_plines();
+    let others = [
+        pline_closed![(3.0, 3.0, 1.0), (13.0, 3.0, 1.0)],
+        pline_closed![
+            (-1.0, 2.0, 0.0),
+            (11.0, 2.0, 0.0),
+            (11.0, 8.0, 0.0),
+            (-1.0, 8.0, 0.0)
+        ],
+    ];
+
+    for pline in plines.iter().filter(|p| p.is_closed()).take(3) {
+        for other in others.iter() {
+            for op in [
+                BooleanOp::Or,
+                BooleanOp::And,
+                BooleanOp::Not,
+                BooleanOp::Xor,
+            ] {
+                let expected = pline.boolean(other, op);
+                let result = pline.boolean_opt(other, op, &context_options);
+                assert_eq!(
+                    result_vertexes(&result.pos_plines),
+                    result_vertexes(&expected.pos_plines)
+                );
+                assert_eq!(
+                    result_vertexes(&result.neg_plines),
+                    result_vertexes(&expected.neg_plines)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn shared_between_threads() {
+    let context = OffsetContext::new();
+    let options = PlineOffsetOptions {
+        context: Some(&context),
+        ..Default::default()
+    };
+
+    let plines = test_plines();
+    let expected: Vec<_> = plines
+        .iter()
+        .map(|p| vertexes(&p.parallel_offset(0.5)))
+        .collect();
+
+    // calls made while the context is in use by another thread allocate their own buffers
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..10 {
+                    for (pline, expected) in plines.iter().zip(expected.iter()) {
+                        let result = pline.parallel_offset_opt(0.5, &options);
+                        assert_eq!(&vertexes(&result), expected);
+                    }
+                }
+            });
+        }
+    });
+}
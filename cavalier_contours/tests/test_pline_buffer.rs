@@ -0,0 +1,123 @@
+mod test_utils;
+
+use cavalier_contours::polyline::{EndCapStyle, PlineBufferOptions, PlineSource, Polyline};
+use test_utils::{ModifiedPlineSet, PlineProperties, create_property_set, property_sets_match};
+
+fn run_pline_buffer_tests(
+    input: &Polyline<f64>,
+    distance: f64,
+    end_cap: EndCapStyle,
+    expected_properties_set: &[PlineProperties],
+) {
+    let options = PlineBufferOptions {
+        end_cap,
+        ..Default::default()
+    };
+
+    // buffer result does not depend on direction or start index of the polyline
+    ModifiedPlineSet::new(input, true, true).accept_closure(&mut |modified_pline, pline_state| {
+        let buffer_results = modified_pline.buffer_opt(distance, &options);
+        for r in buffer_results.iter() {
+            assert!(r.is_closed(), "buffer result should be closed");
+            assert!(
+                r.remove_repeat_pos(PlineProperties::POS_EQ_EPS).is_none(),
+                "buffer result should not have repeat positioned vertexes",
+            );
+        }
+        let result_properties = create_property_set(&buffer_results, false);
+        assert!(
+            property_sets_match(&result_properties, expected_properties_set),
+            "property sets do not match for {end_cap:?}, modified state: {pline_state:?}"
+        );
+    });
+}
+
+macro_rules! declare_buffer_tests {
+    ($($name:ident { $($value:expr => $expected:expr),+ $(,)? })*) => {
+        $(
+            #[test]
+            fn $name() {
+                $(
+                    let (input, distance, end_cap): (Polyline, f64, EndCapStyle) = $value;
+                    run_pline_buffer_tests(&input, distance, end_cap, &$expected);
+                )+
+            }
+        )+
+    };
+}
+
+mod test_simple {
+    use super::*;
+    use cavalier_contours::{pline_closed_userdata, pline_open_userdata};
+    use std::f64::consts::PI;
+
+    declare_buffer_tests!(
+        empty_returns_empty {
+            (Polyline::<f64>::new(), 1.0, EndCapStyle::Round) => []
+        }
+        zero_distance_returns_empty {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0)], 0.0, EndCapStyle::Round) => []
+        }
+        line {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0)], 1.0, EndCapStyle::Round) =>
+            [PlineProperties::new(4, 20.0 + PI, 20.0 + 2.0 * PI, -1.0, -1.0, 11.0, 1.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0)], 1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(4, 20.0, 24.0, 0.0, -1.0, 10.0, 1.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0)], 1.0, EndCapStyle::Square) =>
+            [PlineProperties::new(4, 24.0, 28.0, -1.0, -1.0, 11.0, 1.0, vec![4])]
+        }
+        negative_distance_same_as_positive {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0)], -1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(4, 20.0, 24.0, 0.0, -1.0, 10.0, 1.0, vec![4])]
+        }
+        right_angle {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)], 1.0, EndCapStyle::Round) =>
+            [PlineProperties::new(7, 39.0 + 1.25 * PI, 38.0 + 2.5 * PI, -1.0, -1.0, 11.0, 11.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)], 1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(7, 39.0 + 0.25 * PI, 42.0 + 0.5 * PI, 0.0, -1.0, 11.0, 10.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)], 1.0, EndCapStyle::Square) =>
+            [PlineProperties::new(7, 43.0 + 0.25 * PI, 46.0 + 0.5 * PI, -1.0, -1.0, 11.0, 11.0, vec![4])]
+        }
+        half_circle {
+            (pline_open_userdata![[4], (0.0, 0.0, 1.0), (10.0, 0.0, 0.0)], 1.0, EndCapStyle::Round) =>
+            [PlineProperties::new(4, 11.0 * PI, 12.0 * PI, -1.0, -6.0, 11.0, 1.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 1.0), (10.0, 0.0, 0.0)], 1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(4, 10.0 * PI, 10.0 * PI + 4.0, -1.0, -6.0, 11.0, 0.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 1.0), (10.0, 0.0, 0.0)], 1.0, EndCapStyle::Square) =>
+            [PlineProperties::new(8, 10.0 * PI + 4.0, 10.0 * PI + 8.0, -1.0, -6.0, 11.0, 1.0, vec![4])]
+        }
+        closed_rectangle {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)], 1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(8, 140.0 + PI, 40.0 + 2.0 * PI, -1.0, -1.0, 11.0, 11.0, vec![4]),
+             PlineProperties::new(4, -64.0, 32.0, 1.0, 1.0, 9.0, 9.0, vec![4])]
+        }
+        open_rectangle_forms_hole {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0), (0.0, 0.0, 0.0)], 1.0, EndCapStyle::Round) =>
+            [PlineProperties::new(8, 140.0 + PI, 40.0 + 2.0 * PI, -1.0, -1.0, 11.0, 11.0, vec![4]),
+             PlineProperties::new(4, -64.0, 32.0, 1.0, 1.0, 9.0, 9.0, vec![4])],
+            // flat ends leave a square notch at the start/end corner
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0), (0.0, 0.0, 0.0)], 1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(9, 140.0 + 0.75 * PI, 42.0 + 1.5 * PI, -1.0, -1.0, 11.0, 11.0, vec![4]),
+             PlineProperties::new(4, -64.0, 32.0, 1.0, 1.0, 9.0, 9.0, vec![4])]
+        }
+    );
+}
+
+mod test_specific {
+    use super::*;
+    use cavalier_contours::pline_open_userdata;
+    use std::f64::consts::PI;
+
+    declare_buffer_tests!(
+        arc_collapsed_on_one_side {
+            (pline_open_userdata![[4], (0.0, 0.0, 1.0), (1.0, 0.0, 0.0)], 1.0, EndCapStyle::Round) =>
+            [PlineProperties::new(3, 1.125 * PI + 2.0 * PI / 3.0 + 3.0_f64.sqrt() / 4.0, 1.5 * PI + 4.0 * PI / 3.0, -1.0, -1.5, 2.0, 1.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 1.0), (1.0, 0.0, 0.0)], 1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(2, 1.125 * PI, 1.5 * PI + 3.0, -1.0, -1.5, 2.0, 0.0, vec![])]
+        }
+        u_turn_with_overlapping_ends {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 1.0, 0.0), (0.0, 1.0, 0.0)], 1.0, EndCapStyle::Butt) =>
+            [PlineProperties::new(6, 31.0 + PI / 2.0, 24.0 + PI, 0.0, -1.0, 11.0, 2.0, vec![])]
+        }
+    );
+}
@@ -0,0 +1,157 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq, pline_closed, pline_open,
+    polyline::{ClipResultPline, PlineClipResult, PlineSource, PlineSourceMut, Polyline},
+    shape_algorithms::Shape,
+};
+use std::f64::consts::PI;
+use test_utils::{square, square_hole};
+
+fn total_length(slices: &[ClipResultPline<Polyline>]) -> f64 {
+    slices.iter().map(|s| s.pline.path_length()).sum()
+}
+
+/// Check the slices view data forms the same polylines and all the slices together have the same
+/// length as the source.
+fn check_result(source: &Polyline, result: &PlineClipResult<Polyline>) {
+    let all_slices = result
+        .inside
+        .iter()
+        .chain(result.outside.iter())
+        .chain(result.on_boundary.iter());
+    let mut length = 0.0;
+    for slice in all_slices {
+        assert!(!slice.pline.is_closed());
+        let view = slice.view_data.view(source);
+        assert_fuzzy_eq!(view.path_length(), slice.pline.path_length());
+        length += slice.pline.path_length();
+    }
+    assert_fuzzy_eq!(length, source.path_length());
+}
+
+mod test_pline {
+    use super::*;
+
+    #[test]
+    fn path_crossing_circle() {
+        let circle = pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)];
+        let path = pline_open![(-2.0, 0.0, 0.0), (0.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+        let result = path.clip(&circle);
+        check_result(&path, &result);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.inside[0].pline.vertex_count(), 3);
+        assert_eq!(result.outside.len(), 2);
+        assert_fuzzy_eq!(total_length(&result.inside), 2.0);
+        assert_eq!(result.outside[0].view_data.start_index, 0);
+        assert_eq!(result.outside[1].view_data.start_index, 1);
+
+        // arc path crossing square
+        let path = pline_open![(-1.0, 2.0, -0.3), (5.0, 2.0, 0.0)];
+        let result = path.clip(&square(0.0, 0.0, 4.0));
+        check_result(&path, &result);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.outside.len(), 2);
+    }
+
+    #[test]
+    fn no_intersects() {
+        let region = square(0.0, 0.0, 10.0);
+        let inside = pline_open![(1.0, 1.0, 0.0), (5.0, 5.0, 0.5), (8.0, 2.0, 0.0)];
+        let result = inside.clip(&region);
+        check_result(&inside, &result);
+        assert_eq!(result.inside.len(), 1);
+        assert!(result.outside.is_empty());
+        assert_eq!(result.inside[0].pline.vertex_count(), 3);
+
+        let outside = pline_open![(11.0, 1.0, 0.0), (15.0, 5.0, 0.0)];
+        let result = outside.clip(&region);
+        assert!(result.inside.is_empty());
+        assert_eq!(result.outside.len(), 1);
+
+        // empty inputs
+        let result = Polyline::new().clip(&region);
+        assert!(result.inside.is_empty() && result.outside.is_empty());
+        let result = outside.clip(&Polyline::new_closed());
+        assert_eq!(result.outside.len(), 1);
+    }
+
+    #[test]
+    fn along_boundary() {
+        let region = square(0.0, 0.0, 4.0);
+        // runs along the top edge in the opposite direction then out of the region
+        let path = pline_open![(2.0, 2.0, 0.0), (2.0, 4.0, 0.0), (-1.0, 4.0, 0.0),];
+        let result = path.clip(&region);
+        check_result(&path, &result);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.on_boundary.len(), 1);
+        assert_eq!(result.outside.len(), 1);
+        assert_fuzzy_eq!(result.on_boundary[0].pline.path_length(), 2.0);
+
+        // open region has no area
+        let mut open_region = region.clone();
+        open_region.set_is_closed(false);
+        let result = path.clip(&open_region);
+        check_result(&path, &result);
+        assert!(result.inside.is_empty());
+        assert_eq!(result.on_boundary.len(), 1);
+    }
+
+    #[test]
+    fn closed_path() {
+        let region = square(0.0, 0.0, 4.0);
+        let path = square(2.0, 2.0, 4.0);
+        let result = path.clip(&region);
+        check_result(&path, &result);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.outside.len(), 1);
+        assert_fuzzy_eq!(total_length(&result.inside), 4.0);
+        assert_fuzzy_eq!(total_length(&result.outside), 12.0);
+
+        // entire closed polyline kept as one slice (open polyline with end at start)
+        let circle = pline_closed![(1.0, 2.0, 1.0), (3.0, 2.0, 1.0)];
+        let result = circle.clip(&region);
+        check_result(&circle, &result);
+        assert_eq!(result.inside.len(), 1);
+        assert_eq!(result.inside[0].pline.vertex_count(), 3);
+        assert_fuzzy_eq!(result.inside[0].pline.path_length(), 2.0 * PI);
+    }
+}
+
+mod test_shape {
+    use super::*;
+
+    #[test]
+    fn path_across_holes() {
+        let shape = Shape::from_plines([
+            square(0.0, 0.0, 10.0),
+            square_hole(2.0, 2.0, 2.0),
+            square_hole(6.0, 2.0, 2.0),
+        ]);
+        let path = pline_open![(-1.0, 3.0, 0.0), (11.0, 3.0, 0.0)];
+        let result = shape.clip_pline(&path);
+        check_result(&path, &result);
+        assert_eq!(result.inside.len(), 3);
+        assert_eq!(result.outside.len(), 4);
+        assert_fuzzy_eq!(total_length(&result.inside), 6.0);
+        // ordered along the path
+        assert_fuzzy_eq!(result.inside[0].pline[0].x, 0.0);
+        assert_fuzzy_eq!(result.inside[1].pline[0].x, 4.0);
+        assert_fuzzy_eq!(result.inside[2].pline[0].x, 8.0);
+    }
+
+    #[test]
+    fn path_along_hole_boundary() {
+        let shape = Shape::from_plines([square(0.0, 0.0, 10.0), square_hole(4.0, 4.0, 2.0)]);
+        let path = pline_open![(1.0, 4.0, 0.0), (9.0, 4.0, 0.0)];
+        let result = shape.clip_pline(&path);
+        check_result(&path, &result);
+        assert_eq!(result.inside.len(), 2);
+        assert_eq!(result.on_boundary.len(), 1);
+        assert!(result.outside.is_empty());
+        assert_fuzzy_eq!(result.on_boundary[0].pline.path_length(), 2.0);
+
+        assert!(Shape::empty().clip_pline(&path).inside.is_empty());
+        assert_eq!(Shape::empty().clip_pline(&path).outside.len(), 1);
+    }
+}
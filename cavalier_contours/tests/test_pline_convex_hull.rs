@@ -0,0 +1,261 @@
+use cavalier_contours::{
+    core::{
+        math::{Vector2, angle, angle_from_bulge},
+        traits::FuzzyEq,
+    },
+    pline_closed, pline_closed_userdata, pline_open,
+    polyline::{
+        PlineSource, PlineSourceMut, PlineVertex, Polyline, plines_convex_hull,
+        seg_arc_radius_and_center, seg_tangent_vector,
+    },
+};
+use std::f64::consts::PI;
+
+const EPS: f64 = 1e-5;
+
+/// Polyline with only line segments going through points sampled along all the segments.
+fn sampled(pline: &Polyline, samples_per_arc: usize) -> Polyline {
+    let mut result = Polyline::new_closed();
+    for (v1, v2) in pline.iter_segments() {
+        if v1.bulge_is_zero() {
+            result.add(v1.x, v1.y, 0.0);
+            continue;
+        }
+        let (radius, center) = seg_arc_radius_and_center(v1, v2);
+        let start = angle(center, v1.pos());
+        let sweep = angle_from_bulge(v1.bulge);
+        for i in 0..samples_per_arc {
+            let a = start + sweep * i as f64 / samples_per_arc as f64;
+            result.add(
+                center.x + radius * a.cos(),
+                center.y + radius * a.sin(),
+                0.0,
+            );
+        }
+    }
+    if let Some(last) = pline.last() {
+        result.add(last.x, last.y, 0.0);
+    }
+    result
+}
+
+/// Check the hull is closed, counter clockwise, convex, contains all the input polylines, and has
+/// the same area as the hull of densely sampled input points.
+fn assert_valid_hull(hull: &Polyline, inputs: &[&Polyline]) {
+    assert!(hull.is_closed());
+    assert!(hull.area() > 0.0);
+
+    for (i, j) in hull.iter_segment_indexes() {
+        let v1 = hull[i];
+        let v2 = hull[j];
+        assert!(v1.bulge >= 0.0, "hull arcs must be counter clockwise");
+        let v3 = hull[hull.next_wrapping_index(j)];
+        let t_in = seg_tangent_vector(v1, v2, v2.pos()).normalize();
+        let t_out = seg_tangent_vector(v2, v3, v2.pos()).normalize();
+        assert!(t_in.perp_dot(t_out) > -EPS, "hull must only turn left");
+    }
+
+    let sampled_inputs = inputs.iter().map(|p| sampled(p, 400)).collect::<Vec<_>>();
+    for s in sampled_inputs.iter() {
+        for v in s.iter_vertexes() {
+            let inside = hull.winding_number(v.pos()) != 0
+                || hull.closest_point(v.pos(), EPS).unwrap().distance < EPS;
+            assert!(inside, "input point {:?} outside of hull", v.pos());
+        }
+    }
+
+    let sampled_hull = plines_convex_hull(sampled_inputs.iter());
+    let area = hull.area();
+    assert!(sampled_hull.area() <= area + EPS);
+    assert!((area - sampled_hull.area()) / area < 1e-3);
+}
+
+#[test]
+fn lines_only() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (5.0, 3.0, 0.0),
+        (6.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (5.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let hull = pline.convex_hull();
+    assert_valid_hull(&hull, &[&pline]);
+    // notch and collinear vertexes removed
+    assert_eq!(hull.vertex_count(), 4);
+    assert!(hull.area().fuzzy_eq(100.0));
+}
+
+#[test]
+fn clockwise_input_and_userdata() {
+    let mut pline = pline_closed_userdata![
+        [3, 4],
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.5),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let expected = pline.convex_hull();
+    pline.invert_direction_mut();
+    let hull = pline.convex_hull();
+    assert_valid_hull(&hull, &[&pline]);
+    assert!(hull.area().fuzzy_eq_eps(expected.area(), EPS));
+    assert_eq!(hull.get_userdata_values().collect::<Vec<_>>(), [3, 4]);
+}
+
+#[test]
+fn circle_is_unchanged() {
+    for circle in [
+        pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)],
+        pline_closed![(0.0, 0.0, -1.0), (10.0, 0.0, -1.0)],
+    ] {
+        let hull = circle.convex_hull();
+        assert_valid_hull(&hull, &[&circle]);
+        assert_eq!(hull.vertex_count(), 2);
+        assert!(hull[0].bulge.fuzzy_eq(1.0));
+        assert!(hull[1].bulge.fuzzy_eq(1.0));
+        assert!(hull.area().fuzzy_eq_eps(25.0 * PI, EPS));
+    }
+}
+
+#[test]
+fn concave_arc_is_removed() {
+    // top side arc bulges into the rectangle
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 4.0, -0.5),
+        (0.0, 4.0, 0.0)
+    ];
+    let hull = pline.convex_hull();
+    assert_valid_hull(&hull, &[&pline]);
+    assert_eq!(hull.vertex_count(), 4);
+    assert!(hull.iter_vertexes().all(|v| v.bulge_is_zero()));
+    assert!(hull.area().fuzzy_eq(40.0));
+}
+
+#[test]
+fn arc_portions_joined_by_tangents() {
+    let square = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let circle = pline_closed![(7.0, 5.0, 1.0), (13.0, 5.0, 1.0)];
+    let hull = plines_convex_hull([&square, &circle]);
+    assert_valid_hull(&hull, &[&square, &circle]);
+
+    // tangents from (10, 0) and (10, 10) to the circle have length 4, the arc portion between the
+    // tangent points sweeps PI - 2 * acos(3 / 5)
+    let expected_area = 100.0 + 2.0 * 6.0 + 4.5 * (PI - 2.0 * (0.6f64).acos());
+    assert!(hull.area().fuzzy_eq_eps(expected_area, EPS));
+
+    let arc_count = hull.iter_vertexes().filter(|v| !v.bulge_is_zero()).count();
+    // circle is made of two arcs so the portion on the hull is split at the right most point
+    assert_eq!(arc_count, 2);
+    for (v1, v2) in hull.iter_segments().filter(|(v1, _)| !v1.bulge_is_zero()) {
+        let (radius, center) = seg_arc_radius_and_center(v1, v2);
+        assert!(radius.fuzzy_eq_eps(3.0, EPS));
+        assert!(center.fuzzy_eq_eps(Vector2::new(10.0, 5.0), EPS));
+    }
+}
+
+#[test]
+fn tangent_arcs() {
+    // smaller circle touching the inside of the larger circle at the bottom
+    let large = pline_closed![(3.0, 4.0, 1.0), (11.0, 4.0, 1.0)];
+    let small = pline_closed![(4.0, 3.0, 1.0), (10.0, 3.0, 1.0)];
+    let hull = plines_convex_hull([&small, &large]);
+    assert_valid_hull(&hull, &[&small, &large]);
+    assert!(hull.area().fuzzy_eq_eps(16.0 * PI, EPS));
+
+    // obround inside a circle touching at the bottom right
+    let circle = pline_closed![(1.0, 8.0, 1.0), (5.0, 8.0, 1.0)];
+    let obround = pline_closed![
+        (0.0, 6.0, 0.0),
+        (3.0, 6.0, 1.0),
+        (3.0, 7.0, 0.0),
+        (0.0, 7.0, 1.0)
+    ];
+    let hull = plines_convex_hull([&circle, &obround]);
+    assert_valid_hull(&hull, &[&circle, &obround]);
+
+    // start point (lowest vertex) lies part way along a circle arc
+    let circle = pline_closed![(3.0, 1.0, 1.0), (5.0, 1.0, 1.0)];
+    let point = pline_open![(4.0, 0.0, 0.0)];
+    let hull = plines_convex_hull([&circle, &point]);
+    assert_valid_hull(&hull, &[&circle, &point]);
+    assert!(hull.area().fuzzy_eq_eps(PI, EPS));
+}
+
+#[test]
+fn open_polylines() {
+    // half circle
+    let arc = pline_open![(0.0, 0.0, 1.0), (10.0, 0.0, 0.0)];
+    let hull = arc.convex_hull();
+    assert_valid_hull(&hull, &[&arc]);
+    assert_eq!(hull.vertex_count(), 2);
+    assert!(hull.area().fuzzy_eq_eps(12.5 * PI, EPS));
+
+    let zig_zag = pline_open![
+        (0.0, 0.0, 0.0),
+        (2.0, 5.0, 0.0),
+        (4.0, 0.0, 0.5),
+        (6.0, 5.0, 0.0)
+    ];
+    let hull = plines_convex_hull([&zig_zag, &arc]);
+    assert_valid_hull(&hull, &[&zig_zag, &arc]);
+}
+
+#[test]
+fn random_polylines() {
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    };
+
+    for _ in 0..40 {
+        let mut plines = Vec::new();
+        for _ in 0..3 {
+            let mut pline = Polyline::new();
+            pline.set_is_closed(next() < 0.5);
+            let count = 2 + (next() * 8.0) as usize;
+            for _ in 0..count {
+                let bulge = if next() < 0.3 {
+                    0.0
+                } else {
+                    next() * 2.0 - 1.0
+                };
+                pline.add(next() * 20.0, next() * 20.0, bulge);
+            }
+            plines.push(pline);
+        }
+        let hull = plines_convex_hull(plines.iter());
+        assert_valid_hull(&hull, &plines.iter().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn degenerate_inputs() {
+    let empty = Polyline::<f64>::new_closed();
+    let hull = empty.convex_hull();
+    assert!(hull.is_closed());
+    assert_eq!(hull.vertex_count(), 0);
+
+    let point = pline_open![(1.0, 2.0, 0.0)];
+    let hull = point.convex_hull();
+    assert_eq!(hull.vertex_count(), 1);
+    assert!(hull[0].fuzzy_eq(PlineVertex::new(1.0, 2.0, 0.0)));
+
+    let line = pline_open![(0.0, 0.0, 0.0), (1.0, 1.0, 0.0), (3.0, 3.0, 0.0)];
+    let hull = line.convex_hull();
+    assert_eq!(hull.vertex_count(), 2);
+    assert!(hull.area().fuzzy_eq(0.0));
+}
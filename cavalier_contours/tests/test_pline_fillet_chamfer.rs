@@ -0,0 +1,276 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::Vector2,
+    pline_closed, pline_closed_userdata, pline_open,
+    polyline::{AllCorners, PlineSource, PlineSourceMut, Polyline, seg_tangent_vector},
+};
+use std::f64::consts::PI;
+use test_utils::square;
+
+/// Assert every vertex of `result` lies on `input` (fillets and chamfers only cut between points
+/// on the input).
+fn assert_vertexes_on_input(input: &Polyline, result: &Polyline) {
+    for v in result.iter_vertexes() {
+        let closest = input.closest_point(v.pos(), 1e-5).unwrap();
+        assert!(
+            closest.distance < 1e-5,
+            "vertex {v:?} does not lie on input, distance: {}",
+            closest.distance
+        );
+    }
+}
+
+/// Assert the direction of the polyline is continuous at every vertex (all corners filleted).
+fn assert_tangent_continuous(pline: &Polyline) {
+    let vc = pline.vertex_count();
+    let range = if pline.is_closed() { 0..vc } else { 1..vc - 1 };
+    for i in range {
+        let prev = pline.at(pline.prev_wrapping_index(i));
+        let v = pline.at(i);
+        let next = pline.at(pline.next_wrapping_index(i));
+        let dir1 = seg_tangent_vector(prev, v, v.pos()).normalize();
+        let dir2 = seg_tangent_vector(v, next, v.pos()).normalize();
+        assert!(
+            dir1.fuzzy_eq_eps(dir2, 1e-5),
+            "polyline not tangent continuous at vertex {i}: {dir1:?} {dir2:?}"
+        );
+    }
+}
+
+mod test_fillet {
+    use super::*;
+
+    #[test]
+    fn rectangle_all_corners() {
+        let input = square(0.0, 0.0, 10.0);
+        let result = input.fillet(2.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 8);
+        assert!(result.pline.is_closed());
+        assert_fuzzy_eq!(result.pline.area(), 100.0 - (4.0 - PI) * 4.0);
+        assert_fuzzy_eq!(result.pline.path_length(), 40.0 - 16.0 + 4.0 * PI);
+        assert_vertexes_on_input(&input, &result.pline);
+        assert_tangent_continuous(&result.pline);
+
+        // clockwise orientation
+        let mut input = square(0.0, 0.0, 10.0);
+        input.invert_direction_mut();
+        let result = input.fillet(2.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_fuzzy_eq!(result.pline.area(), -(100.0 - (4.0 - PI) * 4.0));
+        assert_tangent_continuous(&result.pline);
+    }
+
+    #[test]
+    fn concave_corner() {
+        let input = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 5.0, 0.0),
+            (5.0, 5.0, 0.0),
+            (5.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let result = input.fillet(1.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 12);
+        // 5 convex corners remove area and 1 concave corner adds area
+        let corner_area = 1.0 - PI / 4.0;
+        assert_fuzzy_eq!(result.pline.area(), 75.0 - 5.0 * corner_area + corner_area);
+        assert_vertexes_on_input(&input, &result.pline);
+        assert_tangent_continuous(&result.pline);
+    }
+
+    #[test]
+    fn selected_corners() {
+        let input = square(0.0, 0.0, 10.0);
+        let result = input.fillet(1.0, |i| i % 2 == 0);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 6);
+        assert_fuzzy_eq!(result.pline.area(), 100.0 - 2.0 * (1.0 - PI / 4.0));
+        assert_eq!(result.pline[2].pos(), Vector2::new(10.0, 0.0));
+        assert_eq!(result.pline[5].pos(), Vector2::new(0.0, 10.0));
+    }
+
+    #[test]
+    fn segments_too_short() {
+        let input = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 1.0, 0.0),
+            (0.0, 1.0, 0.0)
+        ];
+        // first two corners consume the short segments entirely
+        let result = input.fillet(1.0, AllCorners);
+        assert_eq!(result.skipped_vertexes, vec![2, 3]);
+        assert_eq!(result.pline.vertex_count(), 4);
+        assert_fuzzy_eq!(result.pline.area(), 10.0 - 2.0 * (1.0 - PI / 4.0));
+        assert_vertexes_on_input(&input, &result.pline);
+
+        // radius too large for any corner
+        let result = input.fillet(2.0, AllCorners);
+        assert_eq!(result.skipped_vertexes, vec![0, 1, 2, 3]);
+        assert!(result.pline.fuzzy_eq(&input));
+    }
+
+    #[test]
+    fn line_arc_corners() {
+        // half circle "D" shape
+        let input = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0)];
+        let result = input.fillet(1.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 4);
+        assert!(result.pline.area() < input.area());
+        assert_vertexes_on_input(&input, &result.pline);
+        assert_tangent_continuous(&result.pline);
+
+        // fillet center is 1 from the line and 1 from the circle of radius 5 centered at (5, 0)
+        let tangent_x = 5.0 + 15.0f64.sqrt();
+        assert!(
+            result
+                .pline
+                .iter_vertexes()
+                .any(|v| v.pos().fuzzy_eq(Vector2::new(tangent_x, 0.0)))
+        );
+    }
+
+    #[test]
+    fn arc_arc_corners() {
+        // lens shape made of two arcs
+        let input = pline_closed![(0.0, 0.0, 0.5), (10.0, 0.0, 0.5)];
+        let result = input.fillet(0.5, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 4);
+        assert!(result.pline.area() < input.area());
+        assert_vertexes_on_input(&input, &result.pline);
+        assert_tangent_continuous(&result.pline);
+    }
+
+    #[test]
+    fn open_polyline() {
+        let input = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+        let result = input.fillet(2.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert!(!result.pline.is_closed());
+        assert_eq!(result.pline.vertex_count(), 4);
+        assert_eq!(result.pline[0].pos(), Vector2::new(0.0, 0.0));
+        assert_eq!(result.pline[3].pos(), Vector2::new(10.0, 10.0));
+        assert_fuzzy_eq!(result.pline.path_length(), 16.0 + PI);
+        assert_tangent_continuous(&result.pline);
+    }
+
+    #[test]
+    fn tangent_and_cusp_vertexes() {
+        // collinear vertex is not a corner and is not reported
+        let input = pline_open![(0.0, 0.0, 0.0), (5.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+        let result = input.fillet(1.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert!(result.pline.fuzzy_eq(&input));
+
+        // line tangent to arc is not a corner
+        let input = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0)];
+        let result = input.fillet(1.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert!(result.pline.fuzzy_eq(&input));
+
+        // segments folding back on each other cannot be filleted
+        let input = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (5.0, 0.0, 0.0)];
+        let result = input.fillet(1.0, AllCorners);
+        assert_eq!(result.skipped_vertexes, vec![1]);
+        assert!(result.pline.fuzzy_eq(&input));
+    }
+
+    #[test]
+    fn zero_radius_and_userdata() {
+        let input = pline_closed_userdata![
+            [7, 8],
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let result = input.fillet(0.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert!(result.pline.fuzzy_eq(&input));
+
+        let result = input.fillet(1.0, AllCorners);
+        assert_eq!(result.pline.vertex_count(), 8);
+        assert_eq!(
+            result.pline.get_userdata_values().collect::<Vec<_>>(),
+            [7, 8]
+        );
+    }
+}
+
+mod test_chamfer {
+    use super::*;
+
+    #[test]
+    fn rectangle_all_corners() {
+        let input = square(0.0, 0.0, 10.0);
+        let result = input.chamfer(2.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 8);
+        assert_fuzzy_eq!(result.pline.area(), 100.0 - 4.0 * 2.0);
+        assert!(result.pline.iter_vertexes().all(|v| v.bulge_is_zero()));
+        assert_vertexes_on_input(&input, &result.pline);
+    }
+
+    #[test]
+    fn distance_consumes_segments() {
+        // chamfer distance equal to half the side length leaves a diamond
+        let input = square(0.0, 0.0, 10.0);
+        let result = input.chamfer(5.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 4);
+        assert_fuzzy_eq!(result.pline.area(), 50.0);
+
+        let result = input.chamfer(6.0, AllCorners);
+        assert_eq!(result.skipped_vertexes, vec![1, 3]);
+        assert_eq!(result.pline.vertex_count(), 6);
+        assert_fuzzy_eq!(result.pline.area(), 100.0 - 36.0);
+    }
+
+    #[test]
+    fn arc_corners() {
+        let input = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 1.0)];
+        let result = input.chamfer(1.0, AllCorners);
+        assert!(result.skipped_vertexes.is_empty());
+        assert_eq!(result.pline.vertex_count(), 4);
+        assert_vertexes_on_input(&input, &result.pline);
+        // chamfer ends are at path length 1 from the corner along the arc
+        let arc_end = Vector2::new(5.0 + 5.0 * (1.0f64 / 5.0).cos(), 5.0 * (1.0f64 / 5.0).sin());
+        assert!(
+            result
+                .pline
+                .iter_vertexes()
+                .any(|v| v.pos().fuzzy_eq(arc_end))
+        );
+        assert_fuzzy_eq!(
+            result.pline.path_length(),
+            input.path_length() - 4.0 + 2.0 * (arc_end - Vector2::new(9.0, 0.0)).length()
+        );
+    }
+
+    #[test]
+    fn open_polyline_selected() {
+        let input = pline_open![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (20.0, 10.0, 0.0)
+        ];
+        let result = input.chamfer(1.0, |i| i == 2);
+        assert!(result.skipped_vertexes.is_empty());
+        assert!(!result.pline.is_closed());
+        assert!(result.pline.fuzzy_eq(&pline_open![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 9.0, 0.0),
+            (11.0, 10.0, 0.0),
+            (20.0, 10.0, 0.0)
+        ]));
+    }
+}
@@ -0,0 +1,170 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::{Vector2, midpoint},
+    pline_closed, pline_closed_userdata, pline_open,
+    polyline::{PlineCreation, PlineSource, PlineSourceMut, Polyline},
+};
+use std::f64::consts::PI;
+
+/// Assert the two polylines (`lines` made up of line segments only) are within `tolerance` of
+/// each other by sampling points along each.
+fn assert_within_tolerance(lines: &Polyline, fitted: &Polyline, tolerance: f64) {
+    let check = |pt: Vector2<f64>, pline: &Polyline| {
+        let distance = pline.closest_point(pt, 1e-5).unwrap().distance;
+        assert!(
+            distance <= tolerance + 1e-5,
+            "point {pt:?} is {distance} from polyline"
+        );
+    };
+
+    for (v1, v2) in lines.iter_segments() {
+        check(v1.pos(), fitted);
+        check(midpoint(v1.pos(), v2.pos()), fitted);
+    }
+
+    let fitted_samples = fitted.arcs_to_approx_lines(1e-5).unwrap();
+    for (v1, v2) in fitted_samples.iter_segments() {
+        check(v1.pos(), lines);
+        check(midpoint(v1.pos(), v2.pos()), lines);
+    }
+}
+
+/// Polyline of line segments approximating the path given by `f` over [0, 1].
+fn sample_path<F>(f: F, count: usize, is_closed: bool) -> Polyline
+where
+    F: Fn(f64) -> Vector2<f64>,
+{
+    let mut result = Polyline::with_capacity(count + 1, is_closed);
+    let end = if is_closed { count - 1 } else { count };
+    for i in 0..=end {
+        let pt = f(i as f64 / count as f64);
+        result.add(pt.x, pt.y, 0.0);
+    }
+    result
+}
+
+#[test]
+fn collinear_lines_merged() {
+    let input = pline_open![
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0)
+    ];
+    let result = input.fit_arcs(1e-3);
+    assert!(result.fuzzy_eq(&pline_open![(0.0, 0.0, 0.0), (5.0, 0.0, 0.0)]));
+
+    // rectangle with extra vertexes along the sides keeps the corners
+    let input = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 5.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (5.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0),
+        (0.0, 5.0, 0.0)
+    ];
+    let result = input.fit_arcs(1e-3);
+    assert_eq!(result.vertex_count(), 4);
+    assert!(result.iter_vertexes().all(|v| v.bulge_is_zero()));
+    assert_fuzzy_eq!(result.area(), 100.0);
+    assert_within_tolerance(&input, &result, 1e-3);
+}
+
+#[test]
+fn lines_back_to_arcs() {
+    let input = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.5), (20.0, 0.0, 0.0)];
+    let lines = input.arcs_to_approx_lines(1e-4).unwrap();
+    assert!(lines.vertex_count() > 50);
+    let result = lines.fit_arcs(1e-3);
+    assert!(result.vertex_count() <= 4, "{result:?}");
+    assert_eq!(result[0].pos(), Vector2::new(0.0, 0.0));
+    assert_eq!(result.last().unwrap().pos(), Vector2::new(20.0, 0.0));
+    assert_within_tolerance(&lines, &result, 1e-3);
+    assert_fuzzy_eq!(result.path_length(), input.path_length(), 1e-3);
+}
+
+#[test]
+fn closed_circle() {
+    let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let lines = circle.arcs_to_approx_lines(1e-4).unwrap();
+    let result = lines.fit_arcs(1e-3);
+    assert!(result.is_closed());
+    assert!(result.vertex_count() <= 4, "{result:?}");
+    assert_within_tolerance(&lines, &result, 1e-3);
+    assert_fuzzy_eq!(result.area(), 25.0 * PI, 1e-2);
+
+    // clockwise
+    let mut lines = lines;
+    lines.invert_direction_mut();
+    let result = lines.fit_arcs(1e-3);
+    assert!(result.vertex_count() <= 4, "{result:?}");
+    assert_within_tolerance(&lines, &result, 1e-3);
+    assert_fuzzy_eq!(result.area(), -25.0 * PI, 1e-2);
+}
+
+#[test]
+fn existing_arcs_kept() {
+    let input = pline_closed_userdata![
+        [3],
+        (0.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, -0.5)
+    ];
+    let result = input.fit_arcs(1e-3);
+    // closed polyline starts after the first arc so runs of lines are not split
+    assert!(result.fuzzy_eq(&pline_closed![
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, -0.5),
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0)
+    ]));
+    assert_eq!(result.get_userdata_values().collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn curve_within_tolerance() {
+    // sine wave, lines turn in both directions
+    let wave = |t: f64| Vector2::new(t * 20.0, 3.0 * (t * 4.0 * PI).sin());
+    let lines = sample_path(wave, 2000, false);
+    for tolerance in [1e-1, 1e-2, 1e-3] {
+        let result = lines.fit_arcs(tolerance);
+        assert!(result.vertex_count() < lines.vertex_count() / 10);
+        assert_within_tolerance(&lines, &result, tolerance);
+    }
+
+    // ellipse
+    let ellipse = |t: f64| {
+        let (s, c) = (t * 2.0 * PI).sin_cos();
+        Vector2::new(8.0 * c, 3.0 * s)
+    };
+    let lines = sample_path(ellipse, 1000, true);
+    let result = lines.fit_arcs(1e-3);
+    assert!(result.vertex_count() < 100);
+    assert_within_tolerance(&lines, &result, 1e-3);
+}
+
+#[test]
+fn zigzag_tolerance() {
+    let input = pline_open![
+        (0.0, 0.0, 0.0),
+        (1.0, 0.1, 0.0),
+        (2.0, -0.1, 0.0),
+        (3.0, 0.1, 0.0),
+        (4.0, 0.0, 0.0)
+    ];
+    assert_eq!(input.fit_arcs(0.2).vertex_count(), 2);
+    assert_eq!(input.fit_arcs(0.01).vertex_count(), 5);
+}
+
+#[test]
+fn small_inputs() {
+    let empty = Polyline::<f64>::new();
+    assert_eq!(empty.fit_arcs(1.0).vertex_count(), 0);
+    let line = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+    assert!(line.fit_arcs(1.0).fuzzy_eq(&line));
+}
@@ -1,6 +1,6 @@
 mod test_utils;
 
-use cavalier_contours::polyline::{PlineOffsetOptions, PlineSource, Polyline};
+use cavalier_contours::polyline::{JoinStyle, PlineOffsetOptions, PlineSource, Polyline};
 use test_utils::{
     ModifiedPlineSet, ModifiedPlineSetVisitor, ModifiedPlineState, PlineProperties,
     create_property_set, property_sets_match,
@@ -11,10 +11,12 @@ fn offset_into_properties_set(
     offset: f64,
     inverted: bool,
     handle_self_intersects: bool,
+    join_style: JoinStyle,
 ) -> Vec<PlineProperties> {
     let offset = if inverted { -offset } else { offset };
     let options = PlineOffsetOptions {
         handle_self_intersects,
+        join_style,
         ..Default::default()
     };
     let offset_results = polyline.parallel_offset_opt(offset, &options);
@@ -31,6 +33,7 @@ struct PlineOffsetTestVisitor<'a> {
     offset: f64,
     expected_properties_set: &'a [PlineProperties],
     handle_self_intersects: bool,
+    join_style: JoinStyle,
 }
 
 impl ModifiedPlineSetVisitor for PlineOffsetTestVisitor<'_> {
@@ -40,6 +43,7 @@ impl ModifiedPlineSetVisitor for PlineOffsetTestVisitor<'_> {
             self.offset,
             pline_state.inverted_direction,
             self.handle_self_intersects,
+            self.join_style,
         );
         assert!(
             property_sets_match(&offset_results, self.expected_properties_set),
@@ -52,6 +56,7 @@ impl ModifiedPlineSetVisitor for PlineOffsetTestVisitor<'_> {
                 self.offset,
                 pline_state.inverted_direction,
                 true,
+                self.join_style,
             );
             assert!(
                 property_sets_match(&offset_results, self.expected_properties_set),
@@ -66,11 +71,13 @@ fn run_pline_offset_tests(
     offset: f64,
     expected_properties_set: &[PlineProperties],
     handle_self_intersects: bool,
+    join_style: JoinStyle,
 ) {
     let mut visitor = PlineOffsetTestVisitor {
         offset,
         expected_properties_set,
         handle_self_intersects,
+        join_style,
     };
 
     let test_set = ModifiedPlineSet::new(input, true, true);
@@ -83,7 +90,7 @@ macro_rules! declare_offset_tests {
             #[test]
             fn $name() {
                 $(
-                    run_pline_offset_tests(&$value.0, $value.1, &$expected, false);
+                    run_pline_offset_tests(&$value.0, $value.1, &$expected, false, JoinStyle::Round);
                 )+
             }
         )+
@@ -96,7 +103,21 @@ macro_rules! declare_self_intersecting_offset_tests {
             #[test]
             fn $name() {
                 $(
-                    run_pline_offset_tests(&$value.0, $value.1, &$expected, true);
+                    run_pline_offset_tests(&$value.0, $value.1, &$expected, true, JoinStyle::Round);
+                )+
+            }
+        )+
+    };
+}
+
+macro_rules! declare_join_style_offset_tests {
+    ($($name:ident { $($value:expr => $expected:expr),+ $(,)? })*) => {
+        $(
+            #[test]
+            fn $name() {
+                $(
+                    let (input, offset, join_style): (Polyline, f64, JoinStyle) = $value;
+                    run_pline_offset_tests(&input, offset, &$expected, false, join_style);
                 )+
             }
         )+
@@ -632,6 +653,44 @@ mod test_past_failures {
     );
 }
 
+/// Test cases for the miter and bevel join styles (only outward corners are affected).
+mod test_join_styles {
+    use super::*;
+    use cavalier_contours::{pline_closed_userdata, pline_open_userdata};
+
+    declare_join_style_offset_tests!(
+        closed_rectangle_inward_unchanged {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], 2.0, JoinStyle::Miter { limit: 4.0 }) =>
+            [PlineProperties::new(4, 96.0, 44.0, 2.0, 2.0, 18.0, 8.0, vec![4])],
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], 2.0, JoinStyle::Bevel) =>
+            [PlineProperties::new(4, 96.0, 44.0, 2.0, 2.0, 18.0, 8.0, vec![4])]
+        }
+        closed_rectangle_outward {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, JoinStyle::Miter { limit: 4.0 }) =>
+            [PlineProperties::new(4, 336.0, 76.0, -2.0, -2.0, 22.0, 12.0, vec![4])],
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, JoinStyle::Bevel) =>
+            [PlineProperties::new(8, 328.0, 71.313708498985, -2.0, -2.0, 22.0, 12.0, vec![4])]
+        }
+        closed_rectangle_outward_miter_limit_exceeded {
+            // square corner miter length ratio is sqrt(2) so this falls back to bevel
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0) ], -2.0, JoinStyle::Miter { limit: 1.2 }) =>
+            [PlineProperties::new(8, 328.0, 71.313708498985, -2.0, -2.0, 22.0, 12.0, vec![4])]
+        }
+        open_rectangle_outward {
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0), (0.0, 0.0, 0.0) ], -2.0, JoinStyle::Miter { limit: 4.0 }) =>
+            [PlineProperties::new(5, 0.0, 72.0, -2.0, -2.0, 22.0, 12.0, vec![4])],
+            (pline_open_userdata![[4], (0.0, 0.0, 0.0), (20.0, 0.0, 0.0), (20.0, 10.0, 0.0), (0.0, 10.0, 0.0), (0.0, 0.0, 0.0) ], -2.0, JoinStyle::Bevel) =>
+            [PlineProperties::new(8, 0.0, 68.485281374239, -2.0, -2.0, 22.0, 12.0, vec![4])]
+        }
+        closed_half_circle_outward {
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 1.0) ], -1.0, JoinStyle::Miter { limit: 4.0 }) =>
+            [PlineProperties::new(4, 68.548667764616, 32.849555921539, -1.0, -1.0, 11.0, 6.0, vec![4])],
+            (pline_closed_userdata![[4], (0.0, 0.0, 0.0), (10.0, 0.0, 1.0) ], -1.0, JoinStyle::Bevel) =>
+            [PlineProperties::new(4, 67.548667764616, 31.677983046285, -1.0, -1.0, 11.0, 6.0, vec![4])]
+        }
+    );
+}
+
 // To be revisited:
 
 // Offset fails due distance check - goes away if input is scaled up first
@@ -0,0 +1,136 @@
+use cavalier_contours::{
+    assert_fuzzy_eq, pline_closed, pline_open,
+    polyline::{FillRule, PlineOrientation, PlineSource, PlineSourceMut, Polyline},
+};
+
+fn resolve(pline: &Polyline, fill_rule: FillRule) -> Vec<Polyline> {
+    let result: Vec<Polyline> = pline.resolve_self_intersects(fill_rule);
+    for p in result.iter() {
+        assert!(p.is_closed());
+        assert!(!p.scan_for_self_intersect());
+    }
+    result
+}
+
+#[test]
+fn simple_input() {
+    let square: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let result = resolve(&square, FillRule::NonZero);
+    assert_eq!(result.len(), 1);
+    assert!(result[0].fuzzy_eq(&square));
+
+    // clockwise returned counter clockwise
+    let mut cw = square.clone();
+    cw.invert_direction_mut();
+    let result = resolve(&cw, FillRule::EvenOdd);
+    assert_eq!(result.len(), 1);
+    assert_fuzzy_eq!(result[0].area(), 100.0);
+}
+
+#[test]
+fn invalid_input() {
+    let open: Polyline = pline_open![(0.0, 0.0, 0.0), (2.0, 2.0, 0.0), (2.0, 0.0, 0.0)];
+    assert!(resolve(&open, FillRule::NonZero).is_empty());
+    assert!(resolve(&Polyline::new_closed(), FillRule::NonZero).is_empty());
+    // collapsed to a line
+    let line: Polyline = pline_closed![(0.0, 0.0, 0.0), (5.0, 0.0, 0.0)];
+    assert!(resolve(&line, FillRule::NonZero).is_empty());
+}
+
+#[test]
+fn figure_eight_with_arcs() {
+    // two half circles joined by crossing lines
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 2.0, -1.0),
+        (4.0, 0.0, 0.0),
+        (0.0, 2.0, 1.0)
+    ];
+    let result = resolve(&pline, FillRule::NonZero);
+    assert_eq!(result.len(), 2);
+    let half_circle = std::f64::consts::PI / 2.0;
+    for p in result.iter() {
+        assert_eq!(p.orientation(), PlineOrientation::CounterClockwise);
+        assert_fuzzy_eq!(p.area(), 2.0 + half_circle);
+    }
+
+    // same for even odd since no region has winding number greater than 1
+    assert_eq!(resolve(&pline, FillRule::EvenOdd).len(), 2);
+}
+
+#[test]
+fn fill_rules_differ() {
+    // square loop crossing back over itself to form a small loop wound the same direction
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (4.0, 10.0, 0.0),
+        (4.0, 4.0, 0.0),
+        (6.0, 4.0, 0.0),
+        (6.0, 12.0, 0.0),
+        (0.0, 12.0, 0.0)
+    ];
+    // small loop (4, 4) to (6, 10) has winding number 2
+    let non_zero = resolve(&pline, FillRule::NonZero);
+    let area: f64 = non_zero.iter().map(|p| p.area()).sum();
+    assert_fuzzy_eq!(area, pline.area() - 12.0);
+
+    let even_odd = resolve(&pline, FillRule::EvenOdd);
+    let area: f64 = even_odd.iter().map(|p| p.area()).sum();
+    assert_fuzzy_eq!(area, pline.area() - 24.0);
+    assert!(
+        even_odd
+            .iter()
+            .any(|p| p.orientation() == PlineOrientation::Clockwise)
+    );
+}
+
+#[test]
+fn tiny_opposite_loop() {
+    // corner crossing over itself forming a tiny clockwise loop
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (10.1, 9.9, 0.0),
+        (9.9, 9.9, 0.0),
+        (9.9, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    // loop has winding number -1 so it is kept as a separate counter clockwise polyline
+    let mut result = resolve(&pline, FillRule::NonZero);
+    assert_eq!(result.len(), 2);
+    result.sort_by(|a, b| a.area().total_cmp(&b.area()));
+    assert_fuzzy_eq!(result[0].area(), 0.005);
+    assert_fuzzy_eq!(result[1].area(), 100.0 - 0.01);
+}
+
+#[test]
+fn overlapping_segments() {
+    // two squares joined by a bridge traversed in both directions
+    let pline: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (4.0, 2.0, 0.0),
+        (6.0, 2.0, 0.0),
+        (6.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 4.0, 0.0),
+        (6.0, 4.0, 0.0),
+        (6.0, 2.0, 0.0),
+        (4.0, 2.0, 0.0),
+        (4.0, 4.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let result = resolve(&pline, FillRule::NonZero);
+    assert_eq!(result.len(), 2);
+    for p in result.iter() {
+        assert_fuzzy_eq!(p.area(), 16.0);
+    }
+}
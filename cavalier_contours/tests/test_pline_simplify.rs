@@ -0,0 +1,214 @@
+use cavalier_contours::{
+    core::math::Vector2,
+    pline_closed, pline_closed_userdata, pline_open,
+    polyline::{
+        PlineCreation, PlineSource, PlineSourceMut, Polyline, SimplifyMethod, seg_midpoint,
+    },
+};
+use std::f64::consts::PI;
+
+const METHODS: [SimplifyMethod; 2] = [SimplifyMethod::DouglasPeucker, SimplifyMethod::Visvalingam];
+
+/// Assert every vertex and segment mid point of `input` is within `tolerance` of `result` and all
+/// `result` vertexes are from `input`.
+fn assert_within_tolerance(input: &Polyline, result: &Polyline, tolerance: f64) {
+    for (v1, v2) in input.iter_segments() {
+        for pt in [v1.pos(), seg_midpoint(v1, v2)] {
+            let distance = result.closest_point(pt, 1e-5).unwrap().distance;
+            assert!(
+                distance <= tolerance + 1e-9,
+                "point {pt:?} is {distance} from simplified polyline"
+            );
+        }
+    }
+
+    for v in result.iter_vertexes() {
+        assert!(
+            input.iter_vertexes().any(|u| u.pos() == v.pos()),
+            "vertex {v:?} not from input"
+        );
+    }
+}
+
+/// Noisy circle with `count` vertexes.
+fn noisy_circle(count: usize) -> Polyline {
+    let mut result = Polyline::with_capacity(count, true);
+    for i in 0..count {
+        let a = i as f64 / count as f64 * 2.0 * PI;
+        // deterministic noise
+        let r = 10.0 + 0.05 * ((i * 7919) % 13) as f64 / 13.0;
+        result.add(r * a.cos(), r * a.sin(), 0.0);
+    }
+    result
+}
+
+#[test]
+fn open_zigzag() {
+    let input = pline_open![
+        (0.0, 0.0, 0.0),
+        (1.0, 0.1, 0.0),
+        (2.0, -0.1, 0.0),
+        (3.0, 0.1, 0.0),
+        (4.0, 0.0, 0.0)
+    ];
+    for method in METHODS {
+        let result = input.simplify(0.2, method);
+        assert!(!result.is_closed());
+        assert_eq!(result.vertex_count(), 2, "{method:?}");
+        assert_eq!(result[0].pos(), Vector2::new(0.0, 0.0));
+        assert_eq!(result[1].pos(), Vector2::new(4.0, 0.0));
+
+        let result = input.simplify(0.05, method);
+        assert_eq!(result.vertex_count(), 5, "{method:?}");
+    }
+}
+
+#[test]
+fn closed_noisy_circle() {
+    let input = noisy_circle(500);
+    for method in METHODS {
+        for tolerance in [0.01, 0.1, 1.0] {
+            let result = input.simplify(tolerance, method);
+            assert!(result.is_closed());
+            assert!(result.vertex_count() >= 3);
+            assert!(result.vertex_count() < input.vertex_count());
+            assert_within_tolerance(&input, &result, tolerance);
+            assert!(!result.scan_for_self_intersect());
+            assert!(result.area() > 0.0);
+        }
+    }
+}
+
+#[test]
+fn closed_keeps_polygon() {
+    // thin rectangle collapses to a line with a large tolerance but must remain a polygon
+    let input: Polyline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 0.1, 0.0),
+        (0.0, 0.1, 0.0)
+    ];
+    for method in METHODS {
+        let result = input.simplify(1.0, method);
+        assert!(result.is_closed());
+        assert_eq!(result.vertex_count(), 3, "{method:?}");
+        assert!(result.area().abs() > 0.0);
+    }
+}
+
+#[test]
+fn arc_deviation_measured_along_arc() {
+    // middle vertex is on the chord joining the end points but the arc bulges 0.5 away from it
+    let input = pline_open![(0.0, 0.0, -0.1), (10.0, 0.0, 0.0), (20.0, 0.0, 0.0)];
+    for method in METHODS {
+        let result = input.simplify(0.4, method);
+        assert_eq!(result.vertex_count(), 3, "{method:?}");
+        // arc segment is kept as is
+        assert_eq!(result[0].bulge, -0.1);
+
+        let result = input.simplify(0.6, method);
+        assert_eq!(result.vertex_count(), 2, "{method:?}");
+        assert_eq!(result[0].bulge, 0.0);
+    }
+
+    // arcs with vertexes kept are preserved
+    let input = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, 0.01, 0.0),
+        (10.0, 0.0, 1.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    for method in METHODS {
+        let result = input.simplify(0.1, method);
+        assert_eq!(result.vertex_count(), 4, "{method:?}");
+        assert!(result.iter_vertexes().any(|v| v.bulge == 1.0));
+        assert!((result.area() - (100.0 + 12.5 * PI)).abs() < 0.1);
+    }
+}
+
+#[test]
+fn no_new_self_intersects() {
+    // removing the bottom vertex (5, -1) would cross the spike down to (5, -0.5)
+    let input = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, -1.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 3.0, 0.0),
+        (6.0, 3.0, 0.0),
+        (5.0, -0.5, 0.0),
+        (4.0, 3.0, 0.0),
+        (0.0, 3.0, 0.0)
+    ];
+    assert!(!input.scan_for_self_intersect());
+    for method in METHODS {
+        let result = input.simplify(1.5, method);
+        assert!(!result.scan_for_self_intersect(), "{method:?}");
+        assert!(
+            result
+                .iter_vertexes()
+                .any(|v| v.pos() == Vector2::new(5.0, -1.0))
+        );
+    }
+
+    // without the spike the bottom vertex is removed
+    let input = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, -1.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 3.0, 0.0),
+        (0.0, 3.0, 0.0)
+    ];
+    for method in METHODS {
+        let result = input.simplify(1.5, method);
+        assert_eq!(result.vertex_count(), 4, "{method:?}");
+    }
+}
+
+#[test]
+fn existing_self_intersects_simplified() {
+    // bow tie with noise along both diagonals which cross at (5, 5)
+    let input = pline_closed![
+        (0.0, 0.0, 0.0),
+        (2.5, 2.55, 0.0),
+        (5.0, 4.95, 0.0),
+        (7.5, 7.55, 0.0),
+        (10.0, 10.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (7.5, 2.45, 0.0),
+        (5.0, 5.05, 0.0),
+        (2.5, 7.45, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    assert!(input.scan_for_self_intersect());
+    for method in METHODS {
+        let result = input.simplify(0.1, method);
+        assert_eq!(result.vertex_count(), 4, "{method:?}");
+        assert_within_tolerance(&input, &result, 0.1);
+    }
+}
+
+#[test]
+fn small_inputs_and_userdata() {
+    for method in METHODS {
+        assert_eq!(
+            Polyline::<f64>::new().simplify(1.0, method).vertex_count(),
+            0
+        );
+        let line = pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)];
+        assert_eq!(line.simplify(1.0, method).vertex_count(), 2);
+
+        let input = pline_closed_userdata![
+            [5],
+            (0.0, 0.0, 0.0),
+            (5.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let result = input.simplify(0.1, method);
+        assert_eq!(result.vertex_count(), 4);
+        assert_eq!(result.get_userdata_values().collect::<Vec<_>>(), [5]);
+    }
+}
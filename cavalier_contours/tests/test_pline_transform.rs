@@ -0,0 +1,182 @@
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    core::math::{Affine2, Vector2},
+    pline_closed, pline_open,
+    polyline::{PlineOrientation, PlineSource, PlineSourceMut, Polyline, TransformError},
+    shape_algorithms::Shape,
+};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Half circle "D" shape with a line segment and an arc segment.
+fn d_shape() -> Polyline {
+    pline_closed![(0.0, 0.0, 0.0), (2.0, 0.0, 1.0)]
+}
+
+mod test_pline {
+    use super::*;
+
+    #[test]
+    fn rotate() {
+        let mut pline = d_shape();
+        pline.rotate_mut(FRAC_PI_2, Vector2::new(0.0, 0.0));
+        assert!(pline.fuzzy_eq(&pline_closed![(0.0, 0.0, 0.0), (0.0, 2.0, 1.0)]));
+        assert_fuzzy_eq!(pline.area(), d_shape().area());
+
+        let mut pline = pline_open![(1.0, 1.0, 0.0), (2.0, 1.0, 0.0)];
+        pline.rotate_mut(PI, Vector2::new(1.0, 1.0));
+        assert!(pline.fuzzy_eq(&pline_open![(1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]));
+    }
+
+    #[test]
+    fn mirror() {
+        let input = d_shape();
+        let mut pline = input.clone();
+        // mirror across the line x = 1
+        pline.mirror_mut(Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        assert!(pline.fuzzy_eq(&pline_closed![(2.0, 0.0, 0.0), (0.0, 0.0, -1.0)]));
+        // orientation is inverted, area magnitude and path length unchanged
+        assert_eq!(pline.orientation(), PlineOrientation::Clockwise);
+        assert_fuzzy_eq!(pline.area(), -input.area());
+        assert_fuzzy_eq!(pline.path_length(), input.path_length());
+
+        // mirror across diagonal line through origin
+        let mut pline = pline_open![(1.0, 0.0, 0.5), (2.0, 0.0, 0.0)];
+        pline.mirror_mut(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+        assert!(pline.fuzzy_eq(&pline_open![(0.0, 1.0, -0.5), (0.0, 2.0, 0.0)]));
+    }
+
+    #[test]
+    fn transform_similarity() {
+        let input = d_shape();
+        let transform = Affine2::rotation_about(FRAC_PI_2, Vector2::new(1.0, 1.0))
+            .then(&Affine2::uniform_scaling(3.0))
+            .then(&Affine2::translation(-2.0, 5.0));
+        let mut pline = input.clone();
+        pline.transform_mut(&transform).unwrap();
+        assert_fuzzy_eq!(pline.area(), 9.0 * input.area());
+        assert_fuzzy_eq!(pline.path_length(), 3.0 * input.path_length());
+        for (v, u) in pline.iter_vertexes().zip(input.iter_vertexes()) {
+            assert!(v.pos().fuzzy_eq(transform.transform_point(u.pos())));
+            assert_eq!(v.bulge, u.bulge);
+        }
+
+        // reflection negates bulges
+        let mut pline = input.clone();
+        pline
+            .transform_mut(&Affine2::mirror(
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, 0.0),
+            ))
+            .unwrap();
+        assert!(pline.fuzzy_eq(&pline_closed![(0.0, 0.0, 0.0), (2.0, 0.0, -1.0)]));
+        assert_fuzzy_eq!(pline.area(), -input.area());
+
+        // same as mirror_mut
+        let mut mirrored = input.clone();
+        mirrored.mirror_mut(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+        assert!(mirrored.fuzzy_eq(&pline));
+    }
+
+    #[test]
+    fn transform_non_uniform() {
+        let stretch = Affine2::scaling(2.0, 1.0);
+        let mut pline = d_shape();
+        assert_eq!(
+            pline.transform_mut(&stretch),
+            Err(TransformError::NonUniformScaleWithArcs)
+        );
+        // left unchanged
+        assert!(pline.fuzzy_eq(&d_shape()));
+
+        // lines only are transformed
+        let mut pline: Polyline = pline_closed![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 0.0)
+        ];
+        pline.transform_mut(&stretch).unwrap();
+        assert_fuzzy_eq!(pline.area(), 2.0);
+
+        // skew
+        let skew = Affine2::new(1.0, 1.0, 0.0, 1.0, 0.0, 0.0);
+        pline.transform_mut(&skew).unwrap();
+        assert!(pline[2].pos().fuzzy_eq(Vector2::new(3.0, 1.0)));
+        assert_fuzzy_eq!(pline.area(), 2.0);
+
+        // converting arcs to lines first allows non-uniform transform
+        let mut lines = d_shape().arcs_to_approx_lines(1e-4).unwrap();
+        lines.transform_mut(&stretch).unwrap();
+        // half ellipse area
+        assert_fuzzy_eq!(lines.area(), 2.0 * d_shape().area(), 1e-2);
+    }
+}
+
+mod test_shape {
+    use super::*;
+
+    fn shape_with_hole() -> Shape<f64> {
+        let outer = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let hole = pline_closed![(4.0, 5.0, -1.0), (6.0, 5.0, -1.0)];
+        Shape::from_plines([outer, hole])
+    }
+
+    #[test]
+    fn translate_scale_rotate() {
+        let mut shape = shape_with_hole();
+        let area = shape.area();
+        shape.translate_mut(5.0, 0.0);
+        shape.scale_mut(2.0);
+        shape.rotate_mut(FRAC_PI_2, Vector2::new(0.0, 0.0));
+        assert_fuzzy_eq!(shape.area(), 4.0 * area);
+        // spatial indexes are rebuilt
+        let extents = shape.extents().unwrap();
+        assert_fuzzy_eq!(extents.min_x, -20.0);
+        assert_fuzzy_eq!(extents.max_x, 0.0);
+        assert_fuzzy_eq!(extents.min_y, 10.0);
+        assert_fuzzy_eq!(extents.max_y, 30.0);
+        let index_bounds = shape.plines_index.bounds().unwrap();
+        assert!(index_bounds.min_x <= -20.0 && index_bounds.max_y >= 30.0);
+        // hole center moved from (5, 5) to (-10, 20)
+        assert!(!shape.contains_point(Vector2::new(-10.0, 20.0)));
+        assert!(shape.contains_point(Vector2::new(-2.0, 12.0)));
+    }
+
+    #[test]
+    fn mirror_keeps_orientation() {
+        let mut shape = shape_with_hole();
+        let area = shape.area();
+        shape.mirror_mut(Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0));
+        assert_eq!(shape.ccw_plines.len(), 1);
+        assert_eq!(shape.cw_plines.len(), 1);
+        assert_fuzzy_eq!(shape.area(), area);
+        assert!(shape.contains_point(Vector2::new(-1.0, 1.0)));
+        assert!(!shape.contains_point(Vector2::new(-5.0, 5.0)));
+        assert!(!shape.contains_point(Vector2::new(1.0, 1.0)));
+
+        let mut transformed = shape_with_hole();
+        transformed
+            .transform_mut(&Affine2::mirror(
+                Vector2::new(0.0, 0.0),
+                Vector2::new(0.0, 1.0),
+            ))
+            .unwrap();
+        assert_eq!(transformed.ccw_plines.len(), 1);
+        assert_fuzzy_eq!(transformed.area(), area);
+    }
+
+    #[test]
+    fn transform_non_uniform_with_arcs() {
+        let mut shape = shape_with_hole();
+        assert_eq!(
+            shape.transform_mut(&Affine2::scaling(1.0, 2.0)),
+            Err(TransformError::NonUniformScaleWithArcs)
+        );
+        assert_fuzzy_eq!(shape.area(), shape_with_hole().area());
+    }
+}
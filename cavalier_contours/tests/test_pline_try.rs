@@ -0,0 +1,167 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq, pline_closed, pline_open,
+    polyline::{
+        BooleanOp, CavcError, PlineBooleanOptions, PlineOffsetOptions, PlineSource, PlineSourceMut,
+        Polyline,
+    },
+};
+use test_utils::square;
+
+fn bow_tie() -> Polyline {
+    pline_closed![
+        (0.0, 0.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (0.0, 2.0, 0.0)
+    ]
+}
+
+#[test]
+fn invalid_vertexes() {
+    let mut pline = square(0.0, 0.0, 10.0);
+    pline.set(2, f64::INFINITY, 10.0, 0.0);
+    assert_eq!(
+        pline.try_parallel_offset(1.0).unwrap_err(),
+        CavcError::NonFiniteVertex { index: 2 }
+    );
+
+    let mut pline = square(0.0, 0.0, 10.0);
+    pline.set(3, 0.0, 10.0, f64::NAN);
+    assert_eq!(
+        pline.try_remove_redundant(1e-5).unwrap_err(),
+        CavcError::InvalidBulge { index: 3 }
+    );
+
+    // bulge of 1 (half circle) is valid
+    let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, -1.0)];
+    assert!(circle.try_remove_repeat_pos(1e-5).unwrap().is_none());
+    let circle = pline_closed![(0.0, 0.0, -1.0), (2.0, 0.0, -1.0)];
+    assert_eq!(circle.try_parallel_offset(0.5).unwrap().len(), 1);
+}
+
+#[test]
+fn offset_removes_repeat_positions() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    // index given is for the polyline with the repeat vertex and is not used
+    let aabb_index = pline.create_approx_aabb_index();
+    let options = PlineOffsetOptions {
+        aabb_index: Some(&aabb_index),
+        ..Default::default()
+    };
+    let result = pline.try_parallel_offset_opt(-1.0, &options).unwrap();
+    assert_eq!(result.len(), 1);
+    assert_fuzzy_eq!(result[0].area(), 144.0 - 4.0 + std::f64::consts::PI);
+
+    // collapses to single vertex after removing repeat positions
+    let pline = pline_open![(1.0, 1.0, 0.0), (1.0, 1.0, 0.0)];
+    assert_eq!(
+        pline.try_parallel_offset(1.0).unwrap_err(),
+        CavcError::TooFewVertexes {
+            count: 1,
+            required: 2
+        }
+    );
+}
+
+#[test]
+fn offset_self_intersecting() {
+    let pline = bow_tie();
+    assert_eq!(
+        pline.try_parallel_offset(0.1).unwrap_err(),
+        CavcError::SelfIntersecting
+    );
+
+    // open polylines are always handled
+    let mut open = bow_tie();
+    open.set_is_closed(false);
+    assert!(open.try_parallel_offset(0.1).is_ok());
+}
+
+#[test]
+fn boolean_invalid_input() {
+    let circle = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+    let result = square(0.0, 0.0, 10.0)
+        .try_boolean(&circle, BooleanOp::Not)
+        .unwrap();
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(result.neg_plines.len(), 1);
+
+    let mut open = square(0.0, 0.0, 10.0);
+    open.set_is_closed(false);
+    assert_eq!(
+        square(0.0, 0.0, 10.0)
+            .try_boolean(&open, BooleanOp::Or)
+            .unwrap_err(),
+        CavcError::NotClosed
+    );
+    assert_eq!(
+        bow_tie()
+            .try_boolean(&square(0.0, 0.0, 10.0), BooleanOp::And)
+            .unwrap_err(),
+        CavcError::SelfIntersecting
+    );
+    assert_eq!(
+        square(0.0, 0.0, 10.0)
+            .try_boolean(&Polyline::new_closed(), BooleanOp::Or)
+            .unwrap_err(),
+        CavcError::TooFewVertexes {
+            count: 0,
+            required: 2
+        }
+    );
+}
+
+#[test]
+fn boolean_removes_repeat_positions() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    // index given is for the polyline with the repeat vertex and is not used
+    let aabb_index = pline.create_approx_aabb_index();
+    let options = PlineBooleanOptions {
+        pline1_aabb_index: Some(&aabb_index),
+        ..Default::default()
+    };
+    let other = square(5.0, 5.0, 10.0);
+    let result = pline
+        .try_boolean_opt(&other, BooleanOp::Or, &options)
+        .unwrap();
+    let expected = pline.boolean(&other, BooleanOp::Or);
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_eq!(expected.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].pline.area(), 175.0);
+    assert_fuzzy_eq!(expected.pos_plines[0].pline.area(), 175.0);
+
+    // repeat position in the other polyline
+    let result = other.try_boolean(&pline, BooleanOp::And).unwrap();
+    assert_eq!(result.pos_plines.len(), 1);
+    assert_fuzzy_eq!(result.pos_plines[0].pline.area(), 25.0);
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        CavcError::TooFewVertexes {
+            count: 1,
+            required: 2
+        }
+        .to_string(),
+        "polyline has 1 vertexes but at least 2 are required"
+    );
+    assert_eq!(
+        CavcError::InvalidBulge { index: 4 }.to_string(),
+        "vertex 4 has an invalid bulge value"
+    );
+}
@@ -0,0 +1,206 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq, pline_closed, pline_open,
+    polyline::{PlineSource, PlineSourceMut, PlineValidateIssue, PlineValidateOptions, Polyline},
+};
+use test_utils::square;
+
+fn validate(pline: &Polyline) -> Vec<PlineValidateIssue> {
+    pline.validate(&Default::default()).issues
+}
+
+#[test]
+fn valid_polylines() {
+    assert!(
+        square(0.0, 0.0, 10.0)
+            .validate(&Default::default())
+            .is_valid()
+    );
+    let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    assert!(circle.validate(&Default::default()).is_valid());
+    let open = pline_open![(0.0, 0.0, 0.5), (5.0, 0.0, -0.5), (10.0, 0.0, 0.0)];
+    assert!(open.validate(&Default::default()).is_valid());
+}
+
+#[test]
+fn too_few_vertexes() {
+    assert_eq!(
+        validate(&Polyline::new()),
+        [PlineValidateIssue::TooFewVertexes { count: 0 }]
+    );
+    assert_eq!(
+        validate(&pline_closed![(1.0, 1.0, 0.0)]),
+        [PlineValidateIssue::TooFewVertexes { count: 1 }]
+    );
+    // vertex issues are still reported
+    assert_eq!(
+        validate(&pline_open![(f64::NAN, 1.0, 0.0)]),
+        [
+            PlineValidateIssue::TooFewVertexes { count: 1 },
+            PlineValidateIssue::NonFiniteVertex { index: 0 },
+        ]
+    );
+}
+
+#[test]
+fn invalid_vertexes_only_reported() {
+    let mut pline = square(0.0, 0.0, 10.0);
+    pline.add(f64::NAN, 5.0, 0.0);
+    pline.set(1, 10.0, 0.0, -1.5);
+    // self intersect and other checks skipped
+    pline.add(10.0, 10.0, f64::INFINITY);
+    assert_eq!(
+        validate(&pline),
+        [
+            PlineValidateIssue::InvalidBulge { index: 1 },
+            PlineValidateIssue::NonFiniteVertex { index: 4 },
+            PlineValidateIssue::InvalidBulge { index: 5 },
+        ]
+    );
+}
+
+#[test]
+fn repeat_positions() {
+    // repeat on last to first wraps around
+    let mut pline = square(0.0, 0.0, 10.0);
+    pline.add(0.0, 0.0, 0.0);
+    pline.insert(2, 10.0, 0.0, 0.0);
+    assert_eq!(
+        validate(&pline),
+        [
+            PlineValidateIssue::ZeroLengthSegment { seg_start_index: 1 },
+            PlineValidateIssue::ZeroLengthSegment { seg_start_index: 5 },
+        ]
+    );
+
+    // figure eight touching at a vertex
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, 5.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (5.0, 5.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let issues = validate(&pline);
+    assert_eq!(
+        issues[0],
+        PlineValidateIssue::RepeatPosition {
+            index1: 1,
+            index2: 4
+        }
+    );
+    assert!(
+        issues[1..]
+            .iter()
+            .all(|i| matches!(i, PlineValidateIssue::SelfIntersect { .. }))
+    );
+}
+
+#[test]
+fn self_intersects() {
+    let bow_tie = pline_closed![
+        (0.0, 0.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (0.0, 2.0, 0.0)
+    ];
+    // signed areas of the two loops cancel
+    let issues = validate(&bow_tie);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0], PlineValidateIssue::ZeroArea);
+    match issues[1] {
+        PlineValidateIssue::SelfIntersect {
+            seg_start_index1,
+            seg_start_index2,
+            point,
+        } => {
+            let mut segs = [seg_start_index1, seg_start_index2];
+            segs.sort_unstable();
+            assert_eq!(segs, [0, 2]);
+            assert_fuzzy_eq!(point.x, 1.0);
+            assert_fuzzy_eq!(point.y, 1.0);
+        }
+        _ => panic!("expected self intersect"),
+    }
+
+    // open polyline folding back on itself
+    let pline = pline_open![(0.0, 0.0, 0.0), (5.0, 0.0, 0.0), (2.0, 0.0, 0.0)];
+    let issues = validate(&pline);
+    assert_eq!(issues.len(), 1);
+    assert!(matches!(
+        issues[0],
+        PlineValidateIssue::SelfOverlap {
+            seg_start_index1: 0,
+            seg_start_index2: 1,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn near_touch() {
+    // notch with sides 0.5e-4 apart
+    let gap = 0.5e-4;
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (5.0, 0.0, 0.0),
+        (5.0, 8.0, 0.0),
+        (5.0 + gap, 8.0, 0.0),
+        (5.0 + gap, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let issues = validate(&pline);
+    // notch sides and the bottom segments either side of the notch
+    let pairs: Vec<_> = issues
+        .iter()
+        .map(|i| match *i {
+            PlineValidateIssue::NearTouch {
+                seg_start_index1,
+                seg_start_index2,
+                distance,
+            } => {
+                assert_fuzzy_eq!(distance, gap);
+                (seg_start_index1, seg_start_index2)
+            }
+            _ => panic!("expected near touch"),
+        })
+        .collect();
+    assert_eq!(pairs, [(0, 3), (0, 4), (1, 3), (1, 4)]);
+
+    let options = PlineValidateOptions {
+        near_touch_distance: 0.0,
+        ..Default::default()
+    };
+    assert!(pline.validate(&options).is_valid());
+
+    // arc bulging toward a line segment
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 2.0, 0.0),
+        (6.0, 2.0, -1.0),
+        (4.0, 2.0, 0.0),
+        (0.0, 2.0, 0.0)
+    ];
+    let options = PlineValidateOptions {
+        near_touch_distance: 1.5,
+        ..Default::default()
+    };
+    let issues = pline.validate(&options).issues;
+    assert_eq!(issues.len(), 1);
+    match issues[0] {
+        PlineValidateIssue::NearTouch {
+            seg_start_index1,
+            seg_start_index2,
+            distance,
+        } => {
+            assert_eq!((seg_start_index1, seg_start_index2), (0, 3));
+            assert_fuzzy_eq!(distance, 1.0);
+        }
+        _ => panic!("expected near touch"),
+    }
+}
@@ -0,0 +1,224 @@
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_closed_userdata, pline_open,
+    polyline::{
+        OffsetDistance, PlineOffsetOptions, PlineSource, PlineVariableOffsetOptions, PlineVertex,
+        Polyline,
+    },
+};
+use std::f64::consts::PI;
+
+const EPS: f64 = 1e-3;
+
+fn test_plines() -> Vec<Polyline> {
+    vec![
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ],
+        // circle
+        pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)],
+        // closed with arcs and a narrow neck (pinches off when offset inward)
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.5),
+            (10.0, 4.0, 0.0),
+            (6.0, 4.5, 0.0),
+            (10.0, 5.0, -0.5),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0),
+            (0.0, 5.0, 0.0),
+            (4.0, 4.5, 0.0),
+            (0.0, 4.0, 0.0)
+        ],
+        pline_open![
+            (0.0, 0.0, 0.0),
+            (5.0, 0.0, 0.5),
+            (5.0, 5.0, 0.0),
+            (0.0, 5.0, -1.0),
+            (0.0, 8.0, 0.0)
+        ],
+        // open U shape (collapses when offset inward far enough)
+        pline_open![
+            (0.0, 10.0, 0.0),
+            (0.0, 0.0, 0.0),
+            (3.0, 0.0, 0.0),
+            (3.0, 10.0, 0.0)
+        ],
+    ]
+}
+
+fn assert_same_as_constant(results: &[Polyline], expected: &[Polyline]) {
+    assert_eq!(results.len(), expected.len());
+    for (r, e) in results.iter().zip(expected.iter()) {
+        assert_eq!(r.is_closed(), e.is_closed());
+        assert!(r.area().fuzzy_eq_eps(e.area(), EPS));
+        assert!(r.path_length().fuzzy_eq_eps(e.path_length(), EPS));
+    }
+}
+
+#[test]
+fn constant_distance_same_as_parallel_offset() {
+    for pline in test_plines() {
+        for offset in [-2.0, -0.5, 0.5, 1.0, 2.5] {
+            let expected = pline.parallel_offset(offset);
+
+            let distances = vec![offset; pline.vertex_count()];
+            let results = pline.variable_offset(OffsetDistance::PerVertex(&distances));
+            assert_same_as_constant(&results, &expected);
+
+            let distance_fn = |_: f64| offset;
+            let results = pline.variable_offset(OffsetDistance::PathLength(&distance_fn));
+            assert_same_as_constant(&results, &expected);
+        }
+    }
+}
+
+#[test]
+fn constant_distance_handle_self_intersects() {
+    // figure eight
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (4.0, 4.0, 0.0),
+        (4.0, 0.0, 0.0),
+        (0.0, 4.0, 0.0)
+    ];
+    let options = PlineOffsetOptions {
+        handle_self_intersects: true,
+        ..Default::default()
+    };
+    let variable_options = PlineVariableOffsetOptions {
+        handle_self_intersects: true,
+        ..Default::default()
+    };
+
+    for offset in [-0.25f64, 0.25] {
+        let mut expected = pline.parallel_offset_opt(offset, &options);
+        let mut results =
+            pline.variable_offset_opt(OffsetDistance::PerVertex(&[offset; 4]), &variable_options);
+        // order of results may differ
+        expected.sort_by(|a, b| a.area().total_cmp(&b.area()));
+        results.sort_by(|a, b| a.area().total_cmp(&b.area()));
+        assert_same_as_constant(&results, &expected);
+    }
+}
+
+#[test]
+fn tapered_line() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    let results = pline.variable_offset(OffsetDistance::PerVertex(&[1.0, 2.0]));
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert!(!result.is_closed());
+    assert_eq!(result.vertex_count(), 2);
+    assert!(result[0].fuzzy_eq(PlineVertex::new(0.0, 1.0, 0.0)));
+    assert!(result[1].fuzzy_eq(PlineVertex::new(10.0, 2.0, 0.0)));
+
+    // negative distances offset to the right
+    let results = pline.variable_offset(OffsetDistance::PerVertex(&[-1.0, -2.0]));
+    assert_eq!(results.len(), 1);
+    assert!(results[0][0].fuzzy_eq(PlineVertex::new(0.0, -1.0, 0.0)));
+    assert!(results[0][1].fuzzy_eq(PlineVertex::new(10.0, -2.0, 0.0)));
+}
+
+#[test]
+fn taper_to_zero_at_open_ends() {
+    let pline = pline_open![
+        (0.0, 0.0, 0.0),
+        (5.0, 0.0, 0.5),
+        (5.0, 5.0, 0.0),
+        (0.0, 5.0, -1.0),
+        (0.0, 8.0, 0.0)
+    ];
+    let distances = [0.0, 1.0, 2.0, 1.0, 0.0];
+    for distances in [distances, distances.map(|d| -d)] {
+        let results = pline.variable_offset(OffsetDistance::PerVertex(&distances));
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert!(!result.is_closed());
+        // starts and ends on the polyline
+        assert!(result[0].pos().fuzzy_eq_eps(Vector2::new(0.0, 0.0), EPS));
+        assert!(
+            result
+                .last()
+                .unwrap()
+                .pos()
+                .fuzzy_eq_eps(Vector2::new(0.0, 8.0), EPS)
+        );
+    }
+}
+
+#[test]
+fn closed_distance_by_path_length() {
+    let pline = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    // distance is 0.5 at the corners and 1.5 at the middle of each side
+    let distance_fn = |path_length: f64| 1.0 - 0.5 * (path_length * PI / 5.0).cos();
+    let tolerance = 1e-3;
+    let options = PlineVariableOffsetOptions {
+        tolerance,
+        ..Default::default()
+    };
+
+    for sign in [1.0, -1.0] {
+        let signed_distance_fn = |path_length: f64| sign * distance_fn(path_length);
+        let results =
+            pline.variable_offset_opt(OffsetDistance::PathLength(&signed_distance_fn), &options);
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert!(result.is_closed());
+
+        // offset reaches the distance given at the middle of each side
+        for (point, normal) in [
+            (Vector2::new(5.0, 0.0), Vector2::new(0.0, 1.0)),
+            (Vector2::new(10.0, 5.0), Vector2::new(-1.0, 0.0)),
+            (Vector2::new(5.0, 10.0), Vector2::new(0.0, -1.0)),
+            (Vector2::new(0.0, 5.0), Vector2::new(1.0, 0.0)),
+        ] {
+            let expected = point + normal.scale(sign * 1.5);
+            let closest = result.closest_point(expected, 1e-5).unwrap();
+            assert!(closest.distance < tolerance);
+        }
+
+        // all vertexes are within the distance range
+        for v in result.iter_vertexes() {
+            let closest = pline.closest_point(v.pos(), 1e-5).unwrap();
+            assert!(closest.distance > 0.5 - tolerance);
+            assert!(closest.distance < 1.5 + tolerance);
+        }
+    }
+}
+
+#[test]
+fn userdata_is_kept() {
+    let pline = pline_closed_userdata![
+        [7, 8],
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ];
+    let results = pline.variable_offset(OffsetDistance::PerVertex(&[1.0, 2.0, 1.0, 2.0]));
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].get_userdata_values().collect::<Vec<_>>(), [7, 8]);
+}
+
+#[test]
+#[should_panic(expected = "must have one offset distance per vertex")]
+fn per_vertex_count_mismatch_panics() {
+    let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0)];
+    pline.variable_offset(OffsetDistance::PerVertex(&[1.0]));
+}
+
+#[test]
+#[should_panic(expected = "must have one offset distance per vertex")]
+fn per_vertex_count_mismatch_panics_for_single_vertex() {
+    let pline = pline_open![(0.0, 0.0, 0.0)];
+    pline.variable_offset(OffsetDistance::PerVertex(&[1.0, 2.0]));
+}
@@ -0,0 +1,398 @@
+mod test_utils;
+
+use cavalier_contours::{
+    polyline::{BooleanOp, Polyline},
+    shape_algorithms::Shape,
+};
+use test_utils::{PlineProperties, create_property_set, property_sets_match, square, square_hole};
+
+fn run_shape_boolean_tests<I1, I2>(
+    input1: I1,
+    input2: I2,
+    operation: BooleanOp,
+    expected_properties_set: &[PlineProperties],
+) where
+    I1: IntoIterator<Item = Polyline>,
+    I2: IntoIterator<Item = Polyline>,
+{
+    let s1 = Shape::from_plines(input1);
+    let s2 = Shape::from_plines(input2);
+    let result = s1.boolean(&s2, operation);
+    let plines = result
+        .ccw_plines
+        .iter()
+        .chain(result.cw_plines.iter())
+        .map(|p| &p.polyline);
+    let result_properties = create_property_set(plines, false);
+
+    assert!(
+        property_sets_match(&result_properties, expected_properties_set),
+        "result property sets do not match for {operation:?}"
+    )
+}
+
+macro_rules! declare_boolean_tests {
+    ($($name:ident { $($value:expr => $expected:expr),+ $(,)? })*) => {
+        $(
+            #[test]
+            fn $name() {
+                $(
+                    run_shape_boolean_tests($value.0, $value.1, $value.2, &$expected);
+                )+
+            }
+        )+
+    };
+}
+
+mod test_simple {
+    use super::*;
+
+    declare_boolean_tests!(
+        empty_with_empty {
+            (Vec::<Polyline>::new(), Vec::<Polyline>::new(), BooleanOp::Or) => [],
+            (Vec::<Polyline>::new(), Vec::<Polyline>::new(), BooleanOp::And) => [],
+            (Vec::<Polyline>::new(), Vec::<Polyline>::new(), BooleanOp::Not) => [],
+            (Vec::<Polyline>::new(), Vec::<Polyline>::new(), BooleanOp::Xor) => []
+        }
+        empty_with_square {
+            (Vec::<Polyline>::new(), [square(0.0, 0.0, 4.0)], BooleanOp::Or) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])],
+            (Vec::<Polyline>::new(), [square(0.0, 0.0, 4.0)], BooleanOp::And) => [],
+            ([square(0.0, 0.0, 4.0)], Vec::<Polyline>::new(), BooleanOp::Not) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])],
+            (Vec::<Polyline>::new(), [square(0.0, 0.0, 4.0)], BooleanOp::Xor) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])]
+        }
+        overlapping_squares {
+            ([square(0.0, 0.0, 4.0)], [square(2.0, 2.0, 4.0)], BooleanOp::Or) =>
+                [PlineProperties::new(8, 28.0, 24.0, 0.0, 0.0, 6.0, 6.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(2.0, 2.0, 4.0)], BooleanOp::And) =>
+                [PlineProperties::new(4, 4.0, 8.0, 2.0, 2.0, 4.0, 4.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(2.0, 2.0, 4.0)], BooleanOp::Not) =>
+                [PlineProperties::new(6, 12.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(2.0, 2.0, 4.0)], BooleanOp::Xor) =>
+                [PlineProperties::new(6, 12.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![]),
+                 PlineProperties::new(6, 12.0, 16.0, 2.0, 2.0, 6.0, 6.0, vec![])]
+        }
+        disjoint_squares {
+            ([square(0.0, 0.0, 4.0)], [square(5.0, 0.0, 4.0)], BooleanOp::Or) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![]),
+                 PlineProperties::new(4, 16.0, 16.0, 5.0, 0.0, 9.0, 4.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(5.0, 0.0, 4.0)], BooleanOp::And) => [],
+            ([square(0.0, 0.0, 4.0)], [square(5.0, 0.0, 4.0)], BooleanOp::Not) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])]
+        }
+        identical_squares {
+            ([square(0.0, 0.0, 4.0)], [square(0.0, 0.0, 4.0)], BooleanOp::Or) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(0.0, 0.0, 4.0)], BooleanOp::And) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(0.0, 0.0, 4.0)], BooleanOp::Not) => [],
+            ([square(0.0, 0.0, 4.0)], [square(0.0, 0.0, 4.0)], BooleanOp::Xor) => []
+        }
+        squares_sharing_edge {
+            ([square(0.0, 0.0, 4.0)], [square(4.0, 0.0, 4.0)], BooleanOp::Or) =>
+                [PlineProperties::new(4, 32.0, 24.0, 0.0, 0.0, 8.0, 4.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(4.0, 0.0, 4.0)], BooleanOp::And) => [],
+            ([square(0.0, 0.0, 4.0)], [square(4.0, 0.0, 4.0)], BooleanOp::Not) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![])],
+            ([square(0.0, 0.0, 4.0)], [square(4.0, 0.0, 4.0)], BooleanOp::Xor) =>
+                [PlineProperties::new(4, 16.0, 16.0, 0.0, 0.0, 4.0, 4.0, vec![]),
+                 PlineProperties::new(4, 16.0, 16.0, 4.0, 0.0, 8.0, 4.0, vec![])]
+        }
+    );
+}
+
+mod test_holes {
+    use super::*;
+
+    declare_boolean_tests!(
+        island_inside_hole {
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)], [square(4.0, 4.0, 2.0)], BooleanOp::Or) =>
+                [PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![]),
+                 PlineProperties::new(4, -16.0, 16.0, 3.0, 3.0, 7.0, 7.0, vec![]),
+                 PlineProperties::new(4, 4.0, 8.0, 4.0, 4.0, 6.0, 6.0, vec![])],
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)], [square(4.0, 4.0, 2.0)], BooleanOp::And) => [],
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)], [square(4.0, 4.0, 2.0)], BooleanOp::Not) =>
+                [PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![]),
+                 PlineProperties::new(4, -16.0, 16.0, 3.0, 3.0, 7.0, 7.0, vec![])]
+        }
+        square_filling_hole {
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)], [square(2.0, 2.0, 6.0)], BooleanOp::Or) =>
+                [PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![])],
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)], [square(2.0, 2.0, 6.0)], BooleanOp::And) =>
+                [PlineProperties::new(4, 36.0, 24.0, 2.0, 2.0, 8.0, 8.0, vec![]),
+                 PlineProperties::new(4, -16.0, 16.0, 3.0, 3.0, 7.0, 7.0, vec![])],
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)], [square(2.0, 2.0, 6.0)], BooleanOp::Not) =>
+                [PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![]),
+                 PlineProperties::new(4, -36.0, 24.0, 2.0, 2.0, 8.0, 8.0, vec![])]
+        }
+        rectangle_crossing_hole {
+            // rectangle from inside the hole to outside of the outer boundary
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)],
+             [cavalier_contours::pline_closed![(5.0, 4.0, 0.0), (12.0, 4.0, 0.0), (12.0, 6.0, 0.0), (5.0, 6.0, 0.0)]],
+             BooleanOp::Or) =>
+                [PlineProperties::new(8, 104.0, 44.0, 0.0, 0.0, 12.0, 10.0, vec![]),
+                 PlineProperties::new(8, -12.0, 20.0, 3.0, 3.0, 7.0, 7.0, vec![])],
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)],
+             [cavalier_contours::pline_closed![(5.0, 4.0, 0.0), (12.0, 4.0, 0.0), (12.0, 6.0, 0.0), (5.0, 6.0, 0.0)]],
+             BooleanOp::And) =>
+                [PlineProperties::new(4, 6.0, 10.0, 7.0, 4.0, 10.0, 6.0, vec![])],
+            ([square(0.0, 0.0, 10.0), square_hole(3.0, 3.0, 4.0)],
+             [cavalier_contours::pline_closed![(5.0, 4.0, 0.0), (12.0, 4.0, 0.0), (12.0, 6.0, 0.0), (5.0, 6.0, 0.0)]],
+             BooleanOp::Not) =>
+                [PlineProperties::new(12, 78.0, 58.0, 0.0, 0.0, 10.0, 10.0, vec![])]
+        }
+    );
+}
+
+mod test_arcs {
+    use super::*;
+    use std::f64::consts::PI;
+
+    declare_boolean_tests!(
+        circle_and_square {
+            ([cavalier_contours::pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)]],
+             [square(0.0, 0.0, 2.0)],
+             BooleanOp::And) =>
+                [PlineProperties::new(3, PI / 4.0, 2.0 + PI / 2.0, 0.0, 0.0, 1.0, 1.0, vec![])],
+            ([cavalier_contours::pline_closed![(-1.0, 0.0, 1.0), (1.0, 0.0, 1.0)]],
+             [square(0.0, 0.0, 2.0)],
+             BooleanOp::Not) =>
+                [PlineProperties::new(4, 3.0 * PI / 4.0, 2.0 + 3.0 * PI / 2.0, -1.0, -1.0, 1.0, 1.0, vec![])]
+        }
+    );
+}
+
+mod test_many {
+    use super::*;
+    use cavalier_contours::{
+        assert_fuzzy_eq,
+        polyline::{FillRule, PlineSource, PlineSourceMut},
+    };
+    use std::f64::consts::PI;
+
+    fn run_boolean_many_tests<I>(
+        input: I,
+        fill_rule: FillRule,
+        expected_properties_set: &[PlineProperties],
+    ) where
+        I: IntoIterator<Item = Polyline>,
+    {
+        let result = Shape::boolean_many(input, fill_rule);
+        let plines = result
+            .ccw_plines
+            .iter()
+            .chain(result.cw_plines.iter())
+            .map(|p| &p.polyline);
+        let result_properties = create_property_set(plines, false);
+
+        assert!(
+            property_sets_match(&result_properties, expected_properties_set),
+            "result property sets do not match for {fill_rule:?}"
+        )
+    }
+
+    #[test]
+    fn empty() {
+        run_boolean_many_tests(Vec::new(), FillRule::NonZero, &[]);
+        // open polylines ignored
+        let mut open = square(0.0, 0.0, 4.0);
+        open.set_is_closed(false);
+        run_boolean_many_tests([open], FillRule::NonZero, &[]);
+    }
+
+    #[test]
+    fn single_polyline() {
+        let expected = [PlineProperties::new(
+            4,
+            16.0,
+            16.0,
+            0.0,
+            0.0,
+            4.0,
+            4.0,
+            vec![],
+        )];
+        run_boolean_many_tests([square(0.0, 0.0, 4.0)], FillRule::NonZero, &expected);
+        run_boolean_many_tests([square(0.0, 0.0, 4.0)], FillRule::Positive, &expected);
+        run_boolean_many_tests([square(0.0, 0.0, 4.0)], FillRule::Negative, &[]);
+        // clockwise polyline returned counter clockwise
+        run_boolean_many_tests([square_hole(0.0, 0.0, 4.0)], FillRule::Negative, &expected);
+        run_boolean_many_tests([square_hole(0.0, 0.0, 4.0)], FillRule::Positive, &[]);
+    }
+
+    #[test]
+    fn overlapping_chain() {
+        let input = [
+            square(0.0, 0.0, 4.0),
+            square(2.0, 2.0, 4.0),
+            square(4.0, 4.0, 4.0),
+        ];
+        run_boolean_many_tests(
+            input.clone(),
+            FillRule::NonZero,
+            &[PlineProperties::new(
+                12,
+                40.0,
+                32.0,
+                0.0,
+                0.0,
+                8.0,
+                8.0,
+                vec![],
+            )],
+        );
+
+        // overlapping regions have winding number 2 and are removed
+        let result = Shape::boolean_many(input.clone(), FillRule::EvenOdd);
+        assert_fuzzy_eq!(result.area(), 40.0 - 8.0);
+
+        // all counter clockwise so same as non zero
+        run_boolean_many_tests(
+            input,
+            FillRule::Positive,
+            &[PlineProperties::new(
+                12,
+                40.0,
+                32.0,
+                0.0,
+                0.0,
+                8.0,
+                8.0,
+                vec![],
+            )],
+        );
+    }
+
+    #[test]
+    fn identical_polylines() {
+        let square_props = [PlineProperties::new(
+            4,
+            16.0,
+            16.0,
+            0.0,
+            0.0,
+            4.0,
+            4.0,
+            vec![],
+        )];
+        let input = [square(0.0, 0.0, 4.0), square(0.0, 0.0, 4.0)];
+        run_boolean_many_tests(input.clone(), FillRule::NonZero, &square_props);
+        run_boolean_many_tests(input, FillRule::EvenOdd, &[]);
+
+        let input = [square(0.0, 0.0, 4.0), square_hole(0.0, 0.0, 4.0)];
+        run_boolean_many_tests(input, FillRule::NonZero, &[]);
+
+        let input = [
+            square_hole(0.0, 0.0, 4.0),
+            square_hole(0.0, 0.0, 4.0),
+            square_hole(0.0, 0.0, 4.0),
+        ];
+        run_boolean_many_tests(input, FillRule::Negative, &square_props);
+    }
+
+    #[test]
+    fn holes_and_islands() {
+        // square with hole and island inside of hole
+        let input = [
+            square(0.0, 0.0, 10.0),
+            square_hole(3.0, 3.0, 4.0),
+            square(4.0, 4.0, 2.0),
+        ];
+        let expected = [
+            PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![]),
+            PlineProperties::new(4, -16.0, 16.0, 3.0, 3.0, 7.0, 7.0, vec![]),
+            PlineProperties::new(4, 4.0, 8.0, 4.0, 4.0, 6.0, 6.0, vec![]),
+        ];
+        run_boolean_many_tests(input.clone(), FillRule::NonZero, &expected);
+        run_boolean_many_tests(input, FillRule::EvenOdd, &expected);
+
+        // nested counter clockwise squares, even odd alternates filled and hole
+        let input = [
+            square(0.0, 0.0, 10.0),
+            square(2.0, 2.0, 6.0),
+            square(4.0, 4.0, 2.0),
+        ];
+        run_boolean_many_tests(
+            input.clone(),
+            FillRule::EvenOdd,
+            &[
+                PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![]),
+                PlineProperties::new(4, -36.0, 24.0, 2.0, 2.0, 8.0, 8.0, vec![]),
+                PlineProperties::new(4, 4.0, 8.0, 4.0, 4.0, 6.0, 6.0, vec![]),
+            ],
+        );
+        run_boolean_many_tests(
+            input,
+            FillRule::NonZero,
+            &[PlineProperties::new(
+                4,
+                100.0,
+                40.0,
+                0.0,
+                0.0,
+                10.0,
+                10.0,
+                vec![],
+            )],
+        );
+    }
+
+    #[test]
+    fn self_intersecting_input() {
+        let bow_tie: Polyline = cavalier_contours::pline_closed![
+            (0.0, 0.0, 0.0),
+            (2.0, 2.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (0.0, 2.0, 0.0)
+        ];
+        // both loops kept counter clockwise
+        let result = Shape::boolean_many([bow_tie.clone()], FillRule::NonZero);
+        assert!(result.cw_plines.is_empty());
+        assert_fuzzy_eq!(result.area(), 2.0);
+
+        // only the counter clockwise loop
+        let result = Shape::boolean_many([bow_tie.clone()], FillRule::Positive);
+        assert_eq!(result.ccw_plines.len(), 1);
+        assert_fuzzy_eq!(result.area(), 1.0);
+
+        // square covering the clockwise loop cancels it out
+        let result = Shape::boolean_many([bow_tie, square(1.0, 0.0, 2.0)], FillRule::NonZero);
+        assert_fuzzy_eq!(result.area(), 1.0 + 4.0 - 1.0);
+    }
+
+    #[test]
+    fn grid_of_squares() {
+        let squares = (0..20).flat_map(|i| (0..20).map(move |j| square(i as f64, j as f64, 1.5)));
+        let result = Shape::boolean_many(squares, FillRule::NonZero);
+        assert_eq!(result.ccw_plines.len(), 1);
+        assert!(result.cw_plines.is_empty());
+        assert_fuzzy_eq!(result.area(), 20.5 * 20.5);
+
+        // along each axis the covering count is odd over a total length of 11 (ends and half of
+        // every unit in between)
+        let squares = (0..20).flat_map(|i| (0..20).map(move |j| square(i as f64, j as f64, 1.5)));
+        let result = Shape::boolean_many(squares, FillRule::EvenOdd);
+        assert_fuzzy_eq!(result.area(), 11.0 * 11.0);
+    }
+
+    #[test]
+    fn overlapping_circles() {
+        let circles = (0..4).map(|i| {
+            let x = i as f64;
+            cavalier_contours::pline_closed![(x - 1.0, 0.0, 1.0), (x + 1.0, 0.0, 1.0)]
+        });
+        let result = Shape::boolean_many(circles, FillRule::NonZero);
+        assert_eq!(result.ccw_plines.len(), 1);
+        assert!(result.cw_plines.is_empty());
+        // circle lens area for unit circles with centers 1 apart
+        let lens = 2.0 * PI / 3.0 - 3.0f64.sqrt() / 2.0;
+        assert_fuzzy_eq!(result.area(), 4.0 * PI - 3.0 * lens, 1e-5);
+        let extents = result.extents().unwrap();
+        assert_fuzzy_eq!(extents.min_x, -1.0);
+        assert_fuzzy_eq!(extents.max_x, 4.0);
+        assert_fuzzy_eq!(extents.max_y, 1.0);
+        assert!(result.ccw_plines[0].polyline.vertex_count() > 2);
+    }
+}
@@ -0,0 +1,138 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq, pline_closed, pline_open,
+    polyline::{PlineOrientation, PlineSource, Polyline},
+    shape_algorithms::{NestedShape, Shape},
+};
+use std::f64::consts::PI;
+use test_utils::{square, square_hole};
+
+/// Assert orientation follows depth parity and parent/child links are consistent.
+fn assert_nesting_valid(nested: &NestedShape<f64>) {
+    assert_eq!(
+        nested.loops.len(),
+        nested.shape.ccw_plines.len() + nested.shape.cw_plines.len()
+    );
+    for (i, l) in nested.loops.iter().enumerate() {
+        let expected = if l.depth.is_multiple_of(2) {
+            PlineOrientation::CounterClockwise
+        } else {
+            PlineOrientation::Clockwise
+        };
+        assert_eq!(nested.pline(i).orientation(), expected, "loop {i}");
+        match l.parent {
+            Some(p) => {
+                assert_eq!(nested.loops[p].depth + 1, l.depth);
+                assert!(nested.loops[p].children.contains(&i));
+            }
+            None => assert_eq!(l.depth, 0),
+        }
+        for &c in &l.children {
+            assert_eq!(nested.loops[c].parent, Some(i));
+        }
+    }
+}
+
+/// Shape polyline index for the input index given.
+fn shape_index(nested: &NestedShape<f64>, input_index: usize) -> usize {
+    nested
+        .loops
+        .iter()
+        .position(|l| l.input_index == input_index)
+        .unwrap()
+}
+
+#[test]
+fn deep_nesting_orientation_normalized() {
+    // outer -> hole -> island -> hole in island, all given wound the wrong way or arbitrarily
+    let inputs = vec![
+        square(3.0, 3.0, 4.0),
+        square_hole(0.0, 0.0, 10.0),
+        square_hole(2.0, 2.0, 6.0),
+        pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)],
+    ];
+    let nested = Shape::from_plines_nested(inputs);
+    assert_nesting_valid(&nested);
+    assert_eq!(nested.shape.ccw_plines.len(), 2);
+    assert_eq!(nested.shape.cw_plines.len(), 2);
+
+    let depths: Vec<_> = (0..4)
+        .map(|i| nested.loops[shape_index(&nested, i)].depth)
+        .collect();
+    assert_eq!(depths, [2, 0, 1, 3]);
+    assert_eq!(
+        nested.roots().collect::<Vec<_>>(),
+        [shape_index(&nested, 1)]
+    );
+    assert_eq!(
+        nested.loops[shape_index(&nested, 2)].parent,
+        Some(shape_index(&nested, 1))
+    );
+    assert_eq!(
+        nested.loops[shape_index(&nested, 3)].parent,
+        Some(shape_index(&nested, 0))
+    );
+
+    assert_fuzzy_eq!(nested.shape.area(), 100.0 - 36.0 + 16.0 - PI);
+}
+
+#[test]
+fn multiple_outer_with_sibling_holes() {
+    let inputs = vec![
+        square(0.0, 0.0, 10.0),
+        square(1.0, 1.0, 2.0),
+        square(5.0, 5.0, 2.0),
+        square(20.0, 0.0, 10.0),
+        square(21.0, 1.0, 2.0),
+    ];
+    let nested = Shape::from_plines_nested(inputs);
+    assert_nesting_valid(&nested);
+    assert_eq!(nested.roots().count(), 2);
+    let first = &nested.loops[shape_index(&nested, 0)];
+    let mut children: Vec<_> = first
+        .children
+        .iter()
+        .map(|&c| nested.loops[c].input_index)
+        .collect();
+    children.sort_unstable();
+    assert_eq!(children, [1, 2]);
+    let second = &nested.loops[shape_index(&nested, 3)];
+    assert_eq!(second.children, [shape_index(&nested, 4)]);
+    assert_fuzzy_eq!(nested.shape.area(), 200.0 - 12.0);
+}
+
+#[test]
+fn touching_boundaries() {
+    // hole shares a corner and two sides with the outer boundary
+    let inputs = vec![square(0.0, 0.0, 10.0), square(0.0, 0.0, 5.0)];
+    let nested = Shape::from_plines_nested(inputs);
+    assert_nesting_valid(&nested);
+    assert_eq!(nested.loops[shape_index(&nested, 1)].depth, 1);
+    assert_fuzzy_eq!(nested.shape.area(), 75.0);
+
+    // squares touching side by side are both outer boundaries
+    let inputs = vec![square(0.0, 0.0, 5.0), square(5.0, 0.0, 5.0)];
+    let nested = Shape::from_plines_nested(inputs);
+    assert_nesting_valid(&nested);
+    assert_eq!(nested.roots().count(), 2);
+}
+
+#[test]
+fn invalid_inputs_skipped() {
+    let inputs = vec![
+        pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+        pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+        Polyline::new_closed(),
+        square_hole(0.0, 0.0, 10.0),
+    ];
+    let nested = Shape::from_plines_nested(inputs);
+    assert_eq!(nested.loops.len(), 1);
+    assert_eq!(nested.loops[0].input_index, 3);
+    assert_eq!(nested.shape.ccw_plines.len(), 1);
+    assert_fuzzy_eq!(nested.shape.area(), 100.0);
+
+    let nested = Shape::<f64>::from_plines_nested([]);
+    assert!(nested.loops.is_empty());
+    assert!(nested.shape.ccw_plines.is_empty());
+}
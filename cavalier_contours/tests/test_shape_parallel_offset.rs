@@ -1,6 +1,10 @@
 mod test_utils;
 
-use cavalier_contours::{polyline::Polyline, shape_algorithms::Shape};
+use cavalier_contours::{
+    pline_closed_userdata,
+    polyline::{JoinStyle, Polyline},
+    shape_algorithms::{Shape, ShapeOffsetOptions},
+};
 use test_utils::{PlineProperties, create_property_set};
 
 use crate::test_utils::property_sets_match;
@@ -8,9 +12,20 @@ use crate::test_utils::property_sets_match;
 fn run_shape_offset_tests<I>(input: I, offset: f64, expected_properties_set: &[PlineProperties])
 where
     I: IntoIterator<Item = Polyline>,
+{
+    run_shape_offset_tests_opt(input, offset, Default::default(), expected_properties_set);
+}
+
+fn run_shape_offset_tests_opt<I>(
+    input: I,
+    offset: f64,
+    options: ShapeOffsetOptions<f64>,
+    expected_properties_set: &[PlineProperties],
+) where
+    I: IntoIterator<Item = Polyline>,
 {
     let s = Shape::from_plines(input);
-    let result = s.parallel_offset(offset, Default::default());
+    let result = s.parallel_offset(offset, options);
     let plines = result
         .ccw_plines
         .iter()
@@ -74,3 +89,253 @@ mod test_specific {
         }
     );
 }
+
+/// 10x10 square with a 2x2 square hole in the middle.
+fn square_with_hole() -> [Polyline; 2] {
+    [
+        pline_closed_userdata![
+            [4],
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ],
+        pline_closed_userdata![
+            [117],
+            (4.0, 4.0, 0.0),
+            (4.0, 6.0, 0.0),
+            (6.0, 6.0, 0.0),
+            (6.0, 4.0, 0.0)
+        ],
+    ]
+}
+
+mod test_join_styles {
+    use super::*;
+
+    #[test]
+    fn hole_grows_with_miter_corners() {
+        let options = ShapeOffsetOptions {
+            join_style: JoinStyle::Miter { limit: 4.0 },
+            ..Default::default()
+        };
+        run_shape_offset_tests_opt(
+            square_with_hole(),
+            1.0,
+            options,
+            &[
+                PlineProperties::new(4, 64.0, 32.0, 1.0, 1.0, 9.0, 9.0, vec![4]),
+                PlineProperties::new(4, -16.0, 16.0, 3.0, 3.0, 7.0, 7.0, vec![117]),
+            ],
+        );
+    }
+
+    #[test]
+    fn hole_grows_with_bevel_corners() {
+        let options = ShapeOffsetOptions {
+            join_style: JoinStyle::Bevel,
+            ..Default::default()
+        };
+        run_shape_offset_tests_opt(
+            square_with_hole(),
+            1.0,
+            options,
+            &[
+                PlineProperties::new(4, 64.0, 32.0, 1.0, 1.0, 9.0, 9.0, vec![4]),
+                PlineProperties::new(
+                    8,
+                    -14.0,
+                    8.0 + 4.0 * std::f64::consts::SQRT_2,
+                    3.0,
+                    3.0,
+                    7.0,
+                    7.0,
+                    vec![117],
+                ),
+            ],
+        );
+    }
+}
+
+mod test_many_loops {
+    use super::*;
+    use cavalier_contours::{
+        pline_closed,
+        polyline::{PlineSource, PlineSourceMut},
+    };
+
+    /// 100x100 square with a 10x10 grid of circle holes of radius 2 spaced 9 apart.
+    fn plate_with_holes() -> Vec<Polyline> {
+        let mut plines = vec![pline_closed![
+            (0.0, 0.0, 0.0),
+            (100.0, 0.0, 0.0),
+            (100.0, 100.0, 0.0),
+            (0.0, 100.0, 0.0)
+        ]];
+        for i in 0..10 {
+            for j in 0..10 {
+                let x = 10.0 + 9.0 * i as f64;
+                let y = 10.0 + 9.0 * j as f64;
+                let mut hole = Polyline::new_closed();
+                hole.add(x - 2.0, y, -1.0);
+                hole.add(x + 2.0, y, -1.0);
+                plines.push(hole);
+            }
+        }
+        plines
+    }
+
+    fn offset_vertexes(shape: &Shape<f64>, offset: f64) -> Vec<Vec<(f64, f64, f64)>> {
+        let result = shape.parallel_offset(offset, Default::default());
+        result
+            .ccw_plines
+            .iter()
+            .chain(result.cw_plines.iter())
+            .map(|p| {
+                p.polyline
+                    .iter_vertexes()
+                    .map(|v| (v.x, v.y, v.bulge))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn holes_grow() {
+        let shape = Shape::from_plines(plate_with_holes());
+        let result = shape.parallel_offset(1.0, Default::default());
+        assert_eq!(result.ccw_plines.len(), 1);
+        assert_eq!(result.cw_plines.len(), 100);
+        // holes are ordered by source loop
+        let first_hole = result.cw_plines[0].polyline.extents().unwrap();
+        assert!((first_hole.min_x - 7.0).abs() < 1e-5);
+        assert!((first_hole.min_y - 7.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn holes_merge() {
+        // holes grow to radius 5 and overlap their neighbors
+        let shape = Shape::from_plines(plate_with_holes());
+        let result = shape.parallel_offset(3.0, Default::default());
+        // outer boundary and the 81 islands left between the holes
+        assert_eq!(result.ccw_plines.len(), 82);
+        // single hole formed from all the merged holes
+        assert_eq!(result.cw_plines.len(), 1);
+        let hole = result.cw_plines[0].polyline.extents().unwrap();
+        assert!((hole.min_x - 5.0).abs() < 1e-5);
+        assert!((hole.max_x - 96.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn deterministic_output() {
+        let shape = Shape::from_plines(plate_with_holes());
+        for offset in [1.0, 3.0, -1.0] {
+            let expected = offset_vertexes(&shape, offset);
+            for _ in 0..3 {
+                assert_eq!(offset_vertexes(&shape, offset), expected);
+            }
+        }
+    }
+}
+
+mod test_loop_offsets {
+    use super::*;
+    use cavalier_contours::shape_algorithms::ShapeLoopKey;
+
+    fn expected_grown_hole() -> [PlineProperties; 2] {
+        [
+            PlineProperties::new(4, 81.0, 36.0, 0.5, 0.5, 9.5, 9.5, vec![4]),
+            PlineProperties::new(
+                8,
+                -(20.0 + 4.0 * std::f64::consts::PI),
+                8.0 + 4.0 * std::f64::consts::PI,
+                2.0,
+                2.0,
+                8.0,
+                8.0,
+                vec![117],
+            ),
+        ]
+    }
+
+    #[test]
+    fn hole_offset_by_userdata() {
+        let options = ShapeOffsetOptions {
+            loop_offsets: vec![(ShapeLoopKey::Userdata(117), 2.0)],
+            ..Default::default()
+        };
+        run_shape_offset_tests_opt(square_with_hole(), 0.5, options, &expected_grown_hole());
+    }
+
+    #[test]
+    fn outer_offset_by_index() {
+        let options = ShapeOffsetOptions {
+            loop_offsets: vec![(ShapeLoopKey::Index(0), 0.5)],
+            ..Default::default()
+        };
+        run_shape_offset_tests_opt(square_with_hole(), 2.0, options, &expected_grown_hole());
+    }
+
+    #[test]
+    fn first_matching_key_used() {
+        let options = ShapeOffsetOptions {
+            loop_offsets: vec![
+                (ShapeLoopKey::Userdata(117), 2.0),
+                (ShapeLoopKey::Index(1), 1.0),
+                (ShapeLoopKey::Userdata(4), 0.5),
+            ],
+            ..Default::default()
+        };
+        run_shape_offset_tests_opt(square_with_hole(), 3.0, options, &expected_grown_hole());
+    }
+
+    #[test]
+    fn outer_not_offset() {
+        let options = ShapeOffsetOptions {
+            loop_offsets: vec![(ShapeLoopKey::Userdata(4), 0.0)],
+            ..Default::default()
+        };
+        run_shape_offset_tests_opt(
+            square_with_hole(),
+            1.0,
+            options,
+            &[
+                PlineProperties::new(4, 100.0, 40.0, 0.0, 0.0, 10.0, 10.0, vec![4]),
+                PlineProperties::new(
+                    8,
+                    -(12.0 + std::f64::consts::PI),
+                    8.0 + 2.0 * std::f64::consts::PI,
+                    3.0,
+                    3.0,
+                    7.0,
+                    7.0,
+                    vec![117],
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn differently_offset_loops_intersect() {
+        // hole grows past the inset outer boundary leaving only the four corners
+        let options = ShapeOffsetOptions {
+            loop_offsets: vec![(ShapeLoopKey::Userdata(117), 3.5)],
+            ..Default::default()
+        };
+        let area = 0.5990147781603117;
+        let path_length = 4.104539186618128;
+        let a = 2.197224362268005;
+        let b = 10.0 - a;
+        run_shape_offset_tests_opt(
+            square_with_hole(),
+            1.0,
+            options,
+            &[
+                PlineProperties::new(3, area, path_length, 1.0, 1.0, a, a, vec![4, 117]),
+                PlineProperties::new(3, area, path_length, b, 1.0, 9.0, a, vec![4, 117]),
+                PlineProperties::new(3, area, path_length, b, b, 9.0, 9.0, vec![4, 117]),
+                PlineProperties::new(3, area, path_length, 1.0, b, a, 9.0, vec![4, 117]),
+            ],
+        );
+    }
+}
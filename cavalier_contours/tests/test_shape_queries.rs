@@ -0,0 +1,101 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq, core::math::Vector2, pline_closed, shape_algorithms::Shape,
+};
+use std::f64::consts::PI;
+use test_utils::{square, square_hole};
+
+/// Square with a hole, a circle island inside the hole, and a separate square.
+fn nested_shape() -> Shape<f64> {
+    let circle = pline_closed![(4.0, 5.0, 1.0), (6.0, 5.0, 1.0)];
+    Shape::from_plines([
+        square(0.0, 0.0, 10.0),
+        square_hole(2.0, 2.0, 6.0),
+        circle,
+        square(20.0, 0.0, 5.0),
+    ])
+}
+
+#[test]
+fn area() {
+    let shape = nested_shape();
+    assert_fuzzy_eq!(shape.area(), 100.0 - 36.0 + PI + 25.0);
+    assert_eq!(Shape::<f64>::empty().area(), 0.0);
+}
+
+#[test]
+fn extents() {
+    let shape = nested_shape();
+    let extents = shape.extents().unwrap();
+    assert_fuzzy_eq!(extents.min_x, 0.0);
+    assert_fuzzy_eq!(extents.min_y, 0.0);
+    assert_fuzzy_eq!(extents.max_x, 25.0);
+    assert_fuzzy_eq!(extents.max_y, 10.0);
+
+    // arcs use their exact extents
+    let circle = pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)];
+    let extents = Shape::from_plines([circle]).extents().unwrap();
+    assert_fuzzy_eq!(extents.min_x, 0.0);
+    assert_fuzzy_eq!(extents.min_y, -1.0);
+    assert_fuzzy_eq!(extents.max_x, 2.0);
+    assert_fuzzy_eq!(extents.max_y, 1.0);
+
+    assert!(Shape::<f64>::empty().extents().is_none());
+}
+
+#[test]
+fn winding_number_and_contains_point() {
+    let shape = nested_shape();
+    let cases = [
+        // filled area of outer square
+        ((1.0, 1.0), 1),
+        // inside hole
+        ((3.0, 3.0), 0),
+        // inside circle island
+        ((5.0, 5.0), 1),
+        // separate square
+        ((22.0, 2.0), 1),
+        // outside all
+        ((15.0, 5.0), 0),
+        ((-1.0, 5.0), 0),
+    ];
+
+    for ((x, y), expected) in cases {
+        let pt = Vector2::new(x, y);
+        assert_eq!(shape.winding_number(pt), expected, "point {pt:?}");
+        assert_eq!(shape.contains_point(pt), expected != 0, "point {pt:?}");
+    }
+
+    assert!(!Shape::<f64>::empty().contains_point(Vector2::new(0.0, 0.0)));
+}
+
+#[test]
+fn closest_point() {
+    let shape = nested_shape();
+
+    // inside hole, closest to circle island
+    let result = shape.closest_point(Vector2::new(5.0, 3.2), 1e-5).unwrap();
+    assert_eq!(result.pline_index, 1);
+    assert!(result.seg_point.fuzzy_eq(Vector2::new(5.0, 4.0)));
+    assert_fuzzy_eq!(result.distance, 0.8);
+
+    // inside hole, closest to hole boundary
+    let result = shape.closest_point(Vector2::new(7.5, 3.0), 1e-5).unwrap();
+    // ccw plines first (outer square, circle, separate square) then hole
+    assert_eq!(result.pline_index, 3);
+    assert!(result.seg_point.fuzzy_eq(Vector2::new(8.0, 3.0)));
+    assert_fuzzy_eq!(result.distance, 0.5);
+
+    // between the two squares
+    let result = shape.closest_point(Vector2::new(16.0, 2.0), 1e-5).unwrap();
+    assert_eq!(result.pline_index, 2);
+    assert!(result.seg_point.fuzzy_eq(Vector2::new(20.0, 2.0)));
+    assert_fuzzy_eq!(result.distance, 4.0);
+
+    assert!(
+        Shape::<f64>::empty()
+            .closest_point(Vector2::new(0.0, 0.0), 1e-5)
+            .is_none()
+    );
+}
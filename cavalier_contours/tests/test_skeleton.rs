@@ -0,0 +1,221 @@
+mod test_utils;
+
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{PlineSource, PlineSourceMut, Polyline},
+    shape_algorithms::Shape,
+    skeleton::{MedialAxis, MedialAxisOptions, pline_medial_axis, shape_medial_axis},
+};
+use test_utils::rectangle;
+
+const EPS: f64 = 1e-2;
+
+/// Check every medial axis point lies inside the shape (or on a corner with zero radius), has the
+/// clearance radius of its position, and that all edges are connected.
+fn assert_valid_medial_axis(shape: &Shape<f64>, axis: &MedialAxis<f64>) {
+    assert!(!axis.edges.is_empty());
+    for edge in axis.edges.iter() {
+        assert!(!edge.points.is_empty());
+        for p in edge.points.iter() {
+            let closest = shape.closest_point(p.pos, 1e-5).unwrap();
+            assert!(closest.distance.fuzzy_eq_eps(p.radius, 1e-5));
+            assert!(p.radius == 0.0 || shape.contains_point(p.pos));
+        }
+    }
+
+    // edges connect at their end points
+    let mut connected = vec![false; axis.edges.len()];
+    connected[0] = true;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..axis.edges.len() {
+            if connected[i] {
+                continue;
+            }
+            let e1 = &axis.edges[i];
+            let ends1 = [e1.points[0].pos, e1.points.last().unwrap().pos];
+            let touches = axis.edges.iter().zip(connected.iter()).any(|(e2, &c)| {
+                c && [e2.points[0].pos, e2.points.last().unwrap().pos]
+                    .iter()
+                    .any(|p2| ends1.iter().any(|p1| p1.fuzzy_eq(*p2)))
+            });
+            if touches {
+                connected[i] = true;
+                changed = true;
+            }
+        }
+    }
+    assert!(connected.iter().all(|&c| c));
+}
+
+#[test]
+fn rectangle_medial_axis() {
+    let axis = pline_medial_axis(&rectangle(0.0, 0.0, 10.0, 4.0), &MedialAxisOptions::new());
+    assert_valid_medial_axis(&Shape::from_plines([rectangle(0.0, 0.0, 10.0, 4.0)]), &axis);
+    // center line plus a branch to each corner
+    assert_eq!(axis.edges.len(), 5);
+
+    let center_line = axis
+        .edges
+        .iter()
+        .find(|e| e.points.iter().all(|p| p.radius > 1.0))
+        .unwrap();
+    for p in center_line.points.iter() {
+        assert!(p.pos.y.fuzzy_eq_eps(2.0, EPS));
+        assert!(p.radius.fuzzy_eq_eps(2.0, EPS));
+    }
+    let (start, end) = (
+        center_line.points[0].pos,
+        center_line.points.last().unwrap().pos,
+    );
+    let (min_x, max_x) = if start.x < end.x {
+        (start.x, end.x)
+    } else {
+        (end.x, start.x)
+    };
+    assert!(min_x.fuzzy_eq_eps(2.0, EPS));
+    assert!(max_x.fuzzy_eq_eps(8.0, EPS));
+
+    // corner branches end at the corners with zero radius
+    for corner in rectangle(0.0, 0.0, 10.0, 4.0)
+        .iter_vertexes()
+        .map(|v| v.pos())
+    {
+        assert!(axis.edges.iter().any(|e| {
+            let last = e.points.last().unwrap();
+            last.pos.fuzzy_eq(corner) && last.radius == 0.0
+        }));
+    }
+}
+
+#[test]
+fn orientation_does_not_matter() {
+    let mut cw_rectangle = rectangle(0.0, 0.0, 10.0, 4.0);
+    cw_rectangle.invert_direction_mut();
+    let axis1 = pline_medial_axis(&rectangle(0.0, 0.0, 10.0, 4.0), &MedialAxisOptions::new());
+    let axis2 = pline_medial_axis(&cw_rectangle, &MedialAxisOptions::new());
+    assert_eq!(axis1.edges.len(), axis2.edges.len());
+    for (e1, e2) in axis1.edges.iter().zip(axis2.edges.iter()) {
+        assert_eq!(e1.points.len(), e2.points.len());
+    }
+}
+
+#[test]
+fn triangle_branches_meet_at_incenter() {
+    let triangle = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (3.0, 6.0, 0.0)];
+    let axis = pline_medial_axis(&triangle, &MedialAxisOptions::new());
+    assert_valid_medial_axis(&Shape::from_plines([triangle]), &axis);
+    assert_eq!(axis.edges.len(), 3);
+
+    let a = 85.0f64.sqrt();
+    let b = 45.0f64.sqrt();
+    let c = 10.0;
+    let perimeter = a + b + c;
+    let incenter = Vector2::new((b * 10.0 + c * 3.0) / perimeter, c * 6.0 / perimeter);
+    // inradius = area / semiperimeter
+    let inradius = 30.0 / (perimeter / 2.0);
+    for edge in axis.edges.iter() {
+        assert!(edge.points[0].pos.fuzzy_eq_eps(incenter, EPS));
+        assert!(edge.points[0].radius.fuzzy_eq_eps(inradius, EPS));
+    }
+}
+
+#[test]
+fn circle_medial_axis_is_center() {
+    let circle = pline_closed![(0.0, 0.0, 1.0), (10.0, 0.0, 1.0)];
+    let axis = pline_medial_axis(&circle, &MedialAxisOptions::new());
+    assert_eq!(axis.edges.len(), 1);
+    assert_eq!(axis.edges[0].points.len(), 1);
+    let p = axis.edges[0].points[0];
+    assert!(p.pos.fuzzy_eq_eps(Vector2::new(5.0, 0.0), EPS));
+    assert!(p.radius.fuzzy_eq_eps(5.0, EPS));
+}
+
+#[test]
+fn obround_medial_axis_between_arc_centers() {
+    let obround = pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 1.0),
+        (10.0, 4.0, 0.0),
+        (0.0, 4.0, 1.0)
+    ];
+    let axis = pline_medial_axis(&obround, &MedialAxisOptions::new());
+    assert_valid_medial_axis(&Shape::from_plines([obround]), &axis);
+    assert_eq!(axis.edges.len(), 1);
+    let edge = &axis.edges[0];
+    assert!(!edge.is_closed);
+    for p in edge.points.iter() {
+        assert!(p.pos.y.fuzzy_eq_eps(2.0, EPS));
+        assert!(p.radius.fuzzy_eq_eps(2.0, EPS));
+    }
+    let length = edge.to_pline().path_length();
+    assert!(length.fuzzy_eq_eps(10.0, EPS));
+}
+
+#[test]
+fn annulus_medial_axis_is_closed_loop() {
+    let shape = Shape::from_plines([
+        pline_closed![(-5.0, 0.0, 1.0), (5.0, 0.0, 1.0)],
+        pline_closed![(-2.0, 0.0, -1.0), (2.0, 0.0, -1.0)],
+    ]);
+    let axis = shape_medial_axis(&shape, &MedialAxisOptions::new());
+    assert_valid_medial_axis(&shape, &axis);
+    assert_eq!(axis.edges.len(), 1);
+    let edge = &axis.edges[0];
+    assert!(edge.is_closed);
+    for p in edge.points.iter() {
+        assert!(p.pos.length().fuzzy_eq_eps(3.5, EPS));
+        assert!(p.radius.fuzzy_eq_eps(1.5, EPS));
+    }
+    let pline = edge.to_pline();
+    assert!(pline.is_closed());
+    assert!(pline.area().abs() > 0.0);
+}
+
+#[test]
+fn shape_with_holes_and_arcs() {
+    let mut hole1 = pline_closed![(5.0, 5.0, 1.0), (9.0, 5.0, 1.0)];
+    hole1.invert_direction_mut();
+    let mut hole2 = pline_closed![
+        (12.0, 3.0, 0.0),
+        (17.0, 3.0, 0.5),
+        (17.0, 8.0, 0.0),
+        (12.0, 8.0, 0.0)
+    ];
+    hole2.invert_direction_mut();
+    let shape = Shape::from_plines([
+        pline_closed![
+            (0.0, 0.0, 0.0),
+            (20.0, 0.0, 0.0),
+            (20.0, 10.0, -0.3),
+            (10.0, 12.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ],
+        hole1,
+        hole2,
+    ]);
+    let axis = shape_medial_axis(&shape, &MedialAxisOptions::new());
+    assert_valid_medial_axis(&shape, &axis);
+}
+
+#[test]
+fn open_or_empty_returns_empty() {
+    let open = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0)];
+    assert!(
+        pline_medial_axis(&open, &MedialAxisOptions::new())
+            .edges
+            .is_empty()
+    );
+    assert!(
+        pline_medial_axis(&Polyline::<f64>::new_closed(), &MedialAxisOptions::new())
+            .edges
+            .is_empty()
+    );
+    assert!(
+        shape_medial_axis(&Shape::<f64>::empty(), &MedialAxisOptions::new())
+            .edges
+            .is_empty()
+    );
+}
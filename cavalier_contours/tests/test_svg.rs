@@ -0,0 +1,314 @@
+use cavalier_contours::{
+    core::{math::Vector2, traits::FuzzyEq},
+    pline_closed, pline_open,
+    polyline::{PlineSource, Polyline},
+    shape_algorithms::Shape,
+    svg::{
+        SvgFillRule, SvgParseOptions, SvgPathError, parse_path_data, parse_path_data_opt,
+        pline_to_path_data, plines_to_path_data, shape_to_path_data, shape_to_path_element,
+    },
+};
+use std::f64::consts::PI;
+
+fn assert_plines_eq(result: &[Polyline], expected: &[Polyline]) {
+    assert_eq!(
+        result.len(),
+        expected.len(),
+        "polyline count does not match"
+    );
+    for (r, e) in result.iter().zip(expected) {
+        assert!(r.fuzzy_eq(e), "polylines do not match\n{r:?}\n{e:?}");
+    }
+}
+
+/// Assert every point sampled along `curve` is within `tolerance` of `pline` and the polyline end
+/// points match the curve end points.
+fn assert_curve_approximated<F>(pline: &Polyline, curve: F, tolerance: f64)
+where
+    F: Fn(f64) -> Vector2<f64>,
+{
+    assert!(pline.at(0).pos().fuzzy_eq(curve(0.0)));
+    assert!(pline.last().unwrap().pos().fuzzy_eq(curve(1.0)));
+    let sample_count = 1000;
+    for i in 0..=sample_count {
+        let pt = curve(i as f64 / sample_count as f64);
+        let closest = pline.closest_point(pt, 1e-5).unwrap();
+        assert!(
+            closest.distance <= tolerance + 1e-9,
+            "curve point {pt:?} is {} from approximation",
+            closest.distance
+        );
+    }
+}
+
+mod test_export {
+    use super::*;
+
+    #[test]
+    fn open_pline() {
+        let pline = pline_open![(0.0, 0.0, 0.0), (10.0, 0.0, -1.0), (20.0, 0.0, 0.0)];
+        assert_eq!(pline_to_path_data(&pline), "M 0 0 L 10 0 A 5 5 0 0 0 20 0");
+    }
+
+    #[test]
+    fn closed_pline_with_closing_arc() {
+        let pline = pline_closed![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, -0.5)];
+        let path_data = pline_to_path_data(&pline);
+        assert!(path_data.starts_with("M 0 0 L 10 0 L 10 10 A "));
+        assert!(path_data.ends_with(" 0 0 0 0 Z"));
+    }
+
+    #[test]
+    fn large_arc_flag() {
+        // 3/4 circle arc has bulge greater than 1
+        let bulge = (3.0 * PI / 2.0 / 4.0).tan();
+        let pline = pline_open![(0.0, 0.0, bulge), (1.0, 1.0, 0.0)];
+        let path_data = pline_to_path_data(&pline);
+        assert!(path_data.contains(" 0 1 1 1 1"), "{path_data}");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(pline_to_path_data(&Polyline::<f64>::new()), "");
+        let plines = vec![
+            Polyline::new(),
+            pline_open![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)],
+            pline_open![(2.0, 0.0, 0.0), (3.0, 0.0, 0.0)],
+        ];
+        assert_eq!(plines_to_path_data(&plines), "M 0 0 L 1 0 M 2 0 L 3 0");
+    }
+
+    #[test]
+    fn shape_with_hole() {
+        let outer = pline_closed![
+            (0.0, 0.0, 0.0),
+            (10.0, 0.0, 0.0),
+            (10.0, 10.0, 0.0),
+            (0.0, 10.0, 0.0)
+        ];
+        let hole = pline_closed![(5.0, 2.0, -1.0), (5.0, 8.0, -1.0)];
+        let shape = Shape::from_plines(vec![outer, hole]);
+        let path_data = shape_to_path_data(&shape);
+        assert_eq!(
+            path_data,
+            "M 0 0 L 10 0 L 10 10 L 0 10 Z M 5 2 A 3 3 0 0 0 5 8 A 3 3 0 0 0 5 2 Z"
+        );
+        assert_eq!(
+            shape_to_path_element(&shape, SvgFillRule::NonZero),
+            format!("<path d=\"{path_data}\" fill-rule=\"nonzero\"/>")
+        );
+
+        // round trip keeps the hole
+        let plines: Vec<Polyline> = parse_path_data(&path_data).unwrap();
+        let round_trip = Shape::from_plines(plines);
+        assert_eq!(round_trip.ccw_plines.len(), 1);
+        assert_eq!(round_trip.cw_plines.len(), 1);
+    }
+
+    #[test]
+    fn round_trip() {
+        let plines = vec![
+            pline_closed![
+                (0.0, 0.0, 0.0),
+                (10.0, 0.0, 0.5),
+                (10.0, 10.0, -0.25),
+                (0.0, 10.0, 0.0)
+            ],
+            pline_open![
+                (1.5, -2.25, 1.0),
+                (3.0, 4.0, 0.0),
+                (7.125, 1e-7, -0.75),
+                (8.0, 8.0, 0.0)
+            ],
+            pline_closed![(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)],
+            pline_closed![(0.0, 0.0, -0.3), (2.0, 0.0, 0.0), (1.0, 1.0, 0.7)],
+        ];
+
+        let result: Vec<Polyline> = parse_path_data(&plines_to_path_data(&plines)).unwrap();
+        assert_plines_eq(&result, &plines);
+    }
+}
+
+mod test_parse {
+    use super::*;
+
+    #[test]
+    fn lines_absolute_and_relative() {
+        let result: Vec<Polyline> = parse_path_data("M 1 1 H 5 v 4 l -2 1 L 1 5 V 2 h 1").unwrap();
+        assert_plines_eq(
+            &result,
+            &[pline_open![
+                (1.0, 1.0, 0.0),
+                (5.0, 1.0, 0.0),
+                (5.0, 5.0, 0.0),
+                (3.0, 6.0, 0.0),
+                (1.0, 5.0, 0.0),
+                (1.0, 2.0, 0.0),
+                (2.0, 2.0, 0.0)
+            ]],
+        );
+    }
+
+    #[test]
+    fn implicit_line_to_and_compact_numbers() {
+        let result: Vec<Polyline> = parse_path_data("m0,0 10-5.5.5.5 1e1,0 z").unwrap();
+        assert_plines_eq(
+            &result,
+            &[pline_closed![
+                (0.0, 0.0, 0.0),
+                (10.0, -5.5, 0.0),
+                (10.5, -5.0, 0.0),
+                (20.5, -5.0, 0.0)
+            ]],
+        );
+    }
+
+    #[test]
+    fn multiple_subpaths() {
+        // drawing after close path starts a new subpath at the previous subpath start
+        let result: Vec<Polyline> =
+            parse_path_data("M 0 0 L 1 0 L 1 1 L 0 0 Z L 0 -1 M 5 5 L 6 5 M 9 9").unwrap();
+        assert_plines_eq(
+            &result,
+            &[
+                pline_closed![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)],
+                pline_open![(0.0, 0.0, 0.0), (0.0, -1.0, 0.0)],
+                pline_open![(5.0, 5.0, 0.0), (6.0, 5.0, 0.0)],
+            ],
+        );
+    }
+
+    #[test]
+    fn circular_arcs() {
+        // packed arc flags, radius too small (scaled up to half circle), and large arc
+        let result: Vec<Polyline> =
+            parse_path_data("M 0 0 a5 5 0 016 8 A 1 1 0 0 0 20 8 A 5 5 0 1 1 20 0").unwrap();
+        assert_eq!(result.len(), 1);
+        let pline = &result[0];
+        assert_eq!(pline.vertex_count(), 5);
+        assert!(pline[0].bulge.fuzzy_eq(1.0));
+        assert!(pline[1].bulge.fuzzy_eq(-1.0));
+        // large arc is split in two since arc segments sweep at most a half circle
+        assert!(pline[2].pos().fuzzy_eq(Vector2::new(20.0, 8.0)));
+        assert!(pline[2].bulge > 0.0 && pline[2].bulge < 1.0);
+        assert!(pline[3].bulge.fuzzy_eq(pline[2].bulge));
+        assert!(pline[4].pos().fuzzy_eq(Vector2::new(20.0, 0.0)));
+        // first two arcs are half circles with radius 5 and 7
+        let large_arc_length = pline.path_length() - 5.0 * PI - 7.0 * PI;
+        // chord of length 8 with radius 5, small arc sweeps 2 * asin(4 / 5)
+        assert!(large_arc_length.fuzzy_eq(5.0 * (2.0 * PI - 2.0 * (0.8f64).asin())));
+    }
+
+    #[test]
+    fn cubic_curve() {
+        let p0 = Vector2::new(0.0, 0.0);
+        let c1 = Vector2::new(2.0, 8.0);
+        let c2 = Vector2::new(10.0, -6.0);
+        let p1 = Vector2::new(12.0, 3.0);
+        let curve = |t: f64| {
+            let mt = 1.0 - t;
+            p0.scale(mt * mt * mt)
+                + c1.scale(3.0 * mt * mt * t)
+                + c2.scale(3.0 * mt * t * t)
+                + p1.scale(t * t * t)
+        };
+
+        for tolerance in [1e-1, 1e-2, 1e-4] {
+            let options = SvgParseOptions {
+                curve_tolerance: tolerance,
+                ..Default::default()
+            };
+            let result: Vec<Polyline> =
+                parse_path_data_opt("M 0 0 C 2 8 10 -6 12 3", &options).unwrap();
+            assert_eq!(result.len(), 1);
+            assert_curve_approximated(&result[0], curve, tolerance);
+        }
+    }
+
+    #[test]
+    fn smooth_cubic_reflects_control_point() {
+        let result: Vec<Polyline> = parse_path_data("M 0 0 C 0 5 5 5 5 0 S 10 -5 10 0").unwrap();
+        let explicit: Vec<Polyline> =
+            parse_path_data("M 0 0 C 0 5 5 5 5 0 C 5 -5 10 -5 10 0").unwrap();
+        assert_plines_eq(&result, &explicit);
+    }
+
+    #[test]
+    fn quadratic_curve() {
+        let curve = |t: f64| {
+            let mt = 1.0 - t;
+            Vector2::new(0.0, 0.0).scale(mt * mt)
+                + Vector2::new(5.0, 10.0).scale(2.0 * mt * t)
+                + Vector2::new(10.0, 0.0).scale(t * t)
+        };
+        let result: Vec<Polyline> = parse_path_data("M 0 0 q 5 10 10 0").unwrap();
+        assert_eq!(result.len(), 1);
+        assert_curve_approximated(&result[0], curve, 1e-3);
+
+        // smooth quadratic reflects the previous control point
+        let result: Vec<Polyline> = parse_path_data("M 0 0 Q 5 10 10 0 T 20 0").unwrap();
+        let explicit: Vec<Polyline> = parse_path_data("M 0 0 Q 5 10 10 0 Q 15 -10 20 0").unwrap();
+        assert_plines_eq(&result, &explicit);
+    }
+
+    #[test]
+    fn elliptical_arc() {
+        // half ellipse rotated 30 degrees
+        let angle = PI / 6.0;
+        let (s, c) = angle.sin_cos();
+        let start = Vector2::new(10.0 * c, 10.0 * s);
+        let end = Vector2::new(-start.x, -start.y);
+        let curve = |t: f64| {
+            let (ps, pc) = (t * PI).sin_cos();
+            let x = 10.0 * pc;
+            let y = 4.0 * ps;
+            Vector2::new(c * x - s * y, s * x + c * y)
+        };
+
+        let data = format!(
+            "M {} {} A 10 4 30 0 1 {} {}",
+            start.x, start.y, end.x, end.y
+        );
+        let result: Vec<Polyline> = parse_path_data(&data).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_curve_approximated(&result[0], curve, 1e-3);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse_path_data::<f64>("L 1 1").unwrap_err(),
+            SvgPathError::MissingMoveTo
+        );
+        assert_eq!(
+            parse_path_data::<f64>("M 0 0 X 1 1").unwrap_err(),
+            SvgPathError::InvalidCommand {
+                position: 6,
+                command: 'X'
+            }
+        );
+        assert_eq!(
+            parse_path_data::<f64>("M 0 0 L 1").unwrap_err(),
+            SvgPathError::ExpectedNumber { position: 9 }
+        );
+        assert_eq!(
+            parse_path_data::<f64>("M 0 0 A 1 1 0 2 0 1 1").unwrap_err(),
+            SvgPathError::ExpectedFlag { position: 14 }
+        );
+        assert_eq!(
+            parse_path_data::<f64>("M 0 0 L 1 1 Z 2 2").unwrap_err(),
+            SvgPathError::InvalidCommand {
+                position: 14,
+                command: '2'
+            }
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let result: Vec<Polyline> = parse_path_data("").unwrap();
+        assert!(result.is_empty());
+        let result: Vec<Polyline> = parse_path_data("  M 1 1 ").unwrap();
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+mod test_utils;
+
+use cavalier_contours::{
+    assert_fuzzy_eq,
+    polyline::{PlineSource, PlineSourceMut, Polyline},
+    shape_algorithms::Shape,
+    toolpath::{PocketLinkOptions, PocketLinkOrder, PocketOptions, PocketTree, pocket_offsets},
+};
+use test_utils::{corridor_dumbbell, rectangle};
+
+/// Assert tree parent/child links are consistent and every loop is at the expected offset.
+fn assert_tree_valid(tree: &PocketTree<f64>, start_offset: f64, stepover: f64) {
+    for (i, l) in tree.loops.iter().enumerate() {
+        assert!(l.pline.is_closed());
+        assert_fuzzy_eq!(l.offset, start_offset + stepover * l.depth as f64);
+        match l.parent {
+            Some(p) => {
+                assert_eq!(tree.loops[p].depth + 1, l.depth);
+                assert!(tree.loops[p].children.contains(&i));
+            }
+            None => assert!(l.depth == 0 || start_offset > 0.0),
+        }
+        for &c in &l.children {
+            assert_eq!(tree.loops[c].parent, Some(i));
+        }
+    }
+}
+
+/// Assert `path` traverses every loop of `loops` fully, joined by line segments between them.
+fn assert_path_covers(path: &Polyline, tree: &PocketTree<f64>, loops: &[usize]) {
+    assert!(!path.is_closed());
+    let loop_length: f64 = loops
+        .iter()
+        .map(|&i| tree.loops[i].pline.path_length())
+        .sum();
+    assert!(path.path_length() >= loop_length - 1e-5);
+    for &i in loops {
+        let pline = &tree.loops[i].pline;
+        for v in pline.iter_vertexes() {
+            assert!(path.closest_point(v.pos(), 1e-5).unwrap().distance < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn rectangle_single_chain() {
+    let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 6.0)]);
+    let tree = pocket_offsets(&shape, 1.0, &PocketOptions::new());
+    assert_eq!(tree.loops.len(), 3);
+    assert_tree_valid(&tree, 0.0, 1.0);
+    assert_eq!(tree.roots().collect::<Vec<_>>(), [0]);
+    assert_eq!(tree.loops[1].parent, Some(0));
+    assert_eq!(tree.loops[2].parent, Some(1));
+    assert_fuzzy_eq!(tree.loops[2].pline.area(), 12.0);
+
+    let paths = tree.link_paths(PocketLinkOrder::OutsideIn);
+    assert_eq!(paths.len(), 1);
+    assert_path_covers(&paths[0], &tree, &[0, 1, 2]);
+    // starts on the boundary and ends on the innermost loop
+    assert_eq!(paths[0][0].pos(), tree.loops[0].pline[0].pos());
+    let end = paths[0].last().unwrap().pos();
+    assert!(
+        tree.loops[2]
+            .pline
+            .closest_point(end, 1e-5)
+            .unwrap()
+            .distance
+            < 1e-5
+    );
+    // each link is the shortest distance to the next loop (from corner to corner)
+    let loop_length: f64 = tree.loops.iter().map(|l| l.pline.path_length()).sum();
+    assert_fuzzy_eq!(paths[0].path_length(), loop_length + 2.0 * 2.0f64.sqrt());
+}
+
+#[test]
+fn start_offset_and_max_steps() {
+    let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 6.0)]);
+    let mut options = PocketOptions::new();
+    options.start_offset = 0.5;
+    let tree = pocket_offsets(&shape, 1.0, &options);
+    assert_eq!(tree.loops.len(), 3);
+    assert_tree_valid(&tree, 0.5, 1.0);
+    assert_fuzzy_eq!(tree.loops[0].pline.area(), 9.0 * 5.0);
+
+    options.max_steps = Some(2);
+    let tree = pocket_offsets(&shape, 1.0, &options);
+    assert_eq!(tree.loops.len(), 2);
+
+    options.max_steps = Some(0);
+    assert!(pocket_offsets(&shape, 1.0, &options).is_empty());
+}
+
+#[test]
+fn split_into_children() {
+    let shape = Shape::from_plines([corridor_dumbbell()]);
+    let tree = pocket_offsets(&shape, 1.5, &PocketOptions::new());
+    assert_tree_valid(&tree, 0.0, 1.5);
+    // corridor collapses after the first step leaving two squares (with rounded inner corners)
+    let children = &tree.loops[0].children;
+    assert_eq!(children.len(), 2);
+    let area = tree.loops[children[0]].pline.area();
+    assert!(area > 49.0 && area < 50.0);
+    assert_fuzzy_eq!(tree.loops[children[1]].pline.area(), area);
+
+    let paths = tree.link_paths(PocketLinkOrder::OutsideIn);
+    assert_eq!(paths.len(), 2);
+    let total_loops: usize = tree.loops.len();
+    let mut covered = Vec::new();
+    for path in &paths {
+        for (i, l) in tree.loops.iter().enumerate() {
+            if path.closest_point(l.pline[0].pos(), 1e-5).unwrap().distance < 1e-5 {
+                covered.push(i);
+            }
+        }
+    }
+    covered.sort_unstable();
+    covered.dedup();
+    assert_eq!(covered.len(), total_loops);
+}
+
+#[test]
+fn island() {
+    let mut hole = rectangle(8.0, 8.0, 4.0, 4.0);
+    hole.invert_direction_mut();
+    let shape = Shape::from_plines([rectangle(0.0, 0.0, 20.0, 20.0), hole]);
+    let tree = pocket_offsets(&shape, 1.5, &PocketOptions::new());
+    assert_tree_valid(&tree, 0.0, 1.5);
+    // offsets 0, 1.5 and 3 have a boundary loop and an island loop, at 4.5 the offset island
+    // meets the boundary leaving only the 4 corners
+    assert_eq!(tree.loops.len(), 10);
+    for l in tree.loops.iter().filter(|l| l.depth > 0) {
+        let parent = &tree.loops[l.parent.unwrap()];
+        // island loops are parented to island loops
+        assert_eq!(l.pline.area() > 0.0, parent.pline.area() > 0.0);
+    }
+    let corners: Vec<_> = tree.loops.iter().filter(|l| l.depth == 3).collect();
+    assert_eq!(corners.len(), 4);
+    assert!(corners.iter().all(|l| l.pline.area() > 0.0));
+    let corner_parent = corners[0].parent.unwrap();
+    assert!(corners.iter().all(|l| l.parent == Some(corner_parent)));
+    assert_eq!(tree.loops[corner_parent].children.len(), 4);
+
+    // boundary chain, 3 other corners and island chain
+    let paths = tree.link_paths(PocketLinkOrder::OutsideIn);
+    assert_eq!(paths.len(), 5);
+}
+
+#[test]
+fn inside_out_order() {
+    let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 6.0)]);
+    let tree = pocket_offsets(&shape, 1.0, &PocketOptions::new());
+    let inside_out = tree.link_paths(PocketLinkOrder::InsideOut);
+    assert_eq!(inside_out.len(), 1);
+    assert_path_covers(&inside_out[0], &tree, &[2, 1, 0]);
+    // starts on the innermost loop and ends on the boundary
+    assert_eq!(inside_out[0][0].pos(), tree.loops[2].pline[0].pos());
+    let end = inside_out[0].last().unwrap().pos();
+    assert!(
+        tree.loops[0]
+            .pline
+            .closest_point(end, 1e-5)
+            .unwrap()
+            .distance
+            < 1e-5
+    );
+
+    let options = PocketLinkOptions {
+        pos_equal_eps: 1e-8,
+    };
+    let with_options = tree.link_paths_opt(PocketLinkOrder::InsideOut, &options);
+    assert_eq!(with_options.len(), 1);
+    assert!(with_options[0].fuzzy_eq(&inside_out[0]));
+}
+
+#[test]
+fn invalid_inputs() {
+    let shape = Shape::from_plines([rectangle(0.0, 0.0, 10.0, 6.0)]);
+    assert!(pocket_offsets(&shape, 0.0, &PocketOptions::new()).is_empty());
+    assert!(pocket_offsets(&shape, -1.0, &PocketOptions::new()).is_empty());
+    let empty = pocket_offsets(&Shape::<f64>::empty(), 1.0, &PocketOptions::new());
+    assert!(empty.is_empty());
+    assert!(empty.link_paths(PocketLinkOrder::OutsideIn).is_empty());
+}
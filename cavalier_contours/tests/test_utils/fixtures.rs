@@ -0,0 +1,83 @@
+use cavalier_contours::{
+    pline_closed,
+    polyline::{PlineSourceMut, Polyline},
+};
+use std::f64::consts::PI;
+
+/// Counter clockwise axis aligned rectangle with lower left corner at (`min_x`, `min_y`).
+pub fn rectangle(min_x: f64, min_y: f64, width: f64, height: f64) -> Polyline {
+    pline_closed![
+        (min_x, min_y, 0.0),
+        (min_x + width, min_y, 0.0),
+        (min_x + width, min_y + height, 0.0),
+        (min_x, min_y + height, 0.0)
+    ]
+}
+
+/// Counter clockwise axis aligned square with lower left corner at (`min_x`, `min_y`).
+pub fn square(min_x: f64, min_y: f64, size: f64) -> Polyline {
+    rectangle(min_x, min_y, size, size)
+}
+
+/// Same as [square] but clockwise (e.g. for a hole in a shape).
+pub fn square_hole(min_x: f64, min_y: f64, size: f64) -> Polyline {
+    pline_closed![
+        (min_x, min_y, 0.0),
+        (min_x, min_y + size, 0.0),
+        (min_x + size, min_y + size, 0.0),
+        (min_x + size, min_y, 0.0)
+    ]
+}
+
+/// Two 10x10 squares joined by a 2 wide corridor.
+pub fn corridor_dumbbell() -> Polyline {
+    pline_closed![
+        (0.0, 0.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (10.0, 4.0, 0.0),
+        (20.0, 4.0, 0.0),
+        (20.0, 0.0, 0.0),
+        (30.0, 0.0, 0.0),
+        (30.0, 10.0, 0.0),
+        (20.0, 10.0, 0.0),
+        (20.0, 6.0, 0.0),
+        (10.0, 6.0, 0.0),
+        (10.0, 10.0, 0.0),
+        (0.0, 10.0, 0.0)
+    ]
+}
+
+/// Two lobes joined by a neck that narrows to 1 between two arcs facing each other.
+pub fn arc_neck_dumbbell() -> Polyline {
+    // neck arcs centered at (5, -1) and (5, 2) with radius 1
+    pline_closed![
+        (2.0, -3.0, 0.0),
+        (4.0, -1.0, -1.0),
+        (6.0, -1.0, 0.0),
+        (8.0, -3.0, 0.0),
+        (8.0, 5.0, 0.0),
+        (6.0, 2.0, -1.0),
+        (4.0, 2.0, 0.0),
+        (2.0, 5.0, 0.0)
+    ]
+}
+
+/// Same as [arc_neck_dumbbell] but with the neck arcs approximated by `count` line segments each.
+pub fn polygon_neck_dumbbell(count: usize) -> Polyline {
+    let mut pline = Polyline::new_closed();
+    pline.add(2.0, -3.0, 0.0);
+    for i in 0..=count {
+        // upper half of circle at (5, -1) going clockwise
+        let t = PI - PI * i as f64 / count as f64;
+        pline.add(5.0 + t.cos(), -1.0 + t.sin(), 0.0);
+    }
+    pline.add(8.0, -3.0, 0.0);
+    pline.add(8.0, 5.0, 0.0);
+    for i in 0..=count {
+        // lower half of circle at (5, 2) going clockwise
+        let t = -PI * i as f64 / count as f64;
+        pline.add(5.0 + t.cos(), 2.0 + t.sin(), 0.0);
+    }
+    pline.add(2.0, 5.0, 0.0);
+    pline
+}
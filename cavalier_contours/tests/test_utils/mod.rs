@@ -1,8 +1,10 @@
 #![allow(unused)]
 mod debug;
+mod fixtures;
 mod pline_modifiers;
 mod pline_test_properties;
 
 pub use debug::*;
+pub use fixtures::*;
 pub use pline_modifiers::*;
 pub use pline_test_properties::*;
@@ -3,6 +3,15 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * FFI representation of JoinStyle enum
+ */
+#define CAVC_JOIN_STYLE_ROUND 0
+
+#define CAVC_JOIN_STYLE_MITER 1
+
+#define CAVC_JOIN_STYLE_BEVEL 2
+
 /**
  * FFI representation of SelfIntersectsInclude enum
  */
@@ -59,6 +68,9 @@ typedef struct cavc_shape cavc_shape;
 
 /**
  * FFI representation of [PlineOffsetOptions].
+ *
+ * `join_style` must be one of the `CAVC_JOIN_STYLE_*` values, `miter_limit` is only used when
+ * `join_style` is `CAVC_JOIN_STYLE_MITER`.
  */
 typedef struct cavc_pline_parallel_offset_o {
   const struct cavc_aabbindex *aabb_index;
@@ -66,6 +78,8 @@ typedef struct cavc_pline_parallel_offset_o {
   double slice_join_eps;
   double offset_dist_eps;
   uint8_t handle_self_intersects;
+  uint8_t join_style;
+  double miter_limit;
 } cavc_pline_parallel_offset_o;
 
 /**
@@ -104,11 +118,16 @@ typedef struct cavc_vertex {
 
 /**
  * FFI representation of [ShapeOffsetOptions].
+ *
+ * `join_style` must be one of the `CAVC_JOIN_STYLE_*` values, `miter_limit` is only used when
+ * `join_style` is `CAVC_JOIN_STYLE_MITER`.
  */
 typedef struct cavc_shape_offset_o {
   double pos_equal_eps;
   double offset_dist_eps;
   double slice_join_eps;
+  uint8_t join_style;
+  double miter_limit;
 } cavc_shape_offset_o;
 
 /**
@@ -631,6 +650,57 @@ int32_t cavc_pline_scale(struct cavc_pline *pline, double scale_factor);
  */
 int32_t cavc_pline_translate(struct cavc_pline *pline, double x_offset, double y_offset);
 
+/**
+ * Wraps [PlineSourceMut::rotate_mut].
+ *
+ * ## Specific Error Codes
+ * * 1 = `pline` is null.
+ *
+ * # Safety
+ *
+ * `pline` must be null or a valid cavc_pline object that was created with [cavc_pline_create] and
+ * has not been freed.
+ */
+int32_t cavc_pline_rotate(struct cavc_pline *pline, double angle, double origin_x, double origin_y);
+
+/**
+ * Wraps [PlineSourceMut::mirror_mut].
+ *
+ * ## Specific Error Codes
+ * * 1 = `pline` is null.
+ *
+ * # Safety
+ *
+ * `pline` must be null or a valid cavc_pline object that was created with [cavc_pline_create] and
+ * has not been freed.
+ */
+int32_t cavc_pline_mirror(struct cavc_pline *pline,
+                          double point_x,
+                          double point_y,
+                          double direction_x,
+                          double direction_y);
+
+/**
+ * Wraps [PlineSourceMut::transform_mut], a point `(x, y)` is transformed to
+ * `(m11 * x + m12 * y + tx, m21 * x + m22 * y + ty)`.
+ *
+ * ## Specific Error Codes
+ * * 1 = `pline` is null.
+ * * 2 = transform does not preserve circles and `pline` has arc segments (`pline` is unchanged).
+ *
+ * # Safety
+ *
+ * `pline` must be null or a valid cavc_pline object that was created with [cavc_pline_create] and
+ * has not been freed.
+ */
+int32_t cavc_pline_transform(struct cavc_pline *pline,
+                             double m11,
+                             double m12,
+                             double m21,
+                             double m22,
+                             double tx,
+                             double ty);
+
 /**
  * Wraps [PlineSource::remove_repeat_pos] but modifies in place rather than returning a result.
  *
@@ -683,6 +753,7 @@ int32_t cavc_pline_eval_extents(const struct cavc_pline *pline,
  *
  * ## Specific Error Codes
  * * 1 = `pline` is null.
+ * * 2 = `options` has an unrecognized `join_style` value.
  *
  * # Safety
  *
@@ -994,6 +1065,7 @@ void cavc_shape_f(struct cavc_shape *shape);
  *
  * ## Specific Error Codes
  * * 1 = `shape` is null.
+ * * 2 = `options` has an unrecognized `join_style` value.
  *
  * # Safety
  *
@@ -1,9 +1,9 @@
 //! This module contains the C foreign function interface for cavalier_contours.
 #![allow(non_camel_case_types)]
 use cavalier_contours::{
-    core::math::Vector2,
+    core::math::{Affine2, Vector2},
     polyline::{
-        BooleanOp, PlineBooleanOptions, PlineContainsOptions, PlineOffsetOptions,
+        BooleanOp, JoinStyle, PlineBooleanOptions, PlineContainsOptions, PlineOffsetOptions,
         PlineSelfIntersectOptions, PlineSource, PlineSourceMut, PlineVertex, Polyline,
         SelfIntersectsInclude,
     },
@@ -75,7 +75,35 @@ impl cavc_vertex {
 #[derive(Debug, Clone)]
 pub struct cavc_pline(pub Polyline<f64>);
 
+/// FFI representation of JoinStyle enum
+pub const CAVC_JOIN_STYLE_ROUND: u8 = 0;
+pub const CAVC_JOIN_STYLE_MITER: u8 = 1;
+pub const CAVC_JOIN_STYLE_BEVEL: u8 = 2;
+
+/// Default miter limit used for FFI option types.
+const DEFAULT_MITER_LIMIT: f64 = 4.0;
+
+fn join_style_to_internal(join_style: u8, miter_limit: f64) -> Option<JoinStyle<f64>> {
+    match join_style {
+        CAVC_JOIN_STYLE_ROUND => Some(JoinStyle::Round),
+        CAVC_JOIN_STYLE_MITER => Some(JoinStyle::Miter { limit: miter_limit }),
+        CAVC_JOIN_STYLE_BEVEL => Some(JoinStyle::Bevel),
+        _ => None,
+    }
+}
+
+fn join_style_from_internal(join_style: JoinStyle<f64>) -> (u8, f64) {
+    match join_style {
+        JoinStyle::Round => (CAVC_JOIN_STYLE_ROUND, DEFAULT_MITER_LIMIT),
+        JoinStyle::Miter { limit } => (CAVC_JOIN_STYLE_MITER, limit),
+        JoinStyle::Bevel => (CAVC_JOIN_STYLE_BEVEL, DEFAULT_MITER_LIMIT),
+    }
+}
+
 /// FFI representation of [PlineOffsetOptions].
+///
+/// `join_style` must be one of the `CAVC_JOIN_STYLE_*` values, `miter_limit` is only used when
+/// `join_style` is `CAVC_JOIN_STYLE_MITER`.
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct cavc_pline_parallel_offset_o {
@@ -84,35 +112,45 @@ pub struct cavc_pline_parallel_offset_o {
     pub slice_join_eps: f64,
     pub offset_dist_eps: f64,
     pub handle_self_intersects: u8,
+    pub join_style: u8,
+    pub miter_limit: f64,
 }
 
 impl cavc_pline_parallel_offset_o {
     /// Convert FFI parallel offset options type to internal type.
     ///
+    /// Returns `None` if `join_style` is unrecognized.
+    ///
     /// # Safety
     ///
     /// `aabb_index` field must be null or a valid pointer to a [cavc_aabbindex].
-    pub unsafe fn to_internal(&self) -> PlineOffsetOptions<f64> {
+    pub unsafe fn to_internal(&self) -> Option<PlineOffsetOptions<'_, f64>> {
         let aabb_index = unsafe { self.aabb_index.as_ref().map(|w| &w.0) };
-        PlineOffsetOptions {
+        let join_style = join_style_to_internal(self.join_style, self.miter_limit)?;
+        Some(PlineOffsetOptions {
             aabb_index,
             pos_equal_eps: self.pos_equal_eps,
             slice_join_eps: self.slice_join_eps,
             offset_dist_eps: self.offset_dist_eps,
             handle_self_intersects: self.handle_self_intersects != 0,
-        }
+            join_style,
+            context: None,
+        })
     }
 }
 
 impl Default for cavc_pline_parallel_offset_o {
     fn default() -> Self {
         let d = PlineOffsetOptions::default();
+        let (join_style, miter_limit) = join_style_from_internal(d.join_style);
         Self {
             aabb_index: std::ptr::null(),
             pos_equal_eps: d.pos_equal_eps,
             slice_join_eps: d.slice_join_eps,
             offset_dist_eps: d.offset_dist_eps,
             handle_self_intersects: d.handle_self_intersects as u8,
+            join_style,
+            miter_limit,
         }
     }
 }
@@ -197,11 +235,12 @@ impl cavc_pline_boolean_o {
     /// # Safety
     ///
     /// `pline1_aabb_index` field must be null or a valid pointer to a [cavc_aabbindex].
-    pub unsafe fn to_internal(&self) -> PlineBooleanOptions<f64> {
+    pub unsafe fn to_internal(&self) -> PlineBooleanOptions<'_, f64> {
         let pline1_aabb_index = unsafe { self.pline1_aabb_index.as_ref().map(|w| &w.0) };
         PlineBooleanOptions {
             pline1_aabb_index,
             pos_equal_eps: self.pos_equal_eps,
+            context: None,
         }
     }
 }
@@ -312,7 +351,7 @@ impl cavc_pline_self_intersect_o {
     /// # Safety
     ///
     /// `pline_aabb_index` field must be null or a valid pointer to a [cavc_aabbindex].
-    pub unsafe fn to_internal(&self) -> Option<PlineSelfIntersectOptions<f64>> {
+    pub unsafe fn to_internal(&self) -> Option<PlineSelfIntersectOptions<'_, f64>> {
         let pline_aabb_index = unsafe { self.pline_aabb_index.as_ref().map(|w| &w.0) };
         let include_value = match self.include {
             0 => SelfIntersectsInclude::All,
@@ -420,7 +459,7 @@ impl cavc_pline_contains_o {
     /// # Safety
     ///
     /// `pline1_aabb_index` field must be null or a valid pointer to a [cavc_aabbindex].
-    pub unsafe fn to_internal(&self) -> PlineContainsOptions<f64> {
+    pub unsafe fn to_internal(&self) -> PlineContainsOptions<'_, f64> {
         let pline1_aabb_index = unsafe { self.pline1_aabb_index.as_ref().map(|w| &w.0) };
         PlineContainsOptions {
             pline1_aabb_index,
@@ -1230,6 +1269,102 @@ pub unsafe extern "C" fn cavc_pline_translate(
     })
 }
 
+/// Wraps [PlineSourceMut::rotate_mut].
+///
+/// ## Specific Error Codes
+/// * 1 = `pline` is null.
+///
+/// # Safety
+///
+/// `pline` must be null or a valid cavc_pline object that was created with [cavc_pline_create] and
+/// has not been freed.
+#[unsafe(no_mangle)]
+#[must_use]
+pub unsafe extern "C" fn cavc_pline_rotate(
+    pline: *mut cavc_pline,
+    angle: f64,
+    origin_x: f64,
+    origin_y: f64,
+) -> i32 {
+    ffi_catch_unwind!({
+        if pline.is_null() {
+            return 1;
+        }
+        unsafe {
+            (*pline)
+                .0
+                .rotate_mut(angle, Vector2::new(origin_x, origin_y));
+        }
+        0
+    })
+}
+
+/// Wraps [PlineSourceMut::mirror_mut].
+///
+/// ## Specific Error Codes
+/// * 1 = `pline` is null.
+///
+/// # Safety
+///
+/// `pline` must be null or a valid cavc_pline object that was created with [cavc_pline_create] and
+/// has not been freed.
+#[unsafe(no_mangle)]
+#[must_use]
+pub unsafe extern "C" fn cavc_pline_mirror(
+    pline: *mut cavc_pline,
+    point_x: f64,
+    point_y: f64,
+    direction_x: f64,
+    direction_y: f64,
+) -> i32 {
+    ffi_catch_unwind!({
+        if pline.is_null() {
+            return 1;
+        }
+        unsafe {
+            (*pline).0.mirror_mut(
+                Vector2::new(point_x, point_y),
+                Vector2::new(direction_x, direction_y),
+            );
+        }
+        0
+    })
+}
+
+/// Wraps [PlineSourceMut::transform_mut], a point `(x, y)` is transformed to
+/// `(m11 * x + m12 * y + tx, m21 * x + m22 * y + ty)`.
+///
+/// ## Specific Error Codes
+/// * 1 = `pline` is null.
+/// * 2 = transform does not preserve circles and `pline` has arc segments (`pline` is unchanged).
+///
+/// # Safety
+///
+/// `pline` must be null or a valid cavc_pline object that was created with [cavc_pline_create] and
+/// has not been freed.
+#[unsafe(no_mangle)]
+#[must_use]
+pub unsafe extern "C" fn cavc_pline_transform(
+    pline: *mut cavc_pline,
+    m11: f64,
+    m12: f64,
+    m21: f64,
+    m22: f64,
+    tx: f64,
+    ty: f64,
+) -> i32 {
+    ffi_catch_unwind!({
+        if pline.is_null() {
+            return 1;
+        }
+        let transform = Affine2::new(m11, m12, m21, m22, tx, ty);
+        match unsafe { (*pline).0.transform_mut(&transform) } {
+            Ok(()) => 0,
+            Err(_) => 2,
+        }
+    })
+}
+
 /// Wraps [PlineSource::remove_repeat_pos] but modifies in place rather than returning a result.
 ///
 /// ## Specific Error Codes
@@ -1349,6 +1484,7 @@ pub unsafe extern "C" fn cavc_pline_eval_extents(
 ///
 /// ## Specific Error Codes
 /// * 1 = `pline` is null.
+/// * 2 = `options` has an unrecognized `join_style` value.
 ///
 /// # Safety
 ///
@@ -1373,8 +1509,10 @@ pub unsafe extern "C" fn cavc_pline_parallel_offset(
         let results = if options.is_null() {
             pline.parallel_offset(offset)
         } else {
-            let opts = unsafe { &(*options).to_internal() };
-            pline.parallel_offset_opt(offset, opts)
+            match unsafe { (*options).to_internal() } {
+                None => return 2, // invalid options were passed in.
+                Some(opts) => pline.parallel_offset_opt(offset, &opts),
+            }
         };
 
         unsafe {
@@ -1916,32 +2054,45 @@ pub unsafe extern "C" fn cavc_plinelist_take(
 }
 
 /// FFI representation of [ShapeOffsetOptions].
+///
+/// `join_style` must be one of the `CAVC_JOIN_STYLE_*` values, `miter_limit` is only used when
+/// `join_style` is `CAVC_JOIN_STYLE_MITER`.
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct cavc_shape_offset_o {
     pub pos_equal_eps: f64,
     pub offset_dist_eps: f64,
     pub slice_join_eps: f64,
+    pub join_style: u8,
+    pub miter_limit: f64,
 }
 
 impl cavc_shape_offset_o {
     /// Convert FFI shape offset options type to internal type.
-    pub fn to_internal(&self) -> ShapeOffsetOptions<f64> {
-        ShapeOffsetOptions {
+    ///
+    /// Returns `None` if `join_style` is unrecognized.
+    pub fn to_internal(&self) -> Option<ShapeOffsetOptions<f64>> {
+        let join_style = join_style_to_internal(self.join_style, self.miter_limit)?;
+        Some(ShapeOffsetOptions {
             pos_equal_eps: self.pos_equal_eps,
             offset_dist_eps: self.offset_dist_eps,
             slice_join_eps: self.slice_join_eps,
-        }
+            join_style,
+            loop_offsets: Vec::new(),
+        })
     }
 }
 
 impl Default for cavc_shape_offset_o {
     fn default() -> Self {
         let d = ShapeOffsetOptions::default();
+        let (join_style, miter_limit) = join_style_from_internal(d.join_style);
         Self {
             pos_equal_eps: d.pos_equal_eps,
             offset_dist_eps: d.offset_dist_eps,
             slice_join_eps: d.slice_join_eps,
+            join_style,
+            miter_limit,
         }
     }
 }
@@ -2035,6 +2186,7 @@ pub unsafe extern "C" fn cavc_shape_f(shape: *mut cavc_shape) {
 ///
 /// ## Specific Error Codes
 /// * 1 = `shape` is null.
+/// * 2 = `options` has an unrecognized `join_style` value.
 ///
 /// # Safety
 ///
@@ -2059,7 +2211,10 @@ pub unsafe extern "C" fn cavc_shape_parallel_offset(
                 let default_options = ShapeOffsetOptions::new();
                 (*shape).0.parallel_offset(offset, default_options)
             } else {
-                (*shape).0.parallel_offset(offset, (*options).to_internal())
+                match (*options).to_internal() {
+                    None => return 2, // invalid options were passed in.
+                    Some(opts) => (*shape).0.parallel_offset(offset, opts),
+                }
             };
 
             result.write(Box::into_raw(Box::new(cavc_shape(results))));
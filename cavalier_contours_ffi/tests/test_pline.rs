@@ -247,6 +247,66 @@ fn pline_translate() {
     }
 }
 
+#[test]
+fn pline_rotate() {
+    let pline = create_pline(&[(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)], true);
+    unsafe {
+        assert_eq!(cavc_pline_rotate(pline, std::f64::consts::PI, 1.0, 0.0), 0);
+        assert_eq!(
+            cavc_pline_rotate(ptr::null_mut(), std::f64::consts::PI, 1.0, 0.0),
+            1
+        );
+        let mut v = cavc_vertex::new(0.0, 0.0, 0.0);
+        assert_eq!(cavc_pline_get_vertex(pline, 0, &mut v), 0);
+        assert_fuzzy_eq!(v.x, 2.0);
+        assert_fuzzy_eq!(v.y, 0.0);
+        assert_eq!(v.bulge, 1.0);
+        assert_eq!(cavc_pline_get_vertex(pline, 1, &mut v), 0);
+        assert_fuzzy_eq!(v.x, 0.0);
+        assert_fuzzy_eq!(v.y, 0.0);
+        assert_eq!(v.bulge, 1.0);
+
+        cavc_pline_f(pline)
+    }
+}
+
+#[test]
+fn pline_mirror() {
+    let pline = create_pline(&[(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)], true);
+    unsafe {
+        assert_eq!(cavc_pline_mirror(pline, 0.0, 0.0, 0.0, 1.0), 0);
+        assert_eq!(cavc_pline_mirror(ptr::null_mut(), 0.0, 0.0, 0.0, 1.0), 1);
+        let mut v = cavc_vertex::new(0.0, 0.0, 0.0);
+        assert_eq!(cavc_pline_get_vertex(pline, 1, &mut v), 0);
+        assert_fuzzy_eq!(v.x, -2.0);
+        assert_fuzzy_eq!(v.y, 0.0);
+        assert_eq!(v.bulge, -1.0);
+
+        cavc_pline_f(pline)
+    }
+}
+
+#[test]
+fn pline_transform() {
+    let pline = create_pline(&[(0.0, 0.0, 1.0), (2.0, 0.0, 1.0)], true);
+    unsafe {
+        assert_eq!(cavc_pline_transform(pline, 2.0, 0.0, 0.0, 2.0, 1.0, 1.0), 0);
+        assert_eq!(
+            cavc_pline_transform(ptr::null_mut(), 2.0, 0.0, 0.0, 2.0, 1.0, 1.0),
+            1
+        );
+        // non-uniform scale with arcs
+        assert_eq!(cavc_pline_transform(pline, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0), 2);
+        let mut v = cavc_vertex::new(0.0, 0.0, 0.0);
+        assert_eq!(cavc_pline_get_vertex(pline, 1, &mut v), 0);
+        assert_fuzzy_eq!(v.x, 5.0);
+        assert_fuzzy_eq!(v.y, 1.0);
+        assert_eq!(v.bulge, 1.0);
+
+        cavc_pline_f(pline)
+    }
+}
+
 #[test]
 fn pline_remove_repeat_pos() {
     // no vertexes removed
@@ -423,6 +483,8 @@ fn pline_eval_parallel_offset() {
             slice_join_eps: f64::NAN,
             offset_dist_eps: f64::NAN,
             handle_self_intersects: 0,
+            join_style: u8::MAX,
+            miter_limit: f64::NAN,
         };
 
         let mut results = ptr::null();
@@ -431,6 +493,8 @@ fn pline_eval_parallel_offset() {
             assert!(!options.pos_equal_eps.is_nan());
             assert!(!options.slice_join_eps.is_nan());
             assert!(!options.offset_dist_eps.is_nan());
+            assert_eq!(options.join_style, CAVC_JOIN_STYLE_ROUND);
+            assert!(!options.miter_limit.is_nan());
 
             let mut aabb_index = ptr::null();
 
@@ -451,6 +515,16 @@ fn pline_eval_parallel_offset() {
                 1
             );
 
+            let invalid_options = cavc_pline_parallel_offset_o {
+                join_style: u8::MAX,
+                ..options
+            };
+            let mut invalid_results = ptr::null();
+            assert_eq!(
+                cavc_pline_parallel_offset(pline, offset, &invalid_options, &mut invalid_results),
+                2
+            );
+
             let mut results_count = u32::MAX;
             assert_eq!(cavc_plinelist_get_count(results, &mut results_count), 0);
             assert_eq!(results_count, 1);
@@ -1047,6 +1121,8 @@ fn shape_eval_ffi() {
                 pos_equal_eps: 0.0001,
                 offset_dist_eps: 0.001,
                 slice_join_eps: 0.001,
+                join_style: CAVC_JOIN_STYLE_ROUND,
+                miter_limit: 4.0,
             };
             let mut result_shape = ptr::null_mut();
             assert_eq!(